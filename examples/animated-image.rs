@@ -0,0 +1,84 @@
+use std::f32::consts::TAU;
+
+use cushy::value::{Destination, Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::image::{AnimatedImage, LoopCount};
+use cushy::widgets::Image;
+use cushy::Run;
+use image::{Delay, Frame, Rgba, RgbaImage};
+
+const FRAMES: usize = 24;
+const SIZE: u32 = 32;
+
+/// Renders a dot orbiting the center of the image.
+///
+/// [`AnimatedImage::open`] and [`AnimatedImage::from_data`] can load GIF,
+/// animated PNG, and animated WebP images.
+fn orbiting_dot() -> AnimatedImage {
+    AnimatedImage::from_frames((0..FRAMES).map(|index| {
+        let angle = index as f32 / FRAMES as f32 * TAU;
+        let center = SIZE as f32 / 2.;
+        let (x, y) = (
+            center + angle.cos() * center * 0.6,
+            center + angle.sin() * center * 0.6,
+        );
+        let image = RgbaImage::from_fn(SIZE, SIZE, |px, py| {
+            let distance = (px as f32 - x).hypot(py as f32 - y);
+            if distance < 4. {
+                Rgba([255, 128, 0, 255])
+            } else {
+                Rgba([0, 0, 0, 0])
+            }
+        });
+        Frame::from_parts(image, 0, 0, Delay::from_numer_denom_ms(40, 1))
+    }))
+}
+
+fn animated_image() -> impl MakeWidget {
+    let playing = Dynamic::new(true);
+    let loops = Dynamic::new(LoopCount::Forever);
+    let frame = Dynamic::new(0_usize);
+    let animation = orbiting_dot()
+        .playing(playing.clone())
+        .loops(loops.clone())
+        .frame(frame.clone());
+
+    // Animated images are displayed using Image, and support all of its
+    // scaling strategies.
+    let images = Image::new(animation.clone())
+        .scaled(4.)
+        .and(Image::new(animation).aspect_fit().expand())
+        .into_columns()
+        .expand();
+
+    let controls = "Playing"
+        .into_checkbox(playing)
+        .and(
+            loops
+                .new_radio(LoopCount::Forever)
+                .labelled_by("Loop Forever"),
+        )
+        .and(
+            loops
+                .new_radio(LoopCount::Times(3))
+                .labelled_by("Play 3 Times"),
+        )
+        .and("Next Frame".into_button().on_click({
+            let frame = frame.clone();
+            move |_| frame.set((frame.get() + 1) % FRAMES)
+        }))
+        .and(frame.map_each(|frame| format!("Frame {}", frame + 1)))
+        .into_columns()
+        .centered();
+
+    images.and(controls).into_rows().expand()
+}
+
+fn main() -> cushy::Result {
+    animated_image().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(animated_image).untested_still_frame();
+}
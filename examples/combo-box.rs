@@ -0,0 +1,42 @@
+use cushy::value::{Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::layers::OverlayLayer;
+use cushy::widgets::ComboBox;
+use cushy::Run;
+use figures::units::Lp;
+
+const FRUITS: [&str; 8] = [
+    "Apple", "Banana", "Cherry", "Grape", "Mango", "Orange", "Peach", "Pear",
+];
+
+fn combo_box() -> impl MakeWidget {
+    let overlay = OverlayLayer::default();
+    let options = FRUITS.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+    let fruit = Dynamic::new(String::from("Cherry"));
+    let fruit_label = fruit.map_each(|fruit| format!("Selected: {fruit}"));
+
+    let custom = Dynamic::new(String::new());
+    let custom_label = custom.map_each(|custom| format!("Entered: {custom}"));
+
+    "Choose a fruit"
+        .and(fruit.new_combo_box(options.clone(), &overlay))
+        .and(fruit_label)
+        .and("Enter any fruit")
+        .and(ComboBox::new(custom, options, &overlay).editable())
+        .and(custom_label)
+        .into_rows()
+        .width(Lp::inches(3))
+        .centered()
+        .and(overlay)
+        .into_layers()
+}
+
+fn main() -> cushy::Result {
+    combo_box().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(combo_box).untested_still_frame();
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use cushy::animation::{AnimationHandle, AnimationTarget, IntoAnimate, Spawn};
+use cushy::figures::units::Lp;
+use cushy::figures::Angle;
+use cushy::styles::components::{CornerRadius, PrimaryColor};
+use cushy::styles::{Color, Dimension};
+use cushy::value::{Destination, Dynamic};
+use cushy::widget::MakeWidget;
+use cushy::widgets::button::ButtonClick;
+use cushy::widgets::container::{ContainerBackground, Gradient};
+use cushy::{Run, WithClone};
+
+fn container_backgrounds() -> impl MakeWidget {
+    let background = Dynamic::new(ContainerBackground::Color(Color::DARKSLATEBLUE));
+    let animation = Dynamic::new(AnimationHandle::new());
+
+    let sunset = Gradient::linear(Angle::degrees(90))
+        .with_stop(0., Color::MIDNIGHTBLUE)
+        .with_stop(0.6, Color::ORANGERED)
+        .with_stop(1., Color::GOLD);
+    let dusk = Gradient::linear(Angle::degrees(45))
+        .with_stop(0., Color::INDIGO)
+        .with_stop(0.5, PrimaryColor)
+        .with_stop(1., Color::TEAL);
+    let spotlight = Gradient::radial()
+        .with_stop(0., Color::WHITE)
+        .with_stop(0.4, Color::LIGHTSKYBLUE)
+        .with_stop(1., Color::NAVY);
+    let wheel = Gradient::conic()
+        .with_stop(0., Color::RED)
+        .with_stop(0.33, Color::LIME)
+        .with_stop(0.66, Color::BLUE)
+        .with_stop(1., Color::RED);
+
+    "Choose a background below"
+        .contain()
+        .centered()
+        .expand()
+        .contain()
+        .background(background.clone())
+        .with(&CornerRadius, Dimension::from(Lp::mm(4)))
+        .expand()
+        .and(
+            "Solid"
+                .into_button()
+                .on_click(animate_to(
+                    &animation,
+                    &background,
+                    ContainerBackground::Color(Color::DARKSLATEBLUE),
+                ))
+                .and("Sunset".into_button().on_click(animate_to(
+                    &animation,
+                    &background,
+                    ContainerBackground::Gradient(sunset),
+                )))
+                .and("Dusk".into_button().on_click(animate_to(
+                    &animation,
+                    &background,
+                    ContainerBackground::Gradient(dusk),
+                )))
+                .and("Spotlight".into_button().on_click(animate_to(
+                    &animation,
+                    &background,
+                    ContainerBackground::Gradient(spotlight),
+                )))
+                .and("Color Wheel".into_button().on_click(animate_to(
+                    &animation,
+                    &background,
+                    ContainerBackground::Gradient(wheel),
+                )))
+                .into_columns()
+                .centered(),
+        )
+        .into_rows()
+        .pad()
+}
+
+fn animate_to(
+    animation: &Dynamic<AnimationHandle>,
+    background: &Dynamic<ContainerBackground>,
+    target: ContainerBackground,
+) -> impl FnMut(Option<ButtonClick>) {
+    (animation, background).with_clone(|(animation, background)| {
+        move |_| {
+            animation.set(
+                background
+                    .transition_to(target.clone())
+                    .over(Duration::from_millis(500))
+                    .spawn(),
+            );
+        }
+    })
+}
+
+fn main() -> cushy::Result {
+    container_backgrounds().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(container_backgrounds).untested_still_frame();
+}
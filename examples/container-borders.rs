@@ -0,0 +1,61 @@
+use cushy::figures::units::Lp;
+use cushy::styles::components::{BorderColor, BorderStyle, BorderWidth};
+use cushy::styles::{Color, Dimension, Edges, LineStyle, Styles};
+use cushy::widget::MakeWidget;
+use cushy::Run;
+
+fn bordered(label: &str, width: Edges<Dimension>, style: LineStyle) -> impl MakeWidget {
+    label
+        .contain()
+        // Local components are not inherited by child widgets. Both components
+        // are set in a single set of styles so that they both apply to the
+        // container.
+        .with_styles(
+            Styles::new()
+                .with_local(&BorderWidth, width)
+                .with_local(&BorderStyle, Edges::from(style)),
+        )
+}
+
+fn container_borders() -> impl MakeWidget {
+    bordered(
+        "Solid",
+        Edges::from(Dimension::from(Lp::points(2))),
+        LineStyle::Solid,
+    )
+    .and(bordered(
+        "Dashed",
+        Edges::from(Dimension::from(Lp::points(2))),
+        LineStyle::Dashed,
+    ))
+    .and(bordered(
+        "Dotted",
+        Edges::from(Dimension::from(Lp::points(3))),
+        LineStyle::Dotted,
+    ))
+    .and(
+        "Per-Edge".contain().with_styles(
+            Styles::new()
+                .with_local(
+                    &BorderWidth,
+                    Edges::from(Dimension::from(Lp::points(1)))
+                        .with_left(Dimension::from(Lp::points(6))),
+                )
+                .with_local(
+                    &BorderColor,
+                    Edges::from(Color::GRAY).with_left(Color::ORANGE),
+                ),
+        ),
+    )
+    .into_rows()
+    .centered()
+}
+
+fn main() -> cushy::Result {
+    container_borders().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(container_borders).untested_still_frame();
+}
@@ -0,0 +1,163 @@
+use cushy::value::{Destination, Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::layers::OverlayLayer;
+use cushy::widgets::menu::{Menu, MenuItem};
+use cushy::widgets::Label;
+use cushy::Run;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Action {
+    Increment,
+    Decrement,
+    Reset,
+}
+
+fn context_menu() -> impl MakeWidget {
+    counter_with_menu(Dynamic::new(0))
+}
+
+fn counter_with_menu(count: Dynamic<i32>) -> impl MakeWidget {
+    let overlay = OverlayLayer::default();
+
+    // Context menus are opened when the child ignores the right mouse button.
+    // Selectable labels only respond to the left mouse button, and can be
+    // focused to open the menu using the keyboard.
+    Label::new(count.map_each(|count| format!("Count: {count}")))
+        .selectable()
+        .contain()
+        // The factory is invoked each time the menu is opened, allowing it to
+        // reflect the current state.
+        .with_context_menu(&overlay, move || {
+            let current = count.get();
+            let count = count.clone();
+            Menu::new()
+                .on_selected(move |action| match action {
+                    Action::Increment => count.set(count.get() + 1),
+                    Action::Decrement => count.set(count.get() - 1),
+                    Action::Reset => count.set(0),
+                })
+                .with(MenuItem::new(Action::Increment, "Increment"))
+                .with(MenuItem::new(Action::Decrement, "Decrement"))
+                .with_separator()
+                .with(
+                    MenuItem::build(Action::Reset)
+                        .text("Reset")
+                        .enabled(current != 0),
+                )
+        })
+        .and("Right click the count, or click it and press Shift+F10")
+        .into_rows()
+        .centered()
+        .expand()
+        .and(overlay)
+        .into_layers()
+}
+
+fn main() -> cushy::Result {
+    context_menu().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(context_menu).untested_still_frame();
+}
+
+#[cfg(test)]
+mod opening {
+    use std::time::Duration;
+
+    use cushy::animation::easings::EaseInOutSine;
+    use cushy::figures::Point;
+    use cushy::value::{Dynamic, Source};
+    use cushy::window::{AnimationRecorder, Rgba8};
+    use kludgine::app::winit::event::{Modifiers, MouseButton};
+    use kludgine::app::winit::keyboard::{Key, KeyCode, ModifiersState, NamedKey, PhysicalKey};
+
+    use super::counter_with_menu;
+
+    fn press(r: &mut AnimationRecorder<'_, Rgba8>, code: KeyCode, key: NamedKey) {
+        r.animate_keypress(
+            PhysicalKey::Code(code),
+            Key::Named(key),
+            None,
+            Duration::from_millis(200),
+        )
+        .unwrap();
+    }
+
+    /// Moves the cursor over the count label and clicks `button`.
+    fn click_count(r: &mut AnimationRecorder<'_, Rgba8>, button: MouseButton) {
+        let layout = r
+            .window()
+            .find_text("Count: 0")
+            .and_then(|label| label.last_layout())
+            .expect("count label");
+        r.animate_cursor_to(
+            layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2),
+            Duration::from_millis(250),
+            EaseInOutSine,
+        )
+        .unwrap();
+        r.animate_mouse_button(button, Duration::from_millis(100))
+            .unwrap();
+    }
+
+    fn assert_menu_open(r: &AnimationRecorder<'_, Rgba8>) {
+        assert!(
+            r.window().find_text("Increment").is_some(),
+            "context menu was not opened"
+        );
+    }
+
+    /// Opens the context menu using `open`, which must leave Increment
+    /// highlighted, then selects Decrement using the keyboard.
+    fn decrement_using(name: &'static str, open: impl FnOnce(&mut AnimationRecorder<'_, Rgba8>)) {
+        let count = Dynamic::new(0);
+        cushy::example::Example::build(name, counter_with_menu(count.clone()), 750, None).animated(
+            |r| {
+                assert!(r.window().find_text("Increment").is_none());
+                open(r);
+                assert_menu_open(r);
+                // Increment is highlighted, so this highlights Decrement.
+                press(r, KeyCode::ArrowDown, NamedKey::ArrowDown);
+                press(r, KeyCode::Enter, NamedKey::Enter);
+            },
+        );
+        assert_eq!(count.get(), -1);
+    }
+
+    #[test]
+    fn right_click() {
+        decrement_using("context_menu_right_click", |r| {
+            click_count(r, MouseButton::Right);
+            // Menus opened with the mouse have no highlighted item.
+            press(r, KeyCode::ArrowDown, NamedKey::ArrowDown);
+        });
+    }
+
+    #[test]
+    fn menu_key() {
+        decrement_using("context_menu_menu_key", |r| {
+            // Clicking the selectable label focuses it without opening the
+            // menu.
+            click_count(r, MouseButton::Left);
+            assert!(r.window().find_text("Increment").is_none());
+            press(r, KeyCode::ContextMenu, NamedKey::ContextMenu);
+        });
+    }
+
+    #[test]
+    fn shift_f10() {
+        decrement_using("context_menu_shift_f10", |r| {
+            click_count(r, MouseButton::Left);
+            // F10 without Shift does not open the menu.
+            press(r, KeyCode::F10, NamedKey::F10);
+            assert!(r.window().find_text("Increment").is_none());
+
+            r.window_mut()
+                .set_modifiers(Modifiers::from(ModifiersState::SHIFT));
+            press(r, KeyCode::F10, NamedKey::F10);
+            r.window_mut().set_modifiers(Modifiers::default());
+        });
+    }
+}
@@ -4,7 +4,7 @@ use cushy::widget::MakeWidget;
 use cushy::widgets::label::Displayable;
 use cushy::Run;
 
-fn main() -> cushy::Result {
+fn counter() -> impl MakeWidget {
     let counter = Dynamic::new(0i32);
 
     counter
@@ -22,5 +22,27 @@ fn main() -> cushy::Result {
         })))
         .into_columns()
         .centered()
-        .run()
+}
+
+fn main() -> cushy::Result {
+    counter().run()
+}
+
+#[test]
+fn runs() {
+    use std::time::Duration;
+
+    cushy::example!(counter).still_frame(|recorder| {
+        assert!(recorder.settle(Duration::from_secs(1)).unwrap());
+        let count = recorder.window.find_text("0").expect("count label");
+        let increment = recorder.window.find_text("+").expect("+ button");
+        let decrement = recorder.window.find_text("-").expect("- button");
+        recorder.window.assert_enabled(&increment, true);
+
+        let _ = recorder.window.click(&increment);
+        let _ = recorder.window.click(&increment);
+        let _ = recorder.window.click(&decrement);
+        assert!(recorder.settle(Duration::from_secs(1)).unwrap());
+        recorder.window.assert_text(&count, "1");
+    });
 }
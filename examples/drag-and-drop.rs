@@ -0,0 +1,179 @@
+use cushy::value::{Dynamic, Source};
+use cushy::widget::{MakeWidget, WidgetList};
+use cushy::widgets::drag::DragEvent;
+use cushy::widgets::layers::OverlayLayer;
+use cushy::widgets::{DragSource, DropTarget};
+use cushy::Run;
+
+/// The payload dragged when moving a card.
+#[derive(Debug, Clone, Copy)]
+struct DraggedCard {
+    column: usize,
+    index: usize,
+}
+
+const COLUMNS: [&str; 3] = ["To Do", "In Progress", "Done"];
+
+fn board() -> impl MakeWidget {
+    board_with_cards(Dynamic::new(vec![
+        vec![
+            String::from("Write documentation"),
+            String::from("Fix layout bug"),
+            String::from("Publish release"),
+        ],
+        vec![String::from("Review pull request")],
+        Vec::new(),
+    ]))
+}
+
+fn board_with_cards(cards: Dynamic<Vec<Vec<String>>>) -> impl MakeWidget {
+    let overlay = OverlayLayer::default();
+
+    COLUMNS
+        .iter()
+        .enumerate()
+        .map(|(column, title)| board_column(column, title, &cards, &overlay))
+        .collect::<WidgetList>()
+        .into_columns()
+        .pad()
+        .and(overlay)
+        .into_layers()
+}
+
+fn board_column(
+    column: usize,
+    title: &'static str,
+    cards: &Dynamic<Vec<Vec<String>>>,
+    overlay: &OverlayLayer,
+) -> impl MakeWidget {
+    let overlay = overlay.clone();
+    let column_cards = cards.map_each({
+        let cards = cards.clone();
+        move |all_cards: &Vec<Vec<String>>| {
+            all_cards[column]
+                .iter()
+                .enumerate()
+                .map(|(index, card)| board_card(column, index, card, &cards, &overlay))
+                .collect::<WidgetList>()
+        }
+    });
+
+    DropTarget::new(
+        title
+            .h3()
+            .and(column_cards.into_rows())
+            .into_rows()
+            .align_top()
+            .contain()
+            .expand(),
+    )
+    .on_drop({
+        let cards = cards.clone();
+        move |event: DragEvent<'_, DraggedCard>| {
+            let end = cards.map_ref(|cards| cards[column].len());
+            move_card(&cards, *event.payload, column, end);
+        }
+    })
+    .expand()
+}
+
+fn board_card(
+    column: usize,
+    index: usize,
+    card: &str,
+    cards: &Dynamic<Vec<Vec<String>>>,
+    overlay: &OverlayLayer,
+) -> impl MakeWidget {
+    let preview = card.to_string();
+    let cards = cards.clone();
+    // Each card is also a drop target, allowing cards to be reordered by
+    // dropping them onto another card.
+    DropTarget::new(
+        DragSource::new(card.to_string().contain(), move || DraggedCard {
+            column,
+            index,
+        })
+        .preview(overlay, move || preview.clone().contain()),
+    )
+    .on_drop(move |event: DragEvent<'_, DraggedCard>| {
+        move_card(&cards, *event.payload, column, index);
+    })
+}
+
+/// Moves the dragged card so that it is inserted at `index` in `column`.
+fn move_card(
+    cards: &Dynamic<Vec<Vec<String>>>,
+    dragged: DraggedCard,
+    column: usize,
+    mut index: usize,
+) {
+    let mut cards = cards.lock();
+    let card = cards[dragged.column].remove(dragged.index);
+    if dragged.column == column && dragged.index < index {
+        index -= 1;
+    }
+    cards[column].insert(index, card);
+}
+
+fn main() -> cushy::Result {
+    board().run()
+}
+
+#[test]
+fn runs() {
+    use std::time::Duration;
+
+    use cushy::animation::easings::EaseInOutSine;
+    use cushy::figures::units::Px;
+    use cushy::figures::Point;
+    use cushy::window::VirtualWindow;
+    use kludgine::app::winit::event::MouseButton;
+
+    fn center_of(window: &VirtualWindow, text: &str) -> Point<Px> {
+        let layout = window
+            .find_text(text)
+            .and_then(|widget| widget.last_layout())
+            .unwrap_or_else(|| panic!("{text} not displayed"));
+        layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2)
+    }
+
+    let cards = Dynamic::new(vec![
+        vec![
+            String::from("Write documentation"),
+            String::from("Fix layout bug"),
+        ],
+        vec![String::from("Review pull request")],
+        Vec::new(),
+    ]);
+    cushy::example::Example::build("board", board_with_cards(cards.clone()), 750, None)
+        .prepare_with(|r| {
+            r.refresh().unwrap();
+            let card = center_of(&r.window, "Write documentation");
+            r.set_cursor_position(card);
+            r.set_cursor_visible(true);
+            r.refresh().unwrap();
+        })
+        .animated(|r| {
+            r.wait_for(Duration::from_millis(250)).unwrap();
+            // Dropping the card onto the Done column's title drops it onto the
+            // column, which appends it.
+            let done = center_of(r.window(), "Done");
+            r.animate_mouse_drag_to(
+                MouseButton::Left,
+                done,
+                Duration::from_millis(750),
+                EaseInOutSine,
+            )
+            .unwrap();
+            r.wait_for(Duration::from_millis(500)).unwrap();
+        });
+
+    assert_eq!(
+        cards.get(),
+        [
+            vec![String::from("Fix layout bug")],
+            vec![String::from("Review pull request")],
+            vec![String::from("Write documentation")],
+        ]
+    );
+}
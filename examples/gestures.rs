@@ -0,0 +1,95 @@
+use cushy::figures::units::Lp;
+use cushy::value::{Destination, Dynamic};
+use cushy::widget::{MakeWidget, WidgetList};
+use cushy::widgets::gestures::{Gesture, GesturePhase};
+use cushy::Run;
+
+fn describe(gesture: Gesture) -> String {
+    match gesture {
+        Gesture::Tap(location) => format!("Tap at {}, {}", location.x, location.y),
+        Gesture::DoubleTap(location) => format!("Double tap at {}, {}", location.x, location.y),
+        Gesture::LongPress(location) => format!("Long press at {}, {}", location.x, location.y),
+        Gesture::Pan(pan) if pan.phase == GesturePhase::Ended => format!(
+            "Pan ended at {:.0}, {:.0} px/s",
+            pan.velocity.x, pan.velocity.y
+        ),
+        Gesture::Pan(pan) => format!("Panning at {}, {}", pan.location.x, pan.location.y),
+        Gesture::Pinch(pinch) => format!(
+            "Pinch x{:.2}, rotated {:.1} degrees",
+            pinch.scale,
+            pinch.rotation.to_degrees()
+        ),
+    }
+}
+
+fn gestures() -> impl MakeWidget {
+    let last_gesture = Dynamic::new(String::from("No gestures yet"));
+    let clicked = Dynamic::new(String::from("No rows clicked yet"));
+
+    // Gestures are recognized from touch events delivered to the wrapped
+    // widget.
+    let surface = "Tap, double tap, long press, pan, or pinch here"
+        .centered()
+        .expand()
+        .contain()
+        .on_gesture({
+            let last_gesture = last_gesture.clone();
+            move |gesture| last_gesture.set(describe(gesture))
+        })
+        .height(Lp::inches(2));
+
+    // Scroll views can be panned with a finger, and continue scrolling with
+    // momentum when released. Tapping a row clicks it.
+    let rows = (1..=50)
+        .map(|row| {
+            format!("Row {row}").into_button().on_click({
+                let clicked = clicked.clone();
+                move |_| clicked.set(format!("Clicked row {row}"))
+            })
+        })
+        .collect::<WidgetList>()
+        .into_rows()
+        .vertical_scroll()
+        .expand();
+
+    surface
+        .and(last_gesture)
+        .and(rows)
+        .and(clicked)
+        .into_rows()
+        .expand()
+}
+
+fn main() -> cushy::Result {
+    gestures().run()
+}
+
+#[test]
+fn runs() {
+    use std::time::Duration;
+
+    use cushy::figures::{Point, Px2D};
+    use cushy::window::VirtualWindow;
+
+    fn displays(window: &VirtualWindow, prefix: &str) -> bool {
+        window.widgets().iter().any(|widget| {
+            window
+                .text_of(widget)
+                .is_some_and(|text| text.starts_with(prefix))
+        })
+    }
+
+    cushy::example!(gestures, 600, 600).animated(|r| {
+        assert!(displays(r.window(), "No gestures yet"));
+        r.animate_tap(Point::px(300, 100), Duration::from_millis(100))
+            .unwrap();
+        r.wait_for(Duration::from_millis(500)).unwrap();
+        assert!(displays(r.window(), "Tap at "));
+
+        // Tapping a row in the scroll view clicks it.
+        r.animate_tap(Point::px(300, 400), Duration::from_millis(100))
+            .unwrap();
+        r.wait_for(Duration::from_millis(500)).unwrap();
+        assert!(displays(r.window(), "Clicked row "));
+    });
+}
@@ -9,11 +9,14 @@ use cushy::Run;
 fn main() -> cushy::Result {
     let contents = Dynamic::from("Hello World");
     let password = Dynamic::new(MaskedString::default());
+    let notes = Dynamic::from("Multi-line inputs\ninsert new lines when Enter is pressed.");
 
     "Text Input Field:"
         .and(contents.into_input())
         .and("Masked Input Field:")
         .and(password.into_input())
+        .and("Multi-line Input Field:")
+        .and(notes.into_input().multiline())
         .into_rows()
         .width(Px::new(100)..Px::new(800))
         .with_local(&HorizontalAlignment, HorizontalAlign::Center)
@@ -0,0 +1,60 @@
+use cushy::value::Dynamic;
+use cushy::widget::MakeWidget;
+use cushy::widgets::input::InputValue;
+use cushy::widgets::Markdown;
+use cushy::Run;
+
+const SOURCE: &str = r#"# Markdown
+
+Cushy can display **Markdown** using the `Markdown` widget. Edit the text on
+the left to see the preview update. Clicking [a link](https://cushy.rs)
+prints its destination.
+
+## Lists
+
+1. Ordered lists
+2. Unordered lists
+   - with nesting
+   - [x] and task lists
+
+> Block quotes are displayed with a border.
+
+| Widget     | Description                 |
+|------------|----------------------------:|
+| `Label`    | Displays text               |
+| `Markdown` | Displays Markdown           |
+
+```rust
+fn main() {
+    println!("Hello, world!");
+}
+```
+"#;
+
+fn markdown() -> impl MakeWidget {
+    let source = Dynamic::new(String::from(SOURCE));
+
+    source
+        .clone()
+        .into_input()
+        .multiline()
+        .expand()
+        .and(
+            Markdown::new(source)
+                .on_link(|url| println!("Clicked {url}"))
+                .vertical_scroll()
+                .expand(),
+        )
+        .into_columns()
+        .pad()
+        .expand()
+}
+
+fn main() -> cushy::Result {
+    markdown().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(markdown).untested_still_frame();
+}
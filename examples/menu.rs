@@ -1,9 +1,10 @@
+use cushy::value::{Destination, Dynamic};
 use cushy::widget::MakeWidget;
 use cushy::widgets::layers::{OverlayLayer, Overlayable};
 use cushy::widgets::menu::{Menu, MenuItem};
 use cushy::Run;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum MenuOptions {
     First,
     Second,
@@ -11,6 +12,10 @@ enum MenuOptions {
 }
 
 fn menu_example() -> impl MakeWidget {
+    menu_button(Dynamic::default())
+}
+
+fn menu_button(selected: Dynamic<Option<MenuOptions>>) -> impl MakeWidget {
     let overlay = OverlayLayer::default();
 
     "Click Me"
@@ -19,7 +24,7 @@ fn menu_example() -> impl MakeWidget {
             let overlay = overlay.clone();
             move |click| {
                 if let Some(click) = click {
-                    menu(true)
+                    menu(true, &selected)
                         .overlay_in(&overlay)
                         .at(click.window_location)
                         .show();
@@ -36,14 +41,16 @@ fn main() -> cushy::Result {
     menu_example().run()
 }
 
-fn menu(top: bool) -> Menu<MenuOptions> {
+fn menu(top: bool, selected: &Dynamic<Option<MenuOptions>>) -> Menu<MenuOptions> {
     let mut third = MenuItem::build(MenuOptions::Third).text("Third");
     if top {
-        third = third.submenu(menu(false));
+        third = third.submenu(menu(false, selected));
     }
+    let selected = selected.clone();
     Menu::new()
-        .on_selected(|selected| {
-            println!("Selected item: {selected:?}");
+        .on_selected(move |item| {
+            println!("Selected item: {item:?}");
+            selected.set(Some(item));
         })
         .with(MenuItem::new(MenuOptions::First, "First"))
         .with(MenuItem::new(MenuOptions::Second, "Second"))
@@ -118,3 +125,107 @@ fn runs() {
             r.wait_for(Duration::from_millis(500)).unwrap();
         });
 }
+
+#[test]
+fn keyboard() {
+    use std::time::Duration;
+
+    use cushy::animation::easings::EaseInOutSine;
+    use cushy::figures::{Point, Px2D};
+    use cushy::value::Source;
+    use cushy::widgets::Button;
+    use cushy::window::{AnimationRecorder, Rgba8};
+    use kludgine::app::winit::event::MouseButton;
+    use kludgine::app::winit::keyboard::{Key, KeyCode, NamedKey, PhysicalKey};
+
+    fn press(r: &mut AnimationRecorder<'_, Rgba8>, code: KeyCode, key: Key, text: Option<&str>) {
+        r.animate_keypress(
+            PhysicalKey::Code(code),
+            key,
+            text,
+            Duration::from_millis(200),
+        )
+        .unwrap();
+    }
+
+    fn open_menu(r: &mut AnimationRecorder<'_, Rgba8>) {
+        r.animate_cursor_to(
+            Point::px(410, 300),
+            Duration::from_millis(250),
+            EaseInOutSine,
+        )
+        .unwrap();
+        r.animate_mouse_button(MouseButton::Left, Duration::from_millis(100))
+            .unwrap();
+        // Move the cursor away so that only the keyboard controls the menu.
+        r.animate_cursor_to(
+            Point::px(100, 100),
+            Duration::from_millis(250),
+            EaseInOutSine,
+        )
+        .unwrap();
+    }
+
+    let selected = Dynamic::new(None);
+    cushy::example::Example::build(
+        "menu_keyboard_select",
+        menu_button(selected.clone()),
+        800,
+        Some(600),
+    )
+    .prepare_with(|r| {
+        r.set_cursor_position(Point::px(420, 270));
+        r.set_cursor_visible(true);
+        r.refresh().unwrap();
+    })
+    .animated(|r| {
+        open_menu(r);
+        // First, Second, then Third, skipping the separators and the
+        // disabled item.
+        for _ in 0..3 {
+            press(r, KeyCode::ArrowDown, Key::Named(NamedKey::ArrowDown), None);
+        }
+        // Open Third's submenu, which highlights its first item.
+        press(
+            r,
+            KeyCode::ArrowRight,
+            Key::Named(NamedKey::ArrowRight),
+            None,
+        );
+        press(r, KeyCode::ArrowDown, Key::Named(NamedKey::ArrowDown), None);
+        press(r, KeyCode::Enter, Key::Named(NamedKey::Enter), None);
+    });
+    assert_eq!(selected.get(), Some(MenuOptions::Second));
+
+    let selected = Dynamic::new(None);
+    cushy::example::Example::build(
+        "menu_keyboard_dismiss",
+        menu_button(selected.clone()),
+        800,
+        Some(600),
+    )
+    .prepare_with(|r| {
+        r.set_cursor_position(Point::px(420, 270));
+        r.set_cursor_visible(true);
+        r.refresh().unwrap();
+    })
+    .animated(|r| {
+        open_menu(r);
+        // Type-ahead highlights Third.
+        press(r, KeyCode::KeyT, Key::Character("t".into()), Some("t"));
+        press(
+            r,
+            KeyCode::ArrowRight,
+            Key::Named(NamedKey::ArrowRight),
+            None,
+        );
+        press(r, KeyCode::ArrowLeft, Key::Named(NamedKey::ArrowLeft), None);
+        press(r, KeyCode::Escape, Key::Named(NamedKey::Escape), None);
+        // Dismissing the menu restores focus to the button that opened it.
+        let button = r.window().find_widget::<Button>().expect("button");
+        r.window().assert_focused(&button);
+        // The menu is dismissed, so this key press selects nothing.
+        press(r, KeyCode::Enter, Key::Named(NamedKey::Enter), None);
+    });
+    assert_eq!(selected.get(), None);
+}
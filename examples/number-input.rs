@@ -0,0 +1,45 @@
+use cushy::figures::units::Lp;
+use cushy::value::{Dynamic, MapEach, Validations};
+use cushy::widget::MakeWidget;
+use cushy::widgets::NumberInput;
+use cushy::Run;
+
+fn number_input() -> impl MakeWidget {
+    let validations = Validations::default();
+    let quantity = Dynamic::new(1_u32);
+    let price = Dynamic::new(9.99_f64);
+
+    let quantity_input = NumberInput::new(quantity.clone()).minimum(1).maximum(99);
+    let quantity_validation = validations.validate_result(quantity_input.result());
+
+    let price_input = NumberInput::new(price.clone())
+        .minimum(0.)
+        .step(0.25)
+        .grouped();
+    let price_validation = validations.validate_result(price_input.result());
+
+    let total = (&quantity, &price)
+        .map_each(|(quantity, price)| format!("Total: {:.2}", f64::from(*quantity) * price));
+
+    "Quantity"
+        .and(
+            quantity_input
+                .validation(quantity_validation)
+                .hint("1 to 99"),
+        )
+        .and("Unit Price")
+        .and(price_input.validation(price_validation))
+        .and(total)
+        .into_rows()
+        .width(..Lp::inches(4))
+        .centered()
+}
+
+fn main() -> cushy::Result {
+    number_input().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(number_input).untested_still_frame();
+}
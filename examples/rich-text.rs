@@ -0,0 +1,54 @@
+use cushy::figures::units::Lp;
+use cushy::styles::{Color, FamilyOwned, Weight};
+use cushy::value::{Destination, Dynamic};
+use cushy::widget::MakeWidget;
+use cushy::widgets::rich_text::{RichText, Span};
+use cushy::widgets::RichLabel;
+use cushy::Run;
+
+fn rich_text() -> impl MakeWidget {
+    let clicks = Dynamic::new(0_usize);
+
+    let text = RichText::new()
+        .with("Rich text can mix ")
+        .with(Span::new("bold").bold())
+        .with(", ")
+        .with(Span::new("italic").italic())
+        .with(", ")
+        .with(Span::new("light").weight(Weight::LIGHT))
+        .with(", ")
+        .with(Span::new("monospace").family(FamilyOwned::Monospace))
+        .with(", ")
+        .with(Span::new("colored").color(Color::ORANGE))
+        .with(", and ")
+        .with(Span::new("large").size(Lp::points(24)))
+        .with(" text. Spans can also be ")
+        .with(Span::new("links").on_click({
+            let clicks = clicks.clone();
+            move |()| {
+                *clicks.lock() += 1;
+            }
+        }))
+        .with(". Long text is wrapped the same way as a regular label.");
+
+    RichLabel::new(text)
+        .and(
+            clicks
+                .map_each(|clicks| format!("{clicks} clicks"))
+                .into_button()
+                .on_click(move |_| clicks.set(0)),
+        )
+        .into_rows()
+        .contain()
+        .width(..Lp::inches(6))
+        .centered()
+}
+
+fn main() -> cushy::Result {
+    rich_text().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(rich_text).untested_still_frame();
+}
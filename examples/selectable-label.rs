@@ -0,0 +1,26 @@
+use cushy::figures::units::Lp;
+use cushy::widget::MakeWidget;
+use cushy::widgets::Label;
+use cushy::Run;
+
+const TEXT: &str = "Selectable labels allow their text to be selected by dragging \
+the mouse. Double-click to select a word, or triple-click to select a line. \
+The selected text can be copied using the platform's copy shortcut.
+
+Long text wraps just like any other label.";
+
+fn selectable_label() -> impl MakeWidget {
+    Label::new(TEXT)
+        .selectable()
+        .width(..Lp::inches(5))
+        .centered()
+}
+
+fn main() -> cushy::Result {
+    selectable_label().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(selectable_label).untested_still_frame();
+}
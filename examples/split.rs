@@ -0,0 +1,35 @@
+use cushy::figures::units::Lp;
+use cushy::value::{Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::split::SplitPane;
+use cushy::widgets::Split;
+use cushy::Run;
+
+fn split() -> impl MakeWidget {
+    let positions = Dynamic::<Vec<Lp>>::default();
+    let positions_label = positions.map_each(|positions| format!("Dividers: {positions:?}"));
+
+    Split::columns([
+        SplitPane::new("Sidebar".centered())
+            .minimum(Lp::inches(1))
+            .maximum(Lp::inches(3)),
+        SplitPane::new(Split::rows([
+            SplitPane::new("Editor".centered()).minimum(Lp::inches(1)),
+            SplitPane::new("Terminal".centered()),
+        ])),
+        SplitPane::new("Outline".centered()).minimum(Lp::inches(1)),
+    ])
+    .positions(positions)
+    .expand()
+    .and(positions_label)
+    .into_rows()
+}
+
+fn main() -> cushy::Result {
+    split().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(split).untested_still_frame();
+}
@@ -0,0 +1,45 @@
+use cushy::styles::components::{ButtonBackground, ButtonHoverBackground, TextColor};
+use cushy::styles::{Color, Selector, StyleSheet, Styles};
+use cushy::value::Dynamic;
+use cushy::widget::MakeWidget;
+use cushy::widgets::{Button, Label};
+use cushy::Run;
+
+fn style_sheet() -> impl MakeWidget {
+    let sheet = StyleSheet::new()
+        .with(
+            Selector::widget::<Button>().class("danger"),
+            Styles::new()
+                .with(&ButtonBackground, Color::DARKRED)
+                .with(&ButtonHoverBackground, Color::RED),
+        )
+        .with(
+            Selector::widget::<Label<String>>().class("danger"),
+            Styles::new().with(&TextColor, Color::RED),
+        );
+    let confirm = Dynamic::new(false);
+
+    Label::new(String::from("Deleting cannot be undone."))
+        .with_class_when("danger", confirm.clone())
+        .and("Allow deleting".into_checkbox(confirm.clone()))
+        .and(
+            "Delete"
+                .into_button()
+                .with_class("danger")
+                .with_enabled(confirm),
+        )
+        .and("Cancel".into_button())
+        .into_rows()
+        .contain()
+        .centered()
+        .with_style_sheet(sheet)
+}
+
+fn main() -> cushy::Result {
+    style_sheet().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(style_sheet).untested_still_frame();
+}
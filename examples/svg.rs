@@ -0,0 +1,46 @@
+use cushy::figures::units::Lp;
+use cushy::value::Dynamic;
+use cushy::widget::MakeWidget;
+use cushy::widgets::slider::Slidable;
+use cushy::widgets::svg::SvgDocument;
+use cushy::widgets::Svg;
+use cushy::Run;
+
+fn svg() -> impl MakeWidget {
+    let star = SvgDocument::from_data(include_bytes!("assets/star.svg")).expect("valid svg");
+    let scale = Dynamic::new(4_f32);
+
+    // Tinted documents are drawn using the current text color, allowing
+    // monochrome icons to follow the theme.
+    let icons = Svg::new(star.clone())
+        .tinted()
+        .and(Svg::new(star.clone()).tinted().scaled(2.))
+        .and(Svg::new(star.clone()).tinted().scaled(4.))
+        .into_columns()
+        .centered();
+
+    // Documents are rasterized at the window's effective scale, so the image
+    // stays crisp regardless of how large it is drawn.
+    let scaled = Svg::new(star).scaled(scale.clone()).centered().expand();
+
+    icons
+        .and(scaled)
+        .and(
+            "Scale"
+                .and(scale.slider_between(1., 20.))
+                .into_rows()
+                .width(..Lp::inches(4))
+                .centered(),
+        )
+        .into_rows()
+        .expand()
+}
+
+fn main() -> cushy::Result {
+    svg().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(svg).untested_still_frame();
+}
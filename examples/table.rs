@@ -0,0 +1,191 @@
+use cushy::value::{Destination, Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::grid::GridDimension;
+use cushy::widgets::table::{SortDirection, TableColumn, TableSelection, TableSort};
+use cushy::widgets::Table;
+use cushy::Run;
+use kempt::Set;
+
+struct Planet {
+    name: &'static str,
+    moons: u32,
+    radius_km: u32,
+}
+
+const PLANETS: [Planet; 8] = [
+    Planet {
+        name: "Mercury",
+        moons: 0,
+        radius_km: 2_440,
+    },
+    Planet {
+        name: "Venus",
+        moons: 0,
+        radius_km: 6_052,
+    },
+    Planet {
+        name: "Earth",
+        moons: 1,
+        radius_km: 6_371,
+    },
+    Planet {
+        name: "Mars",
+        moons: 2,
+        radius_km: 3_390,
+    },
+    Planet {
+        name: "Jupiter",
+        moons: 95,
+        radius_km: 69_911,
+    },
+    Planet {
+        name: "Saturn",
+        moons: 146,
+        radius_km: 58_232,
+    },
+    Planet {
+        name: "Uranus",
+        moons: 28,
+        radius_km: 25_362,
+    },
+    Planet {
+        name: "Neptune",
+        moons: 16,
+        radius_km: 24_622,
+    },
+];
+
+fn sorted_order(sort: Option<TableSort>) -> Vec<usize> {
+    let mut order = (0..PLANETS.len()).collect::<Vec<_>>();
+    if let Some(sort) = sort {
+        order.sort_by(|a, b| {
+            let (a, b) = (&PLANETS[*a], &PLANETS[*b]);
+            let ordering = match sort.column {
+                0 => a.name.cmp(b.name),
+                1 => a.moons.cmp(&b.moons),
+                _ => a.radius_km.cmp(&b.radius_km),
+            };
+            match sort.direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+    order
+}
+
+fn table() -> impl MakeWidget {
+    let sort = Dynamic::<Option<TableSort>>::default();
+    let selection = Dynamic::<Set<usize>>::default();
+    let selected = selection.map_each({
+        let sort = sort.clone();
+        move |selection: &Set<usize>| {
+            let order = sorted_order(sort.get());
+            let names = selection
+                .iter()
+                .map(|row| PLANETS[order[*row]].name)
+                .collect::<Vec<_>>();
+            format!("Selected: {}", names.join(", "))
+        }
+    });
+    // Row indices refer to the sorted order, so the selection is cleared
+    // whenever the sort changes.
+    sort.for_each({
+        let selection = selection.clone();
+        move |_| selection.set(Set::new())
+    })
+    .persist();
+
+    let mut order_cache: Option<(Option<TableSort>, Vec<usize>)> = None;
+    let table = Table::new(
+        [
+            TableColumn::new("Planet")
+                .dimension(GridDimension::Fractional { weight: 1 })
+                .sortable(),
+            TableColumn::new("Moons").sortable(),
+            TableColumn::new("Radius (km)").sortable(),
+        ],
+        PLANETS.len(),
+        {
+            let sort = sort.clone();
+            move |row, column| {
+                let current_sort = sort.get();
+                if order_cache
+                    .as_ref()
+                    .map_or(true, |(cached_sort, _)| *cached_sort != current_sort)
+                {
+                    order_cache = Some((current_sort, sorted_order(current_sort)));
+                }
+                let (_, order) = order_cache.as_ref().expect("order cached");
+                let planet = &PLANETS[order[row]];
+                match column {
+                    0 => planet.name.to_string(),
+                    1 => planet.moons.to_string(),
+                    _ => planet.radius_km.to_string(),
+                }
+            }
+        },
+    )
+    .sort(sort)
+    .selection(selection)
+    .selection_mode(TableSelection::Multiple);
+
+    table.expand().and(selected).into_rows().pad()
+}
+
+fn main() -> cushy::Result {
+    table().run()
+}
+
+#[test]
+fn runs() {
+    use std::time::Duration;
+
+    use cushy::animation::easings::EaseInOutSine;
+    use cushy::figures::Point;
+    use cushy::window::{AnimationRecorder, Rgba8};
+    use kludgine::app::winit::event::MouseButton;
+
+    /// Moves the cursor over the widget displaying `text` and clicks it.
+    fn click_text(r: &mut AnimationRecorder<'_, Rgba8>, text: &str) {
+        let layout = r
+            .window()
+            .find_text(text)
+            .and_then(|widget| widget.last_layout())
+            .unwrap_or_else(|| panic!("{text} not displayed"));
+        r.animate_cursor_to(
+            layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2),
+            Duration::from_millis(250),
+            EaseInOutSine,
+        )
+        .unwrap();
+        r.animate_mouse_button(MouseButton::Left, Duration::from_millis(100))
+            .unwrap();
+        r.wait_for(Duration::from_millis(250)).unwrap();
+    }
+
+    cushy::example!(table).animated(|r| {
+        click_text(r, "Mars");
+        assert!(r.window().find_text("Selected: Mars").is_some());
+
+        // Sorting by moons in descending order moves Saturn to the first row,
+        // and clears the selection.
+        click_text(r, "Moons");
+        click_text(r, "Moons");
+        assert!(r.window().find_text("Selected: ").is_some());
+        let first_row = r
+            .window()
+            .find_text("146")
+            .and_then(|widget| widget.last_layout())
+            .expect("saturn's moons");
+        let no_moons = r
+            .window()
+            .find_text("0")
+            .and_then(|widget| widget.last_layout())
+            .expect("row without moons");
+        assert!(first_row.origin.y < no_moons.origin.y);
+
+        click_text(r, "Saturn");
+        assert!(r.window().find_text("Selected: Saturn").is_some());
+    });
+}
@@ -0,0 +1,64 @@
+use cushy::value::{Destination, Dynamic, Source};
+use cushy::widget::MakeWidget;
+use cushy::widgets::tabs::Tab;
+use cushy::widgets::Tabs;
+use cushy::Run;
+
+fn tabs() -> impl MakeWidget {
+    let pinned = Dynamic::new(true);
+    let pinned_tab = Tab::new(
+        "Pinned",
+        "Prevent this tab from being closed"
+            .into_checkbox(pinned.clone())
+            .centered(),
+    )
+    .closable();
+    let pinned_tab_id = pinned_tab.id();
+    let tabs = Dynamic::new(vec![
+        Tab::new(
+            "Welcome",
+            "Drag tabs to reorder them, or press Ctrl+Tab to switch tabs.",
+        ),
+        pinned_tab,
+    ]);
+    let selected = Dynamic::new(Some(0));
+    let mut next_tab = 1;
+
+    let add = "Add Tab".into_button().on_click({
+        let tabs = tabs.clone();
+        let selected = selected.clone();
+        move |_| {
+            let mut tabs = tabs.lock();
+            tabs.push(
+                Tab::new(
+                    format!("Tab {next_tab}"),
+                    format!("This is tab number {next_tab}").centered(),
+                )
+                .closable(),
+            );
+            next_tab += 1;
+            let index = tabs.len() - 1;
+            drop(tabs);
+            selected.set(Some(index));
+        }
+    });
+
+    let tab_widget = Tabs::new(tabs.clone())
+        .selected(selected)
+        .on_close(move |index| {
+            // The pinned tab can only be closed once it has been unpinned.
+            let is_pinned_tab = tabs.map_ref(|tabs| tabs[index].id() == pinned_tab_id);
+            !(is_pinned_tab && pinned.get())
+        });
+
+    add.and(tab_widget.expand()).into_rows().pad()
+}
+
+fn main() -> cushy::Result {
+    tabs().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(tabs).untested_still_frame();
+}
@@ -0,0 +1,21 @@
+use cushy::styles::ThemePair;
+use cushy::value::Dynamic;
+use cushy::widget::MakeWidget;
+use cushy::Run;
+
+const THEME_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples/assets/theme.toml");
+
+fn main() -> cushy::Result {
+    let theme = Dynamic::new(ThemePair::default());
+    // The theme is reloaded each time the file is saved.
+    ThemePair::watch(THEME_PATH, &theme).expect("error loading theme");
+
+    "Edit examples/assets/theme.toml while this example is running to change its colors."
+        .and("Themed Button".into_button())
+        .into_rows()
+        .contain()
+        .centered()
+        .into_window()
+        .themed(theme)
+        .run()
+}
@@ -0,0 +1,62 @@
+use cushy::value::{Dynamic, Source};
+use cushy::widget::{MakeWidget, WidgetInstance};
+use cushy::widgets::tree_view::TreeSource;
+use cushy::widgets::TreeView;
+use cushy::Run;
+
+/// A tree where each node is identified by the path of indices leading to it.
+///
+/// Every node has ten children until the maximum depth is reached, resulting
+/// in over ten thousand nodes when fully expanded. Children are only created
+/// when their parent is expanded.
+struct NumberedTree;
+
+const MAX_DEPTH: usize = 4;
+
+impl TreeSource for NumberedTree {
+    type Node = Vec<u8>;
+
+    fn roots(&mut self) -> Vec<Self::Node> {
+        (0..10).map(|index| vec![index]).collect()
+    }
+
+    fn has_children(&mut self, node: &Self::Node) -> bool {
+        node.len() < MAX_DEPTH
+    }
+
+    fn children(&mut self, node: &Self::Node) -> Vec<Self::Node> {
+        (0..10)
+            .map(|index| {
+                let mut child = node.clone();
+                child.push(index);
+                child
+            })
+            .collect()
+    }
+
+    fn node_widget(&mut self, node: &Self::Node) -> WidgetInstance {
+        let path = node.iter().map(u8::to_string).collect::<Vec<_>>().join(".");
+        format!("Item {path}").make_widget()
+    }
+}
+
+fn tree() -> impl MakeWidget {
+    let selected = Dynamic::<Option<Vec<u8>>>::default();
+    let selected_label = selected.map_each(|selected| format!("Selected: {selected:?}"));
+
+    TreeView::new(NumberedTree)
+        .selected(selected)
+        .expand()
+        .and(selected_label)
+        .into_rows()
+        .pad()
+}
+
+fn main() -> cushy::Result {
+    tree().run()
+}
+
+#[test]
+fn runs() {
+    cushy::example!(tree).untested_still_frame();
+}
@@ -1,8 +1,10 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use cushy::value::Dynamic;
+use cushy::animation::easings::EaseInOutSine;
+use cushy::value::{Dynamic, Source};
 use cushy::widget::MakeWidget;
 use cushy::widgets::label::{Displayable, LabelOverflow};
+use cushy::widgets::scroll::{ScrollAlign, ScrollIntoView};
 use cushy::widgets::slider::Slidable;
 use cushy::widgets::VirtualList;
 use cushy::Run;
@@ -19,6 +21,8 @@ fn list() -> impl MakeWidget {
             .overflow(LabelOverflow::Clip)
     });
     let content_changed = list.content_watcher().clone();
+    let scroller = list.scroller();
+    let last_row = count.map_each(|count| count.saturating_sub(1));
 
     "Count"
         .and(count.slider_between(0, 10_000).expand_horizontally())
@@ -27,6 +31,14 @@ fn list() -> impl MakeWidget {
                 .into_button()
                 .on_click(move |_| content_changed.notify()),
         )
+        .and("Scroll to End".into_button().on_click(move |_| {
+            scroller.scroll_to_row(
+                last_row.get(),
+                ScrollIntoView::new()
+                    .align(ScrollAlign::End)
+                    .animated(Duration::from_millis(250), EaseInOutSine),
+            );
+        }))
         .into_columns()
         .and(list.expand())
         .into_rows()
@@ -0,0 +1,498 @@
+//! Types for exposing Cushy interfaces to assistive technologies.
+//!
+//! When the `accessibility` feature is enabled, each window builds an
+//! [AccessKit](accesskit) tree from its mounted widgets every time it is
+//! redrawn. Widgets describe themselves by implementing
+//! [`Widget::accessibility_node`](crate::widget::Widget::accessibility_node).
+//!
+//! The current tree can be observed using
+//! [`Window::on_accessibility_update`](crate::window::Window::on_accessibility_update),
+//! which provides the [`TreeUpdate`]s needed to drive an AccessKit platform
+//! adapter, or inspected directly in headless tests using
+//! [`VirtualRecorder::accessibility_tree`](crate::window::VirtualRecorder::accessibility_tree).
+//!
+//! Cushy does not create a platform adapter itself. The adapter's
+//! [`ActionRequest`]s should be forwarded to
+//! [`WindowHandle::accessibility_action`](crate::window::WindowHandle::accessibility_action),
+//! which delivers them to the targeted widget using
+//! [`Widget::accessibility_action`](crate::widget::Widget::accessibility_action).
+
+use std::mem;
+
+use accesskit::Tree;
+pub use accesskit::{Action, ActionRequest, Node, NodeId, Role, Toggled, TreeUpdate};
+use ahash::{AHashMap, AHashSet};
+use figures::units::Px;
+use figures::{FloatConversion, Rect};
+
+use crate::context::WidgetContext;
+use crate::widget::MountedWidget;
+
+/// The id of the [`Role::Window`] node at the root of every
+/// [`AccessibilityTree`].
+pub const WINDOW_NODE_ID: NodeId = NodeId(u64::MAX);
+
+/// A snapshot of a window's accessibility tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityTree {
+    nodes: AHashMap<NodeId, Node>,
+    parents: AHashMap<NodeId, NodeId>,
+    focus: NodeId,
+    initialized: bool,
+}
+
+impl Default for AccessibilityTree {
+    fn default() -> Self {
+        Self {
+            nodes: AHashMap::new(),
+            parents: AHashMap::new(),
+            focus: WINDOW_NODE_ID,
+            initialized: false,
+        }
+    }
+}
+
+impl AccessibilityTree {
+    /// Returns the id of the root node of this tree.
+    #[must_use]
+    pub const fn root(&self) -> NodeId {
+        WINDOW_NODE_ID
+    }
+
+    /// Returns the id of the node that currently has keyboard focus.
+    ///
+    /// If no accessible widget has focus, the root node's id is returned.
+    #[must_use]
+    pub const fn focus(&self) -> NodeId {
+        self.focus
+    }
+
+    /// Returns the node with `id`, if it is present in this tree.
+    #[must_use]
+    pub fn get(&self, id: NodeId) -> Option<&Node> {
+        self.nodes.get(&id)
+    }
+
+    /// Returns the id of the parent of the node with `id`.
+    #[must_use]
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.parents.get(&id).copied()
+    }
+
+    /// Returns the number of nodes in this tree, including the root.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if this tree has not been built yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over all nodes in this tree, in depth-first order
+    /// starting at the root.
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &Node)> + '_ {
+        let mut stack = vec![WINDOW_NODE_ID];
+        std::iter::from_fn(move || loop {
+            let id = stack.pop()?;
+            let Some(node) = self.nodes.get(&id) else {
+                continue;
+            };
+            stack.extend(node.children().iter().rev().copied());
+            return Some((id, node));
+        })
+    }
+
+    /// Returns the first node in depth-first order that `predicate` returns
+    /// true for.
+    pub fn find(&self, mut predicate: impl FnMut(&Node) -> bool) -> Option<(NodeId, &Node)> {
+        self.iter().find(|(_, node)| predicate(node))
+    }
+
+    /// Returns the first node in depth-first order that has `role`.
+    #[must_use]
+    pub fn find_by_role(&self, role: Role) -> Option<(NodeId, &Node)> {
+        self.find(|node| node.role() == role)
+    }
+
+    /// Returns the first node in depth-first order whose [name](name_of) is
+    /// `name`.
+    #[must_use]
+    pub fn find_by_name(&self, name: &str) -> Option<(NodeId, &Node)> {
+        self.find(|node| name_of(node) == Some(name))
+    }
+
+    /// Returns an update that describes this entire tree.
+    ///
+    /// This is useful when initializing a platform adapter after the window
+    /// has already been shown.
+    #[must_use]
+    pub fn full_update(&self) -> TreeUpdate {
+        TreeUpdate {
+            nodes: self.iter().map(|(id, node)| (id, node.clone())).collect(),
+            tree: Some(Self::tree_info()),
+            focus: self.focus,
+        }
+    }
+
+    fn tree_info() -> Tree {
+        let mut tree = Tree::new(WINDOW_NODE_ID);
+        tree.toolkit_name = Some(String::from("Cushy"));
+        tree.toolkit_version = Some(String::from(env!("CARGO_PKG_VERSION")));
+        tree
+    }
+
+    /// Replaces the contents of this tree with `nodes`, returning an update
+    /// containing only the nodes that changed. `None` is returned if nothing
+    /// changed.
+    pub(crate) fn update(
+        &mut self,
+        nodes: Vec<(NodeId, Node)>,
+        focus: NodeId,
+    ) -> Option<TreeUpdate> {
+        let mut previous = mem::take(&mut self.nodes);
+        self.parents.clear();
+        let mut changed = Vec::new();
+        for (id, node) in nodes {
+            for child in node.children() {
+                self.parents.insert(*child, id);
+            }
+            if previous.remove(&id).as_ref() != Some(&node) {
+                changed.push((id, node.clone()));
+            }
+            self.nodes.insert(id, node);
+        }
+
+        let tree = (!self.initialized).then(Self::tree_info);
+        self.initialized = true;
+        if changed.is_empty() && tree.is_none() && previous.is_empty() && self.focus == focus {
+            return None;
+        }
+        self.focus = focus;
+
+        Some(TreeUpdate {
+            nodes: changed,
+            tree,
+            focus,
+        })
+    }
+}
+
+/// Returns the name of `node` as it would be presented by an assistive
+/// technology.
+///
+/// For [`Role::Label`] nodes this is the node's value, as AccessKit expects
+/// the text of a label to be stored there. For all other nodes, this is the
+/// node's label.
+#[must_use]
+pub fn name_of(node: &Node) -> Option<&str> {
+    if node.role() == Role::Label {
+        node.value().or_else(|| node.label())
+    } else {
+        node.label()
+    }
+}
+
+fn name_from_contents(role: Role) -> bool {
+    matches!(
+        role,
+        Role::Button
+            | Role::DefaultButton
+            | Role::CheckBox
+            | Role::RadioButton
+            | Role::Switch
+            | Role::MenuItem
+            | Role::MenuItemCheckBox
+            | Role::MenuItemRadio
+            | Role::Tab
+            | Role::Link
+            | Role::ListItem
+            | Role::TreeItem
+            | Role::Cell
+            | Role::ColumnHeader
+            | Role::RowHeader
+    )
+}
+
+/// Builds the nodes for the accessibility tree rooted at `context`'s widget,
+/// returning all nodes and the id of the node that should receive focus.
+pub(crate) fn build_nodes(
+    context: &mut WidgetContext<'_>,
+    window_title: Option<String>,
+) -> (Vec<(NodeId, Node)>, NodeId) {
+    let mut nodes = Vec::new();
+    let mut children = Vec::new();
+    collect(context, &mut nodes, &mut children);
+
+    let mut window = Node::new(Role::Window);
+    window.set_children(children);
+    if let Some(title) = window_title.filter(|title| !title.is_empty()) {
+        window.set_label(title);
+    }
+    if let Some(layout) = context.last_layout() {
+        window.set_bounds(bounds(layout));
+    }
+    nodes.push((WINDOW_NODE_ID, window));
+
+    let focus = focused_node(context, &nodes);
+    (nodes, focus)
+}
+
+fn collect(
+    context: &mut WidgetContext<'_>,
+    nodes: &mut Vec<(NodeId, Node)>,
+    siblings: &mut Vec<NodeId>,
+) {
+    let widget = context.widget().clone();
+    let Some(layout) = widget.last_layout() else {
+        return;
+    };
+    let node = widget.lock().as_widget().accessibility_node(context);
+    let Some(mut node) = node else {
+        collect_children(context, &widget, nodes, siblings);
+        return;
+    };
+
+    let first_descendant = nodes.len();
+    let mut children = Vec::new();
+    collect_children(context, &widget, nodes, &mut children);
+    node.set_children(children);
+    node.set_bounds(bounds(layout));
+    if !context.enabled() {
+        node.set_disabled();
+    }
+    if node.label().is_none() && name_from_contents(node.role()) {
+        let name = nodes[first_descendant..]
+            .iter()
+            .filter(|(_, child)| child.role() == Role::Label)
+            .filter_map(|(_, child)| name_of(child))
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !name.is_empty() {
+            node.set_label(name);
+        }
+    }
+
+    let id = NodeId::from(widget.id());
+    nodes.push((id, node));
+    siblings.push(id);
+}
+
+fn collect_children(
+    context: &mut WidgetContext<'_>,
+    widget: &MountedWidget,
+    nodes: &mut Vec<(NodeId, Node)>,
+    siblings: &mut Vec<NodeId>,
+) {
+    for child in widget.children() {
+        collect(&mut context.for_other(&child), nodes, siblings);
+    }
+}
+
+fn focused_node(context: &WidgetContext<'_>, nodes: &[(NodeId, Node)]) -> NodeId {
+    let accessible = nodes.iter().map(|(id, _)| *id).collect::<AHashSet<_>>();
+    let mut focused = context
+        .tree
+        .focused_widget()
+        .and_then(|id| context.tree.widget_from_node(id));
+    while let Some(widget) = focused {
+        let id = NodeId::from(widget.id());
+        if accessible.contains(&id) {
+            return id;
+        }
+        focused = widget.parent();
+    }
+    WINDOW_NODE_ID
+}
+
+fn bounds(layout: Rect<Px>) -> accesskit::Rect {
+    let x0 = f64::from(layout.origin.x.into_float());
+    let y0 = f64::from(layout.origin.y.into_float());
+    accesskit::Rect {
+        x0,
+        y0,
+        x1: x0 + f64::from(layout.size.width.into_float()),
+        y1: y0 + f64::from(layout.size.height.into_float()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessibilityTree, Node, NodeId, Role, WINDOW_NODE_ID};
+
+    fn window_with(children: &[NodeId]) -> (NodeId, Node) {
+        let mut window = Node::new(Role::Window);
+        window.set_children(children.to_vec());
+        (WINDOW_NODE_ID, window)
+    }
+
+    fn label(text: &str) -> Node {
+        let mut node = Node::new(Role::Label);
+        node.set_value(text);
+        node
+    }
+
+    #[test]
+    fn updates_only_include_changes() {
+        let mut tree = AccessibilityTree::default();
+        let first = tree
+            .update(
+                vec![(NodeId(0), label("a")), window_with(&[NodeId(0)])],
+                WINDOW_NODE_ID,
+            )
+            .expect("initial update");
+        assert!(first.tree.is_some());
+        assert_eq!(first.nodes.len(), 2);
+
+        assert!(tree
+            .update(
+                vec![(NodeId(0), label("a")), window_with(&[NodeId(0)])],
+                WINDOW_NODE_ID,
+            )
+            .is_none());
+
+        let changed = tree
+            .update(
+                vec![(NodeId(0), label("b")), window_with(&[NodeId(0)])],
+                WINDOW_NODE_ID,
+            )
+            .expect("label changed");
+        assert!(changed.tree.is_none());
+        assert_eq!(changed.nodes.len(), 1);
+        assert_eq!(changed.nodes[0].0, NodeId(0));
+
+        let (id, _) = tree.find_by_name("b").expect("label present");
+        assert_eq!(id, NodeId(0));
+        assert_eq!(tree.parent(id), Some(WINDOW_NODE_ID));
+    }
+
+    #[test]
+    fn removing_nodes_produces_update() {
+        let mut tree = AccessibilityTree::default();
+        tree.update(
+            vec![(NodeId(0), label("a")), window_with(&[NodeId(0)])],
+            WINDOW_NODE_ID,
+        );
+        let update = tree
+            .update(vec![window_with(&[])], WINDOW_NODE_ID)
+            .expect("child removed");
+        assert_eq!(update.nodes.len(), 1);
+        assert_eq!(update.nodes[0].0, WINDOW_NODE_ID);
+        assert!(tree.get(NodeId(0)).is_none());
+    }
+
+    #[test]
+    fn mounted_widgets() {
+        use accesskit::{Action, Toggled};
+
+        use crate::value::Dynamic;
+        use crate::widget::MakeWidget;
+
+        let checked = Dynamic::new(true);
+        let recorder = "Click Me"
+            .into_button()
+            .and("Enabled".into_checkbox(checked))
+            .and("Hello")
+            .into_rows()
+            .build_recorder()
+            .finish()
+            .expect("error creating recorder");
+        let tree = recorder.accessibility_tree();
+
+        let (button, node) = tree.find_by_role(Role::Button).expect("button node");
+        assert_eq!(super::name_of(node), Some("Click Me"));
+        assert!(node.supports_action(Action::Click));
+        assert!(node.supports_action(Action::Focus));
+
+        let (checkbox, node) = tree.find_by_role(Role::CheckBox).expect("checkbox node");
+        assert_eq!(node.toggled(), Some(Toggled::True));
+
+        let (label, _) = tree.find_by_name("Hello").expect("label node");
+        // The stack has no node of its own, so its children are attached to
+        // the window.
+        assert_eq!(tree.parent(button), Some(WINDOW_NODE_ID));
+        assert_eq!(tree.parent(checkbox), Some(WINDOW_NODE_ID));
+        assert_eq!(tree.parent(label), Some(WINDOW_NODE_ID));
+    }
+
+    #[test]
+    fn action_requests() {
+        use accesskit::{Action, ActionRequest};
+
+        use crate::value::{Destination, Dynamic, Source};
+        use crate::widget::{MakeWidget, HANDLED, IGNORED};
+        use crate::widgets::Slider;
+
+        let clicks = Dynamic::new(0);
+        let value = Dynamic::new(5_u8);
+        let mut recorder = "Click Me"
+            .into_button()
+            .on_click({
+                let clicks = clicks.clone();
+                move |_| clicks.set(clicks.get() + 1)
+            })
+            .and(Slider::<u8>::new(value.clone(), 0, 10))
+            .and("Hello")
+            .into_rows()
+            .build_recorder()
+            .finish()
+            .expect("error creating recorder");
+        let tree = recorder.accessibility_tree();
+        let (button, _) = tree.find_by_role(Role::Button).expect("button node");
+        let (slider, _) = tree.find_by_role(Role::Slider).expect("slider node");
+        let (label, _) = tree.find_by_name("Hello").expect("label node");
+        let request = |action, target| ActionRequest {
+            action,
+            target,
+            data: None,
+        };
+
+        assert_eq!(
+            recorder
+                .window
+                .accessibility_action(&request(Action::Click, button)),
+            HANDLED
+        );
+        assert_eq!(clicks.get(), 1);
+
+        assert_eq!(
+            recorder
+                .window
+                .accessibility_action(&request(Action::Focus, button)),
+            HANDLED
+        );
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(recorder.accessibility_tree().focus(), button);
+
+        assert_eq!(
+            recorder
+                .window
+                .accessibility_action(&request(Action::Increment, slider)),
+            HANDLED
+        );
+        assert_eq!(value.get(), 6);
+        assert_eq!(
+            recorder
+                .window
+                .accessibility_action(&request(Action::Decrement, slider)),
+            HANDLED
+        );
+        assert_eq!(value.get(), 5);
+
+        // Labels do not advertise any actions.
+        assert_eq!(
+            recorder
+                .window
+                .accessibility_action(&request(Action::Increment, label)),
+            IGNORED
+        );
+        assert_eq!(
+            recorder
+                .window
+                .accessibility_action(&request(Action::Click, WINDOW_NODE_ID)),
+            IGNORED
+        );
+    }
+}
@@ -37,11 +37,12 @@
 //! assert_eq!(reader.get(), 100);
 //! ```
 
+use std::cell::RefCell;
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display};
 use std::ops::{ControlFlow, Deref, Div, DivAssign, Mul, MulAssign, Sub};
 use std::str::FromStr;
-use std::sync::OnceLock;
+use std::sync::{Arc, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
@@ -68,6 +69,50 @@ pub(crate) fn spawn(app: Cushy) {
     let _ignored = thread_state(Some(app));
 }
 
+thread_local! {
+    static SPAWNING_FOR: RefCell<Option<SpawnedAnimations>> = const { RefCell::new(None) };
+}
+
+/// A collection of animations spawned while handling events for a single
+/// window.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SpawnedAnimations(Arc<Mutex<Vec<LotId>>>);
+
+impl SpawnedAnimations {
+    /// Records all animations spawned on the current thread into this
+    /// collection until the returned guard is dropped.
+    pub(crate) fn track(&self) -> TrackingAnimations {
+        TrackingAnimations(SPAWNING_FOR.with(|spawning| spawning.replace(Some(self.clone()))))
+    }
+
+    /// Returns true if any of the recorded animations have not completed.
+    pub(crate) fn running(&self) -> bool {
+        let mut spawned = self.0.lock();
+        let state = thread_state(None);
+        spawned.retain(|id| state.running.contains(id));
+        !spawned.is_empty()
+    }
+
+    fn spawned(id: LotId) {
+        SPAWNING_FOR.with(|spawning| {
+            if let Some(spawned) = &*spawning.borrow() {
+                spawned.0.lock().push(id);
+            }
+        });
+    }
+}
+
+/// Tracks spawned animations for a [`SpawnedAnimations`] until dropped.
+#[must_use]
+pub(crate) struct TrackingAnimations(Option<SpawnedAnimations>);
+
+impl Drop for TrackingAnimations {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        SPAWNING_FOR.with(|spawning| *spawning.borrow_mut() = previous);
+    }
+}
+
 fn thread_state(app: Option<Cushy>) -> MutexGuard<'static, Animating> {
     static THREAD: OnceLock<()> = OnceLock::new();
     THREAD.get_or_init(move || {
@@ -462,7 +507,11 @@ where
 
 impl Spawn for Box<dyn Animate> {
     fn spawn(self) -> AnimationHandle {
-        thread_state(None).spawn(self)
+        let handle = thread_state(None).spawn(self);
+        if let Some(id) = handle.0 {
+            SpawnedAnimations::spawned(id);
+        }
+        handle
     }
 }
 
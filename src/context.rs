@@ -21,10 +21,14 @@ use crate::styles::components::{
     CornerRadius, FontFamily, FontStyle, FontWeight, HighlightColor, LayoutOrder, LineHeight,
     Opacity, OutlineWidth, TextSize, WidgetBackground,
 };
-use crate::styles::{ComponentDefinition, Dimension, FontFamilyList, Styles, Theme, ThemePair};
+use crate::styles::{
+    ComponentDefinition, Dimension, FontFamilyList, StyleSheet, Styles, Theme, ThemePair,
+    WidgetStates,
+};
 use crate::tree::Tree;
 use crate::value::{IntoValue, Source, Value};
 use crate::widget::{EventHandling, MountedWidget, RootBehavior, WidgetId, WidgetInstance};
+use crate::widgets::scroll::ScrollIntoView;
 use crate::window::{
     CursorState, DeviceId, KeyEvent, PlatformWindow, ThemeMode, WidgetCursorState,
 };
@@ -121,6 +125,108 @@ impl<'context> EventContext<'context> {
             .mouse_up(location, device_id, button, self);
     }
 
+    /// Delivers a click of the left mouse button at `location` to the top-most
+    /// child of this widget at that location.
+    ///
+    /// This allows widgets that track touches, such as [`Scroll`], to forward
+    /// taps to their contents. The click is offered to each parent of the
+    /// child until it is handled, stopping before reaching this widget.
+    ///
+    /// [`Scroll`]: crate::widgets::Scroll
+    pub(crate) fn click_child_at(&mut self, location: Point<Px>, device_id: DeviceId) {
+        let this = self.current_node.clone();
+        let Some(layout) = self.last_layout() else {
+            return;
+        };
+        let mut target = self.child_at(location);
+        let location = layout.origin + location;
+
+        while let Some(widget) = target {
+            if widget.id() == this.id() {
+                break;
+            }
+            let mut context = self.for_other(&widget);
+            if let Some(layout) = context.last_layout() {
+                let relative = location - layout.origin;
+                if context
+                    .mouse_down(relative, device_id, MouseButton::Left)
+                    .is_break()
+                {
+                    context.mouse_up(Some(relative), device_id, MouseButton::Left);
+                    break;
+                }
+            }
+            drop(context);
+            target = widget.parent();
+        }
+    }
+
+    /// Returns the top-most child of this widget whose
+    /// [`hit_test()`](crate::widget::Widget::hit_test) accepts `location`.
+    ///
+    /// This allows container widgets that accept input themselves to avoid
+    /// intercepting input meant for their children.
+    pub(crate) fn child_at(&mut self, location: Point<Px>) -> Option<MountedWidget> {
+        let this = self.current_node.clone();
+        let location = self.last_layout()?.origin + location;
+        for widget in self.tree.widgets_under_point(location) {
+            if widget.id() == this.id() || !self.tree.is_child(widget.node_id, this.instance()) {
+                continue;
+            }
+            let mut context = self.for_other(&widget);
+            let Some(layout) = context.last_layout() else {
+                continue;
+            };
+            if context.hit_test(location - layout.origin) {
+                drop(context);
+                return Some(widget);
+            }
+        }
+        None
+    }
+
+    /// Invokes [`Widget::touch_down()`](crate::widget::Widget::touch_down) on
+    /// this context's widget and returns the result.
+    pub fn touch_down(&mut self, location: Point<Px>, device_id: DeviceId) -> EventHandling {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .touch_down(location, device_id, self)
+    }
+
+    /// Invokes
+    /// [`Widget::accessibility_action()`](crate::widget::Widget::accessibility_action)
+    /// on this context's widget and returns the result.
+    #[cfg(feature = "accessibility")]
+    pub fn accessibility_action(&mut self, request: &accesskit::ActionRequest) -> EventHandling {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .accessibility_action(request, self)
+    }
+
+    /// Invokes [`Widget::touch_moved()`](crate::widget::Widget::touch_moved)
+    /// on this context's widget.
+    pub fn touch_moved(&mut self, location: Point<Px>, device_id: DeviceId) {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .touch_moved(location, device_id, self);
+    }
+
+    /// Invokes [`Widget::touch_up()`](crate::widget::Widget::touch_up) on this
+    /// context's widget.
+    pub fn touch_up(&mut self, location: Point<Px>, device_id: DeviceId, cancelled: bool) {
+        self.current_node
+            .clone()
+            .lock()
+            .as_widget()
+            .touch_up(location, device_id, cancelled, self);
+    }
+
     /// Invokes [`Widget::keyboard_input()`](crate::widget::Widget::keyboard_input) on this
     /// context's widget and returns the result.
     pub fn keyboard_input(
@@ -171,6 +277,7 @@ impl<'context> EventContext<'context> {
                 .lock()
                 .as_widget()
                 .hover(location - last_layout.origin, &mut context);
+            context.invalidate_if_state_styled();
 
             if cursor.is_none() {
                 cursor = widget_cursor;
@@ -182,6 +289,7 @@ impl<'context> EventContext<'context> {
         for unhovered in changes.unhovered {
             let mut context = self.for_other(&unhovered);
             unhovered.lock().as_widget().unhover(&mut context);
+            context.invalidate_if_state_styled();
         }
     }
 
@@ -192,6 +300,7 @@ impl<'context> EventContext<'context> {
         for old_hover in changes.unhovered {
             let mut old_hover_context = self.for_other(&old_hover);
             old_hover.lock().as_widget().unhover(&mut old_hover_context);
+            old_hover_context.invalidate_if_state_styled();
         }
 
         self.window_mut().set_cursor(Cursor::default());
@@ -211,6 +320,7 @@ impl<'context> EventContext<'context> {
                     if let Some(old) = old {
                         let mut old_context = self.for_other(&old);
                         old.lock().as_widget().deactivate(&mut old_context);
+                        old_context.invalidate_if_state_styled();
                     }
                     true
                 }
@@ -219,10 +329,9 @@ impl<'context> EventContext<'context> {
             if new {
                 let active = self.pending_state.active.and_then(|w| self.tree.widget(w));
                 if let Some(active) = &active {
-                    active
-                        .lock()
-                        .as_widget()
-                        .activate(&mut self.for_other(active));
+                    let mut context = self.for_other(active);
+                    active.lock().as_widget().activate(&mut context);
+                    context.invalidate_if_state_styled();
                 }
             } else {
                 break;
@@ -267,6 +376,7 @@ impl<'context> EventContext<'context> {
                         let mut old = old_widget.lock();
                         if old.as_widget().allow_blur(&mut old_context) {
                             old.as_widget().blur(&mut old_context);
+                            old_context.invalidate_if_state_styled();
                         } else {
                             // This widget is rejecting the focus change.
                             drop(old_context);
@@ -281,7 +391,19 @@ impl<'context> EventContext<'context> {
             };
             if new {
                 if let Some(focus) = self.pending_state.focus.and_then(|w| self.tree.widget(w)) {
-                    focus.lock().as_widget().focus(&mut self.for_other(&focus));
+                    let mut context = self.for_other(&focus);
+                    focus.lock().as_widget().focus(&mut context);
+                    context.invalidate_if_state_styled();
+                    drop(context);
+                    // Ensure the newly focused widget is visible, unless a
+                    // more specific scroll has already been requested.
+                    self.pending_state
+                        .scroll_into_view
+                        .get_or_insert_with(|| ScrollRequest {
+                            widget: focus.id(),
+                            region: None,
+                            options: ScrollIntoView::new(),
+                        });
                 }
             } else {
                 break;
@@ -293,6 +415,33 @@ impl<'context> EventContext<'context> {
         }
     }
 
+    fn apply_pending_scroll(&mut self) {
+        let Some(request) = self.pending_state.scroll_into_view.take() else {
+            return;
+        };
+        let Some(widget) = self.tree.widget(request.widget) else {
+            return;
+        };
+        let Some(layout) = widget.last_layout() else {
+            return;
+        };
+
+        let mut region = request.region.map_or(layout, |region| {
+            Rect::new(layout.origin + region.origin, region.size)
+        });
+        let mut ancestor = widget.parent();
+        while let Some(current) = ancestor {
+            let mut context = self.for_other(&current);
+            region =
+                current
+                    .lock()
+                    .as_widget()
+                    .scroll_into_view(region, &request.options, &mut context);
+            drop(context);
+            ancestor = current.parent();
+        }
+    }
+
     pub(crate) fn apply_pending_state(&mut self) {
         // These two blocks apply active/focus in a loop to pick up the event
         // where during the process of calling deactivate/blur or activate/focus
@@ -304,6 +453,8 @@ impl<'context> EventContext<'context> {
 
         self.apply_pending_focus();
 
+        self.apply_pending_scroll();
+
         // Check that our hover widget still exists. If not, we should try to find a new one.
         if let Some(hover) = self.tree.hovered_widget() {
             if self.tree.widget_from_node(hover).is_none() {
@@ -975,6 +1126,7 @@ pub struct WidgetContext<'context> {
     pending_state: PendingState<'context>,
     font_state: &'context mut FontState,
     effective_styles: Styles,
+    style_sheet_styles: Styles,
     cache: WidgetCacheKey,
     #[cfg(feature = "localization")]
     locale: Value<LanguageIdentifier>,
@@ -1009,11 +1161,13 @@ impl<'context> WidgetContext<'context> {
                     .active_widget()
                     .and_then(|id| tree.widget_from_node(id).map(|w| w.id())),
                 focus_is_advancing: false,
+                scroll_into_view: None,
                 unmount_queue: Vec::new(),
                 unmounting: false,
             }),
             tree,
             effective_styles,
+            style_sheet_styles: Styles::new(),
             cache: WidgetCacheKey {
                 kludgine_id: Some(window.kludgine_id()),
                 theme_mode,
@@ -1042,6 +1196,7 @@ impl<'context> WidgetContext<'context> {
         } else {
             context.locale = Value::Constant(context.localizations.effective_locale(&context));
         }
+        context.style_sheet_styles = context.resolve_style_sheet();
 
         context
     }
@@ -1057,6 +1212,7 @@ impl<'context> WidgetContext<'context> {
             pending_state: self.pending_state.borrowed(),
             cache: self.cache,
             effective_styles: self.effective_styles.clone(),
+            style_sheet_styles: self.style_sheet_styles.clone(),
             cursor: &mut *self.cursor,
             #[cfg(feature = "localization")]
             locale: self.locale.clone(),
@@ -1092,8 +1248,9 @@ impl<'context> WidgetContext<'context> {
             } else {
                 self.locale.clone()
             };
-            WidgetContext {
+            let mut context = WidgetContext {
                 effective_styles,
+                style_sheet_styles: Styles::new(),
                 cache: WidgetCacheKey {
                     kludgine_id: self.cache.kludgine_id,
                     theme_mode,
@@ -1110,10 +1267,46 @@ impl<'context> WidgetContext<'context> {
                 locale,
                 #[cfg(feature = "localization")]
                 localizations: self.localizations,
-            }
+            };
+            context.style_sheet_styles = context.resolve_style_sheet();
+            context
         })
     }
 
+    /// Returns the combined styles of all style sheet rules that match this
+    /// widget in its current state.
+    fn resolve_style_sheet(&self) -> Styles {
+        let style_sheets = self.current_node.style_sheets();
+        if style_sheets.is_empty() {
+            return Styles::new();
+        }
+
+        let widget = self.current_node.instance();
+        widget.invalidate_when_classes_change(self);
+        style_sheets.resolve(
+            widget,
+            WidgetStates {
+                hovered: self.hovered(),
+                active: self.active(),
+                focused: self.focused(false),
+                disabled: !self.enabled(),
+            },
+            self,
+        )
+    }
+
+    /// Invalidates this widget if a style sheet rule that applies to it depends
+    /// on its hover, active, or focus state.
+    pub(crate) fn invalidate_if_state_styled(&self) {
+        if self
+            .current_node
+            .style_sheets()
+            .depends_on_state(self.current_node.instance())
+        {
+            self.handle().invalidate(self.current_node.id());
+        }
+    }
+
     /// Returns true if `possible_parent` is in this widget's parent list.
     #[must_use]
     pub fn is_child_of(&self, possible_parent: &WidgetInstance) -> bool {
@@ -1156,6 +1349,34 @@ impl<'context> WidgetContext<'context> {
         self.pending_state.focus = Some(self.current_node.id());
     }
 
+    /// Scrolls each scrolling ancestor of this widget so that this widget is
+    /// visible.
+    ///
+    /// Scrolling is deferred until after all contexts for the currently firing
+    /// event are dropped, and uses the widget's most recent layout. Widgets
+    /// that have not been laid out cannot be scrolled into view. Focusing a
+    /// widget automatically scrolls it into view.
+    pub fn scroll_into_view(&mut self, options: ScrollIntoView) {
+        self.pending_state.scroll_into_view = Some(ScrollRequest {
+            widget: self.current_node.id(),
+            region: None,
+            options,
+        });
+    }
+
+    /// Scrolls each scrolling ancestor of this widget so that `region` is
+    /// visible. `region` is relative to this widget's layout.
+    ///
+    /// See [`scroll_into_view()`](Self::scroll_into_view) for more
+    /// information.
+    pub fn scroll_region_into_view(&mut self, region: Rect<Px>, options: ScrollIntoView) {
+        self.pending_state.scroll_into_view = Some(ScrollRequest {
+            widget: self.current_node.id(),
+            region: Some(region),
+            options,
+        });
+    }
+
     pub(crate) fn clear_focus(&mut self) {
         self.pending_state.focus = None;
     }
@@ -1281,6 +1502,15 @@ impl<'context> WidgetContext<'context> {
         self.current_node.attach_styles(styles.into_value());
     }
 
+    /// Attaches `style_sheet` to the widget hierarchy for this widget.
+    ///
+    /// Style queries for children will include components from the rules in
+    /// this style sheet that match the child.
+    pub fn attach_style_sheet(&self, style_sheet: impl IntoValue<StyleSheet>) {
+        self.current_node
+            .attach_style_sheet(style_sheet.into_value());
+    }
+
     /// Attaches `theme` to the widget hierarchy for this widget.
     ///
     /// All children nodes will access this theme in their contexts.
@@ -1306,28 +1536,43 @@ impl<'context> WidgetContext<'context> {
 
     /// Queries the widget hierarchy for a single style component.
     ///
-    /// This function traverses up the widget hierarchy looking for the
-    /// component being requested. If a matching component is found, it will be
-    /// returned. Otherwise, the default value will be returned.
+    /// Components are resolved in this order:
+    ///
+    /// 1. Components applied directly to this widget.
+    /// 2. Components from the most specific [`StyleSheet`] rule matching this
+    ///    widget.
+    /// 3. Components inherited from this widget's parents.
+    /// 4. The component's default value.
     #[must_use]
     pub fn get<Component: ComponentDefinition>(
         &self,
         query: &Component,
     ) -> Component::ComponentType {
-        self.effective_styles.get(query, self)
+        self.try_get(query)
+            .unwrap_or_else(|| query.default_value(self))
     }
 
     /// Queries the widget hierarchy for a single style component.
     ///
-    /// This function traverses up the widget hierarchy looking for the
-    /// component being requested. If a matching component is found, it will be
-    /// returned.
+    /// Components applied directly to this widget are returned first. Next,
+    /// any [`StyleSheet`] rules that match this widget are checked, followed by
+    /// components inherited from this widget's parents.
     #[must_use]
     pub fn try_get<Component: ComponentDefinition>(
         &self,
         query: &Component,
     ) -> Option<Component::ComponentType> {
-        self.effective_styles.try_get(query, self)
+        if self.style_sheet_styles.is_empty() {
+            return self.effective_styles.try_get(query, self);
+        }
+
+        self.effective_styles
+            .try_get_where_inherited(query, false, self)
+            .or_else(|| self.style_sheet_styles.try_get(query, self))
+            .or_else(|| {
+                self.effective_styles
+                    .try_get_where_inherited(query, true, self)
+            })
     }
 
     /// Returns the window containing this widget.
@@ -1436,10 +1681,17 @@ struct PendingWidgetState {
     focus_is_advancing: bool,
     focus: Option<WidgetId>,
     active: Option<WidgetId>,
+    scroll_into_view: Option<ScrollRequest>,
     unmounting: bool,
     unmount_queue: Vec<WidgetId>,
 }
 
+struct ScrollRequest {
+    widget: WidgetId,
+    region: Option<Rect<Px>>,
+    options: ScrollIntoView,
+}
+
 impl PendingState<'_> {
     pub fn borrowed(&mut self) -> PendingState<'_> {
         PendingState::Borrowed(self)
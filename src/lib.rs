@@ -13,6 +13,8 @@ extern crate self as cushy;
 #[macro_use]
 mod utils;
 
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
 pub mod animation;
 pub mod context;
 pub mod graphics;
@@ -29,7 +29,8 @@ use crate::widget::{
     MakeWidget, MakeWidgetWithTag, Notify, OnceCallback, WidgetId, WidgetInstance, WidgetList,
 };
 use crate::widgets::checkbox::CheckboxState;
-use crate::widgets::{Checkbox, Radio, Select, Space, Switcher};
+use crate::widgets::layers::OverlayLayer;
+use crate::widgets::{Checkbox, ComboBox, Radio, Select, Space, Switcher};
 use crate::window::WindowHandle;
 
 /// A source of one or more `T` values.
@@ -1526,6 +1527,23 @@ impl<T> Dynamic<T> {
         Select::new(widget_value, self.clone(), label)
     }
 
+    /// Returns a new [`ComboBox`] that updates this dynamic to the option
+    /// chosen from `options`. The list of options is shown in `overlay`.
+    #[must_use]
+    pub fn new_combo_box(
+        &self,
+        options: impl IntoValue<Vec<T>>,
+        overlay: &OverlayLayer,
+    ) -> ComboBox<T>
+    where
+        Self: Clone,
+        // As with new_select, this bound prevents calling new_combo_box on
+        // types that the widget implementation does not support.
+        T: Clone + Display + PartialEq,
+    {
+        ComboBox::new(self.clone(), options, overlay)
+    }
+
     /// Validates the contents of this dynamic using the `check` function,
     /// returning a dynamic that contains the validation status.
     #[must_use]
@@ -4547,6 +4565,347 @@ where
     }
 }
 
+/// A history of changes to one or more [`Dynamic`] values that can be undone
+/// and redone.
+///
+/// Values are added to the history using [`UndoStack::track`]. Changes to
+/// tracked values are collected until the history is checkpointed, at which
+/// point all of the collected changes are recorded as a single step. A
+/// checkpoint occurs when [`UndoStack::checkpoint`] is called, when an
+/// [`UndoTransaction`] begins or is committed, and before a step is undone or
+/// redone. Because steps are only recorded at these points, the grouping of
+/// changes never depends on when change callbacks are executed.
+///
+/// ```rust
+/// use cushy::value::{Destination, Dynamic, Source, UndoStack};
+///
+/// let history = UndoStack::default();
+/// let name = Dynamic::new(String::from("Ferris"));
+/// let subscribed = Dynamic::new(false);
+/// history.track(&name);
+/// history.track(&subscribed);
+///
+/// let transaction = history.transaction();
+/// name.set(String::from("Crab"));
+/// subscribed.set(true);
+/// drop(transaction);
+///
+/// assert!(history.undo());
+/// assert_eq!(name.get(), "Ferris");
+/// assert!(!subscribed.get());
+///
+/// assert!(history.redo());
+/// assert_eq!(name.get(), "Crab");
+/// assert!(subscribed.get());
+/// ```
+#[derive(Clone)]
+pub struct UndoStack {
+    data: Arc<Mutex<UndoStackData>>,
+}
+
+impl Default for UndoStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UndoStack {
+    /// Returns a new, empty history.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            data: Arc::new(Mutex::new(UndoStackData {
+                tracked: Vec::new(),
+                undo: Vec::new(),
+                redo: Vec::new(),
+                transaction_depth: 0,
+                can_undo: Dynamic::new(false),
+                can_redo: Dynamic::new(false),
+            })),
+        }
+    }
+
+    /// Records all future changes to `dynamic` in this history.
+    pub fn track<T>(&self, dynamic: &Dynamic<T>)
+    where
+        T: Clone + PartialEq + Send + 'static,
+    {
+        let tracked = Arc::new(Mutex::new(TrackedDynamic {
+            dynamic: dynamic.downgrade(),
+            last: dynamic.get(),
+        }));
+        // The callback only keeps `can_undo` up to date. Changes are recorded
+        // when the history is checkpointed.
+        let data = Arc::downgrade(&self.data);
+        let callback = dynamic.for_each_subsequent_try(move || {
+            let data = data.upgrade().ok_or(CallbackDisconnected)?;
+            data.lock().update_state();
+            Ok(())
+        });
+
+        let mut data = self.data.lock();
+        data.tracked.push(Box::new(TrackedHandle {
+            tracked,
+            _callback: callback,
+        }));
+    }
+
+    /// Records all changes to tracked values since the last checkpoint as a
+    /// single step in this history.
+    ///
+    /// While a transaction is active, this function does nothing, as the
+    /// changes are recorded when the transaction is committed.
+    pub fn checkpoint(&self) {
+        let mut data = self.data.lock();
+        if data.transaction_depth == 0 {
+            data.record_pending_changes();
+        }
+    }
+
+    /// Begins a transaction that groups all changes to tracked values into a
+    /// single step in this history.
+    ///
+    /// Any changes made before the transaction began are recorded as their
+    /// own step. The transaction is committed when the returned guard is
+    /// dropped. Transactions can be nested, and the changes are grouped until
+    /// the outermost transaction is committed.
+    pub fn transaction(&self) -> UndoTransaction {
+        let mut data = self.data.lock();
+        if data.transaction_depth == 0 {
+            data.record_pending_changes();
+        }
+        data.transaction_depth += 1;
+        UndoTransaction(self.clone())
+    }
+
+    /// Reverts the most recent step in this history. Returns true if a step
+    /// was undone.
+    ///
+    /// Any changes made since the last checkpoint are recorded as a step
+    /// before undoing.
+    #[allow(clippy::must_use_candidate)]
+    pub fn undo(&self) -> bool {
+        let mut data = self.data.lock();
+        data.record_pending_changes();
+        let Some(changes) = data.undo.pop() else {
+            return false;
+        };
+        drop(data);
+
+        // The changes are applied without holding the lock so that code
+        // reacting to the tracked values is free to use this history.
+        for change in changes.iter().rev() {
+            change.undo();
+        }
+
+        let mut data = self.data.lock();
+        data.redo.push(changes);
+        data.update_state();
+        true
+    }
+
+    /// Re-applies the most recently undone step in this history. Returns true
+    /// if a step was redone.
+    ///
+    /// If any changes were made since the last checkpoint, they are recorded
+    /// as a new step, which discards the steps that could be redone.
+    #[allow(clippy::must_use_candidate)]
+    pub fn redo(&self) -> bool {
+        let mut data = self.data.lock();
+        data.record_pending_changes();
+        let Some(changes) = data.redo.pop() else {
+            return false;
+        };
+        drop(data);
+
+        for change in &changes {
+            change.redo();
+        }
+
+        let mut data = self.data.lock();
+        data.undo.push(changes);
+        data.update_state();
+        true
+    }
+
+    /// Returns a dynamic that contains true when there is a step that can be
+    /// undone, including changes that have not been checkpointed yet.
+    #[must_use]
+    pub fn can_undo(&self) -> DynamicReader<bool> {
+        self.data.lock().can_undo.create_reader()
+    }
+
+    /// Returns a dynamic that contains true when there is a step that can be
+    /// redone.
+    #[must_use]
+    pub fn can_redo(&self) -> DynamicReader<bool> {
+        self.data.lock().can_redo.create_reader()
+    }
+
+    /// Removes all steps from this history.
+    pub fn clear(&self) {
+        let mut data = self.data.lock();
+        for tracked in &mut data.tracked {
+            drop(tracked.take_change());
+        }
+        data.undo.clear();
+        data.redo.clear();
+        data.update_state();
+    }
+}
+
+impl Debug for UndoStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let data = self.data.lock();
+        f.debug_struct("UndoStack")
+            .field("tracked", &data.tracked.len())
+            .field("undo", &data.undo.len())
+            .field("redo", &data.redo.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A transaction that groups changes in an [`UndoStack`].
+///
+/// The transaction is committed when dropped.
+#[must_use = "the transaction is committed as soon as it is dropped"]
+pub struct UndoTransaction(UndoStack);
+
+impl Drop for UndoTransaction {
+    fn drop(&mut self) {
+        let mut data = self.0.data.lock();
+        data.transaction_depth -= 1;
+        if data.transaction_depth == 0 {
+            data.record_pending_changes();
+        }
+    }
+}
+
+struct UndoStackData {
+    tracked: Vec<Box<dyn AnyTrackedDynamic>>,
+    undo: Vec<Vec<Box<dyn UndoableChange>>>,
+    redo: Vec<Vec<Box<dyn UndoableChange>>>,
+    transaction_depth: usize,
+    can_undo: Dynamic<bool>,
+    can_redo: Dynamic<bool>,
+}
+
+impl UndoStackData {
+    fn record_pending_changes(&mut self) {
+        let changes: Vec<_> = self
+            .tracked
+            .iter_mut()
+            .filter_map(|tracked| tracked.take_change())
+            .collect();
+        if !changes.is_empty() {
+            self.redo.clear();
+            self.undo.push(changes);
+        }
+        self.update_state();
+    }
+
+    fn update_state(&self) {
+        // Dynamics never invoke their callbacks on the calling thread, which
+        // makes it safe to update these while the history is locked.
+        self.can_undo
+            .set(!self.undo.is_empty() || self.tracked.iter().any(|tracked| tracked.has_change()));
+        self.can_redo.set(!self.redo.is_empty());
+    }
+}
+
+trait AnyTrackedDynamic: Send {
+    fn has_change(&self) -> bool;
+    fn take_change(&mut self) -> Option<Box<dyn UndoableChange>>;
+}
+
+trait UndoableChange: Send {
+    fn undo(&self);
+    fn redo(&self);
+}
+
+struct TrackedDynamic<T> {
+    dynamic: WeakDynamic<T>,
+    last: T,
+}
+
+impl<T> TrackedDynamic<T>
+where
+    T: Clone + PartialEq + Send + 'static,
+{
+    fn observe(this: &Arc<Mutex<Self>>, value: T) -> Option<Box<dyn UndoableChange>> {
+        let mut tracked = this.lock();
+        if tracked.last == value {
+            return None;
+        }
+
+        let before = std::mem::replace(&mut tracked.last, value.clone());
+        Some(Box::new(DynamicChange {
+            tracked: this.clone(),
+            before,
+            after: value,
+        }))
+    }
+}
+
+struct TrackedHandle<T> {
+    tracked: Arc<Mutex<TrackedDynamic<T>>>,
+    _callback: CallbackHandle,
+}
+
+impl<T> AnyTrackedDynamic for TrackedHandle<T>
+where
+    T: Clone + PartialEq + Send + 'static,
+{
+    fn has_change(&self) -> bool {
+        let Some(dynamic) = self.tracked.lock().dynamic.upgrade() else {
+            return false;
+        };
+        let value = dynamic.get();
+        self.tracked.lock().last != value
+    }
+
+    fn take_change(&mut self) -> Option<Box<dyn UndoableChange>> {
+        let dynamic = self.tracked.lock().dynamic.upgrade()?;
+        TrackedDynamic::observe(&self.tracked, dynamic.get())
+    }
+}
+
+struct DynamicChange<T> {
+    tracked: Arc<Mutex<TrackedDynamic<T>>>,
+    before: T,
+    after: T,
+}
+
+impl<T> DynamicChange<T>
+where
+    T: Clone + Send,
+{
+    fn apply(&self, value: &T) {
+        let mut tracked = self.tracked.lock();
+        // Updating the last observed value first prevents this change from
+        // being recorded as a new step.
+        tracked.last = value.clone();
+        let dynamic = tracked.dynamic.upgrade();
+        drop(tracked);
+        if let Some(dynamic) = dynamic {
+            dynamic.set(value.clone());
+        }
+    }
+}
+
+impl<T> UndoableChange for DynamicChange<T>
+where
+    T: Clone + Send,
+{
+    fn undo(&self) {
+        self.apply(&self.before);
+    }
+
+    fn redo(&self) {
+        self.apply(&self.after);
+    }
+}
+
 #[test]
 fn compare_swap() {
     let dynamic = Dynamic::new(1);
@@ -4637,3 +4996,83 @@ fn graph_shortcircuit() {
     assert_eq!(a.get(), 4);
     assert_eq!(doubled_reader.get(), 8);
 }
+
+#[test]
+fn undo_stack_transactions() {
+    let history = UndoStack::new();
+    let a = Dynamic::new(0);
+    let b = Dynamic::new(false);
+    history.track(&a);
+    history.track(&b);
+    assert!(!history.undo());
+
+    let transaction = history.transaction();
+    a.set(1);
+    b.set(true);
+    drop(transaction);
+
+    let transaction = history.transaction();
+    a.set(2);
+    drop(transaction);
+
+    assert!(history.undo());
+    assert_eq!(a.get(), 1);
+    assert!(b.get());
+
+    assert!(history.undo());
+    assert_eq!(a.get(), 0);
+    assert!(!b.get());
+    assert!(!history.undo());
+
+    assert!(history.redo());
+    assert_eq!(a.get(), 1);
+    assert!(b.get());
+
+    // A new change discards the steps that could be redone.
+    a.set(3);
+    assert!(history.undo());
+    assert_eq!(a.get(), 1);
+    assert!(history.undo());
+    assert_eq!(a.get(), 0);
+    assert!(history.redo());
+    assert!(history.redo());
+    assert_eq!(a.get(), 3);
+    assert!(!history.redo());
+}
+
+#[test]
+fn undo_stack_checkpoints() {
+    let history = UndoStack::new();
+    let can_undo = history.can_undo();
+    let can_redo = history.can_redo();
+    let a = Dynamic::new(0);
+    history.track(&a);
+
+    // Changes between checkpoints are grouped into a single step, regardless
+    // of when the change callbacks execute.
+    a.set(1);
+    a.set(2);
+    history.checkpoint();
+    assert!(can_undo.get());
+    a.set(3);
+    history.checkpoint();
+    // Checkpointing without any changes does not record a step.
+    history.checkpoint();
+
+    assert!(history.undo());
+    assert_eq!(a.get(), 2);
+    assert!(can_redo.get());
+    assert!(history.undo());
+    assert_eq!(a.get(), 0);
+    assert!(!history.undo());
+    assert!(!can_undo.get());
+
+    // Undoing records uncheckpointed changes before reverting them.
+    assert!(history.redo());
+    a.set(4);
+    assert!(history.undo());
+    assert_eq!(a.get(), 2);
+    assert!(history.redo());
+    assert_eq!(a.get(), 4);
+    assert!(!history.redo());
+}
@@ -33,6 +33,14 @@ use crate::widgets::ComponentProbe;
 
 #[macro_use]
 pub mod components;
+#[cfg(feature = "serde")]
+mod serialization;
+mod sheet;
+
+#[cfg(feature = "theme-files")]
+pub use self::serialization::{ThemeError, ThemeFormat};
+pub use self::sheet::{Selector, StyleSheet};
+pub(crate) use self::sheet::{StyleSheets, WidgetStates};
 
 /// A collection of style components organized by their name.
 #[derive(Clone, Default)]
@@ -233,6 +241,27 @@ impl Styles {
             .and_then(|stored| Self::resolve_component(&stored.component, context))
     }
 
+    /// Returns the component associated with the given name if a value is
+    /// specified and the component's inherited status matches `inherited`.
+    ///
+    /// Components are inherited when they were attached to a parent of the
+    /// widget these styles are for, rather than to the widget itself.
+    pub(crate) fn try_get_where_inherited<Named>(
+        &self,
+        component: &Named,
+        inherited: bool,
+        context: &WidgetContext<'_>,
+    ) -> Option<Named::ComponentType>
+    where
+        Named: ComponentDefinition,
+    {
+        self.0
+            .components
+            .get(&component.name())
+            .filter(|stored| stored.inherited == inherited)
+            .and_then(|stored| Self::resolve_component(&stored.component, context))
+    }
+
     /// Returns the component associated with the given name, or if not found,
     /// returns the default value provided by the definition.
     #[must_use]
@@ -260,16 +289,43 @@ impl Styles {
         }
     }
 
-    /// Returns this collection of styles without any local style definitions.
+    /// Returns true if this collection contains no components.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.components.is_empty()
+    }
+
+    /// Inserts all components from `other`, overwriting any existing entries
+    /// with the same [`ComponentName`].
+    pub(crate) fn overwrite_with(&mut self, other: &Styles) {
+        if other.0.components.is_empty() {
+            return;
+        }
+        let data = Arc::make_mut(&mut self.0);
+        for (name, stored) in &other.0.components {
+            data.components.insert(name.clone(), stored.clone());
+        }
+    }
+
+    /// Returns this collection of styles without any local style definitions,
+    /// marking the remaining components as inherited.
     #[must_use]
     pub fn into_inherited(self) -> Self {
-        if self.0.components.values().any(|stored| !stored.inheritable) {
+        if self
+            .0
+            .components
+            .values()
+            .any(|stored| !stored.inheritable || !stored.inherited)
+        {
             Self(Arc::new(StyleData {
                 components: Arc::try_unwrap(self.0)
                     .unwrap_or_else(|err| err.as_ref().clone())
                     .components
                     .into_iter()
                     .filter(|(_, stored)| stored.inheritable)
+                    .map(|(name, mut stored)| {
+                        stored.inherited = true;
+                        (name, stored)
+                    })
                     .collect(),
             }))
         } else {
@@ -742,6 +798,44 @@ impl TryFrom<Component> for CornerRadii<Dimension> {
     }
 }
 
+macro_rules! impl_edges_component {
+    ($($type:ty),+) => {$(
+        impl From<Edges<$type>> for Component {
+            fn from(edges: Edges<$type>) -> Self {
+                Component::custom(edges)
+            }
+        }
+
+        impl TryFrom<Component> for Edges<$type> {
+            type Error = Component;
+
+            fn try_from(value: Component) -> Result<Self, Self::Error> {
+                match value {
+                    Component::Custom(custom) => custom
+                        .downcast()
+                        .copied()
+                        .ok_or_else(|| Component::Custom(custom)),
+                    other => Err(other),
+                }
+            }
+        }
+    )+};
+}
+
+impl_edges_component!(Dimension, Color, LineStyle);
+
+impl<T> RequireInvalidation for Edges<T>
+where
+    T: RequireInvalidation,
+{
+    fn requires_invalidation(&self) -> bool {
+        self.left.requires_invalidation()
+            || self.top.requires_invalidation()
+            || self.right.requires_invalidation()
+            || self.bottom.requires_invalidation()
+    }
+}
+
 /// A 1-dimensional measurement that may be automatically calculated.
 #[derive(Clone, Copy, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -1644,6 +1738,7 @@ where
 
 /// A set of light and dark [`Theme`]s.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThemePair {
     /// The theme to use when the user interface is in light mode.
     pub light: Theme,
@@ -1661,9 +1756,11 @@ pub struct ThemePair {
 
     /// A color to apply to scrims, a term sometimes used to refer to the
     /// translucent backdrop placed behind a modal popup.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub scrim: Color,
 
     /// A color to apply to shadows.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub shadow: Color,
 }
 
@@ -1711,6 +1808,7 @@ impl Default for ThemePair {
 
 /// A Cushy Color theme.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Theme {
     /// The primary color theme.
     pub primary: ColorTheme,
@@ -1767,35 +1865,49 @@ impl Theme {
 
 /// A theme of surface colors.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SurfaceTheme {
     /// The default background color.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub color: Color,
     /// A dimmer variant of the default background color.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub dim_color: Color,
     /// A brighter variant of the default background color.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub bright_color: Color,
 
     /// The background color to use for the lowest level container widget.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub lowest_container: Color,
     /// The background color to use for the low level container widgets.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub low_container: Color,
     /// The background color for middle-level container widgets.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub container: Color,
     /// The background color for high-level container widgets.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub high_container: Color,
     /// The background color for highest-level container widgets.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub highest_container: Color,
 
     /// The default background color for widgets that are opaque.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub opaque_widget: Color,
 
     /// The default text/content color.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub on_color: Color,
     /// A variation of the text/content color that is de-emphasized.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub on_color_variant: Color,
     /// The color to draw important outlines.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub outline: Color,
     /// The color to use for decorative outlines.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub outline_variant: Color,
 }
 
@@ -1845,18 +1957,25 @@ impl SurfaceTheme {
 
 /// A pallete of a shared [`ColorSource`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorTheme {
     /// The primary color, used for high-emphasis content.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub color: Color,
     /// The primary color, dimmed for de-emphasized or disabled content.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub color_dim: Color,
     /// The primary color, brightened for highlighting content.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub color_bright: Color,
     /// The color for content that sits atop the primary color.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub on_color: Color,
     /// The backgrond color for containers.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub container: Color,
     /// The color for content that is inside of a container.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub on_container: Color,
 }
 
@@ -1890,15 +2009,20 @@ impl ColorTheme {
 
 /// A theme of colors that is shared between light and dark theme variants.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixedTheme {
     /// An accent background color.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub color: Color,
     /// An alternate background color, for less emphasized content.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub dim_color: Color,
     /// The primary color for content on either background color in this theme.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub on_color: Color,
     /// The color for de-emphasized content on either background color in this
     /// theme.
+    #[cfg_attr(feature = "serde", serde(with = "serialization::color"))]
     pub on_color_variant: Color,
 }
 
@@ -2663,6 +2787,7 @@ where
 
 /// A color scheme for a Cushy application.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorScheme {
     /// The primary accent color.
     pub primary: ColorSource,
@@ -2780,8 +2905,28 @@ impl TryFrom<Component> for FontFamilyList {
     }
 }
 
+/// The pattern used to draw a line, such as a border.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineStyle {
+    /// A single, unbroken line.
+    #[default]
+    Solid,
+    /// A series of short line segments.
+    Dashed,
+    /// A series of round dots.
+    Dotted,
+}
+
+impl RequireInvalidation for LineStyle {
+    fn requires_invalidation(&self) -> bool {
+        false
+    }
+}
+
 /// Alignment along the horizontal axis.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HorizontalAlign {
     /// Align the content to the left of the area provided.
     #[default]
@@ -2833,6 +2978,7 @@ impl RequireInvalidation for HorizontalAlign {
 
 /// Alignment along the vertical axis.
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalAlign {
     /// Align towards the top.
     #[default] // TODO this should be baseline, not top.
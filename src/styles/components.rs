@@ -8,7 +8,8 @@ use kludgine::Color;
 use crate::animation::easings::{EaseInOutQuadradic, EaseInQuadradic, EaseOutQuadradic};
 use crate::animation::{EasingFunction, ZeroToOne};
 use crate::styles::{
-    Dimension, FocusableWidgets, FontFamilyList, HorizontalAlign, VerticalAlign, VisualOrder,
+    Dimension, Edges, FocusableWidgets, FontFamilyList, HorizontalAlign, LineStyle, VerticalAlign,
+    VisualOrder,
 };
 use crate::window::ThemeMode;
 
@@ -252,6 +253,21 @@ define_components! {
         OutlineColor(Color, "outline_color", .surface.outline)
         /// A [`Color`] to be used as an outline color.
         DisabledOutlineColor(Color, "disabled_outline_color", .surface.outline_variant)
+        /// The width of each edge of the border drawn by a
+        /// [`Container`](crate::widgets::Container).
+        ///
+        /// Borders are drawn inside of the container's background and follow its
+        /// [`CornerRadius`]. Because this component is inherited, consider
+        /// applying it using
+        /// [`MakeWidget::with_local`](crate::widget::MakeWidget::with_local) to
+        /// prevent nested containers from also drawing borders.
+        BorderWidth(Edges<Dimension>, "border_width", Edges::from(Dimension::ZERO))
+        /// The [`Color`] of each edge of the border drawn by a
+        /// [`Container`](crate::widgets::Container).
+        BorderColor(Edges<Color>, "border_color", |context| Edges::from(context.get(&OutlineColor)))
+        /// The [`LineStyle`] of each edge of the border drawn by a
+        /// [`Container`](crate::widgets::Container).
+        BorderStyle(Edges<LineStyle>, "border_style", Edges::from(LineStyle::Solid))
         /// A [`Color`] to be used as a background color for widgets that render an
         /// opaque background.
         OpaqueWidgetColor(Color, "opaque_color", .surface.opaque_widget)
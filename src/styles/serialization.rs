@@ -0,0 +1,578 @@
+//! Serialization support for themes and styles.
+//!
+//! Loading, saving, and watching theme files requires the `theme-files`
+//! feature.
+
+use std::collections::BTreeMap;
+#[cfg(feature = "theme-files")]
+use std::fmt::{self, Display};
+#[cfg(feature = "theme-files")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "theme-files")]
+use std::time::{Duration, SystemTime};
+#[cfg(feature = "theme-files")]
+use std::{fs, io, thread};
+
+use kludgine::cosmic_text::{FamilyOwned, Style, Weight};
+use kludgine::Color;
+use serde::de::Error as _;
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "theme-files")]
+use super::{ColorScheme, ThemePair};
+use super::{
+    ColorSource, Component, ComponentName, ContainerLevel, Dimension, DimensionRange,
+    FocusableWidgets, HorizontalAlign, Styles, VerticalAlign, VisualOrder,
+};
+use crate::animation::ZeroToOne;
+#[cfg(feature = "theme-files")]
+use crate::value::{Destination, Dynamic};
+use crate::widgets::input::CowString;
+
+/// How often a watched theme file is checked for changes.
+#[cfg(feature = "theme-files")]
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Serializes [`Color`]s as `#RRGGBBAA` hex strings.
+///
+/// When deserializing, `#RGB`, `#RGBA`, and `#RRGGBB` are also accepted.
+pub(super) mod color {
+    use kludgine::Color;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(color: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            color.red(),
+            color.green(),
+            color.blue(),
+            color.alpha()
+        ))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        parse(&hex).ok_or_else(|| D::Error::custom(format_args!("invalid hex color: {hex:?}")))
+    }
+
+    pub(super) fn parse(hex: &str) -> Option<Color> {
+        let digits = hex.strip_prefix('#')?;
+        if !digits.is_ascii() {
+            return None;
+        }
+        let channel = |index: usize, width: usize| {
+            let value =
+                u8::from_str_radix(digits.get(index * width..(index + 1) * width)?, 16).ok()?;
+            // Short-form colors repeat each digit: #abc is #aabbcc.
+            Some(if width == 1 { value * 0x11 } else { value })
+        };
+        let (width, has_alpha) = match digits.len() {
+            3 => (1, false),
+            4 => (1, true),
+            6 => (2, false),
+            8 => (2, true),
+            _ => return None,
+        };
+        let alpha = if has_alpha { channel(3, width)? } else { 255 };
+        Some(Color::new(
+            channel(0, width)?,
+            channel(1, width)?,
+            channel(2, width)?,
+            alpha,
+        ))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedColorSource {
+    hue: f32,
+    saturation: ZeroToOne,
+}
+
+impl Serialize for ColorSource {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        SerializedColorSource {
+            hue: self.hue.into_degrees(),
+            saturation: self.saturation,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let source = SerializedColorSource::deserialize(deserializer)?;
+        Ok(Self::new(source.hue, source.saturation))
+    }
+}
+
+/// The serialized representation of a [`Component`].
+///
+/// Only components that can be represented as data are included. Easing
+/// functions, custom components, and dynamic components cannot be serialized.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SerializedComponent {
+    #[serde(with = "color")]
+    Color(Color),
+    Dimension(Dimension),
+    DimensionRange(DimensionRange),
+    Percent(ZeroToOne),
+    VisualOrder(VisualOrder),
+    FocusableWidgets(FocusableWidgets),
+    ContainerLevel(ContainerLevel),
+    FontFamily(String),
+    FontWeight(u16),
+    FontStyle(SerializedFontStyle),
+    String(CowString),
+    HorizontalAlign(HorizontalAlign),
+    VerticalAlign(VerticalAlign),
+}
+
+impl SerializedComponent {
+    fn from_component(component: &Component) -> Option<Self> {
+        Some(match component {
+            Component::Color(color) => Self::Color(*color),
+            Component::Dimension(dimension) => Self::Dimension(*dimension),
+            Component::DimensionRange(range) => Self::DimensionRange(*range),
+            Component::Percent(percent) => Self::Percent(*percent),
+            Component::VisualOrder(order) => Self::VisualOrder(*order),
+            Component::FocusableWidgets(focusable) => Self::FocusableWidgets(*focusable),
+            Component::ContainerLevel(level) => Self::ContainerLevel(*level),
+            Component::FontFamily(family) => Self::FontFamily(match family {
+                FamilyOwned::Name(name) => name.to_string(),
+                FamilyOwned::Serif => String::from("serif"),
+                FamilyOwned::SansSerif => String::from("sans-serif"),
+                FamilyOwned::Cursive => String::from("cursive"),
+                FamilyOwned::Fantasy => String::from("fantasy"),
+                FamilyOwned::Monospace => String::from("monospace"),
+            }),
+            Component::FontWeight(weight) => Self::FontWeight(weight.0),
+            Component::FontStyle(style) => Self::FontStyle(match style {
+                Style::Normal => SerializedFontStyle::Normal,
+                Style::Italic => SerializedFontStyle::Italic,
+                Style::Oblique => SerializedFontStyle::Oblique,
+            }),
+            Component::String(string) => Self::String(string.clone()),
+            Component::HorizontalAlign(align) => Self::HorizontalAlign(*align),
+            Component::VerticalAlign(align) => Self::VerticalAlign(*align),
+            Component::Easing(_) | Component::Custom(_) | Component::Dynamic(_) => return None,
+        })
+    }
+}
+
+impl From<SerializedComponent> for Component {
+    fn from(component: SerializedComponent) -> Self {
+        match component {
+            SerializedComponent::Color(color) => Self::Color(color),
+            SerializedComponent::Dimension(dimension) => Self::Dimension(dimension),
+            SerializedComponent::DimensionRange(range) => Self::DimensionRange(range),
+            SerializedComponent::Percent(percent) => Self::Percent(percent),
+            SerializedComponent::VisualOrder(order) => Self::VisualOrder(order),
+            SerializedComponent::FocusableWidgets(focusable) => Self::FocusableWidgets(focusable),
+            SerializedComponent::ContainerLevel(level) => Self::ContainerLevel(level),
+            SerializedComponent::FontFamily(family) => Self::FontFamily(match family.as_str() {
+                "serif" => FamilyOwned::Serif,
+                "sans-serif" => FamilyOwned::SansSerif,
+                "cursive" => FamilyOwned::Cursive,
+                "fantasy" => FamilyOwned::Fantasy,
+                "monospace" => FamilyOwned::Monospace,
+                _ => FamilyOwned::Name(family.into()),
+            }),
+            SerializedComponent::FontWeight(weight) => Self::FontWeight(Weight(weight)),
+            SerializedComponent::FontStyle(style) => Self::FontStyle(match style {
+                SerializedFontStyle::Normal => Style::Normal,
+                SerializedFontStyle::Italic => Style::Italic,
+                SerializedFontStyle::Oblique => Style::Oblique,
+            }),
+            SerializedComponent::String(string) => Self::String(string),
+            SerializedComponent::HorizontalAlign(align) => Self::HorizontalAlign(align),
+            SerializedComponent::VerticalAlign(align) => Self::VerticalAlign(align),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SerializedFontStyle {
+    Normal,
+    Italic,
+    Oblique,
+}
+
+/// Styles are serialized as a map of `group.name` keys to their components.
+///
+/// Whether a component is local or inherited is not preserved. Dynamic values
+/// are serialized using their current value.
+impl Serialize for Styles {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut components = self
+            .0
+            .components
+            .iter()
+            .map(|(name, stored)| {
+                let component = stored
+                    .component
+                    .map(SerializedComponent::from_component)
+                    .ok_or_else(|| {
+                        S::Error::custom(format_args!("{name:?} cannot be serialized"))
+                    })?;
+                Ok((format!("{}.{}", &*name.group, &*name.name), component))
+            })
+            .collect::<Result<Vec<_>, S::Error>>()?;
+        // Sorting keeps serialized styles stable, which is friendlier to files
+        // that are stored in version control.
+        components.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut map = serializer.serialize_map(Some(components.len()))?;
+        for (name, component) in &components {
+            map.serialize_entry(name, component)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Styles {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let components = BTreeMap::<String, SerializedComponent>::deserialize(deserializer)?;
+        let mut styles = Styles::with_capacity(components.len());
+        for (name, component) in components {
+            let Some((group, name)) = name.split_once('.') else {
+                return Err(D::Error::custom(format_args!(
+                    "invalid component name {name:?}: expected `group.name`"
+                )));
+            };
+            styles.insert_named(ComponentName::new(group, name), Component::from(component));
+        }
+        Ok(styles)
+    }
+}
+
+/// A file format that a [`ThemePair`] can be loaded from or saved to.
+#[cfg(feature = "theme-files")]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ThemeFormat {
+    /// [TOML](https://toml.io/).
+    Toml,
+    /// [JSON](https://www.json.org/).
+    Json,
+}
+
+#[cfg(feature = "theme-files")]
+impl ThemeFormat {
+    /// Returns the format matching the extension of `path`, if recognized.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        if extension.eq_ignore_ascii_case("toml") {
+            Some(Self::Toml)
+        } else if extension.eq_ignore_ascii_case("json") {
+            Some(Self::Json)
+        } else {
+            None
+        }
+    }
+}
+
+/// An error loading or saving a [`ThemePair`].
+#[cfg(feature = "theme-files")]
+#[derive(Debug)]
+pub enum ThemeError {
+    /// An error occurred reading or writing a theme file.
+    Io(io::Error),
+    /// The theme file's extension is not a recognized [`ThemeFormat`].
+    UnknownFormat(PathBuf),
+    /// An error occurred parsing TOML.
+    TomlDeserialize(toml::de::Error),
+    /// An error occurred producing TOML.
+    TomlSerialize(toml::ser::Error),
+    /// An error occurred parsing or producing JSON.
+    Json(serde_json::Error),
+}
+
+#[cfg(feature = "theme-files")]
+impl From<io::Error> for ThemeError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl From<toml::de::Error> for ThemeError {
+    fn from(value: toml::de::Error) -> Self {
+        Self::TomlDeserialize(value)
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl From<toml::ser::Error> for ThemeError {
+    fn from(value: toml::ser::Error) -> Self {
+        Self::TomlSerialize(value)
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl From<serde_json::Error> for ThemeError {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Json(value)
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(err) => write!(f, "error accessing theme file: {err}"),
+            ThemeError::UnknownFormat(path) => write!(
+                f,
+                "unrecognized theme format for {}: expected a .toml or .json file",
+                path.display()
+            ),
+            ThemeError::TomlDeserialize(err) => write!(f, "error parsing theme: {err}"),
+            ThemeError::TomlSerialize(err) => write!(f, "error serializing theme: {err}"),
+            ThemeError::Json(err) => write!(f, "error parsing or serializing theme: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl std::error::Error for ThemeError {}
+
+/// The contents of a theme file.
+///
+/// Theme files can either contain every color of a [`ThemePair`], or the
+/// [`ColorScheme`] to generate the theme from.
+#[cfg(feature = "theme-files")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ThemeFile {
+    Pair(ThemePair),
+    Scheme(ColorScheme),
+}
+
+#[cfg(feature = "theme-files")]
+impl From<ThemeFile> for ThemePair {
+    fn from(file: ThemeFile) -> Self {
+        match file {
+            ThemeFile::Pair(pair) => pair,
+            ThemeFile::Scheme(scheme) => ThemePair::from_scheme(&scheme),
+        }
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl ThemePair {
+    /// Parses a theme from `source` in `format`.
+    ///
+    /// The source can either contain a complete theme pair, or a
+    /// [`ColorScheme`] to generate the theme from.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` is not a valid theme.
+    pub fn parse(source: &str, format: ThemeFormat) -> Result<Self, ThemeError> {
+        let file = match format {
+            ThemeFormat::Toml => toml::from_str::<ThemeFile>(source).map_err(ThemeError::from),
+            ThemeFormat::Json => {
+                serde_json::from_str::<ThemeFile>(source).map_err(ThemeError::from)
+            }
+        };
+        match file {
+            Ok(file) => Ok(file.into()),
+            // Untagged enums produce vague errors, so the error from parsing
+            // the complete theme is reported instead.
+            Err(_) => match format {
+                ThemeFormat::Toml => Ok(toml::from_str(source)?),
+                ThemeFormat::Json => Ok(serde_json::from_str(source)?),
+            },
+        }
+    }
+
+    /// Loads a theme from the file at `path`.
+    ///
+    /// The format of the file is determined by its extension using
+    /// [`ThemeFormat::from_path`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, its format is not
+    /// recognized, or it does not contain a valid theme.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let format = ThemeFormat::from_path(path)
+            .ok_or_else(|| ThemeError::UnknownFormat(path.to_path_buf()))?;
+        Self::parse(&fs::read_to_string(path)?, format)
+    }
+
+    /// Serializes this theme as a string in `format`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the theme cannot be represented in `format`.
+    pub fn serialize_to_string(&self, format: ThemeFormat) -> Result<String, ThemeError> {
+        match format {
+            ThemeFormat::Toml => Ok(toml::to_string_pretty(self)?),
+            ThemeFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+        }
+    }
+
+    /// Saves this theme to the file at `path`.
+    ///
+    /// The format of the file is determined by its extension using
+    /// [`ThemeFormat::from_path`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format is not recognized or the file cannot be
+    /// written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ThemeError> {
+        let path = path.as_ref();
+        let format = ThemeFormat::from_path(path)
+            .ok_or_else(|| ThemeError::UnknownFormat(path.to_path_buf()))?;
+        fs::write(path, self.serialize_to_string(format)?)?;
+        Ok(())
+    }
+
+    /// Loads the theme at `path` into `theme`, and reloads it each time the
+    /// file is modified.
+    ///
+    /// This allows a theme passed to
+    /// [`Window::themed`](crate::window::Window::themed) to be edited while the
+    /// application is running. The file is checked for changes in a background
+    /// thread until all other references to `theme` have been dropped. If a
+    /// modified file cannot be loaded, the error is logged and the previous
+    /// theme is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the theme cannot be loaded initially.
+    pub fn watch(path: impl Into<PathBuf>, theme: &Dynamic<ThemePair>) -> Result<(), ThemeError> {
+        let path = path.into();
+        let mut modified = modified_at(&path);
+        theme.set(Self::load(&path)?);
+
+        let theme = theme.downgrade();
+        thread::Builder::new()
+            .name(String::from("theme watcher"))
+            .spawn(move || loop {
+                thread::sleep(WATCH_INTERVAL);
+                let Some(theme) = theme.upgrade() else {
+                    break;
+                };
+
+                let last_modified = modified_at(&path);
+                if last_modified == modified {
+                    continue;
+                }
+                modified = last_modified;
+
+                match Self::load(&path) {
+                    Ok(loaded) => theme.set(loaded),
+                    Err(err) => {
+                        tracing::error!("error reloading theme {}: {err}", path.display());
+                    }
+                }
+            })?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "theme-files")]
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use kludgine::Color;
+
+    use super::color;
+
+    #[test]
+    fn hex_colors() {
+        assert_eq!(
+            color::parse("#abc"),
+            Some(Color::new(0xaa, 0xbb, 0xcc, 255))
+        );
+        assert_eq!(
+            color::parse("#abcd"),
+            Some(Color::new(0xaa, 0xbb, 0xcc, 0xdd))
+        );
+        assert_eq!(
+            color::parse("#102030"),
+            Some(Color::new(0x10, 0x20, 0x30, 255))
+        );
+        assert_eq!(
+            color::parse("#10203040"),
+            Some(Color::new(0x10, 0x20, 0x30, 0x40))
+        );
+        assert_eq!(color::parse("102030"), None);
+        assert_eq!(color::parse("#10203"), None);
+        assert_eq!(color::parse("#1020zz"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn theme_round_trip() {
+        use super::ThemeFormat;
+        use crate::styles::ThemePair;
+
+        let theme = ThemePair::default();
+        for format in [ThemeFormat::Toml, ThemeFormat::Json] {
+            let serialized = theme.serialize_to_string(format).unwrap();
+            assert_eq!(ThemePair::parse(&serialized, format).unwrap(), theme);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn theme_from_scheme() {
+        use super::ThemeFormat;
+        use crate::styles::{ColorScheme, ThemePair};
+
+        let scheme = ColorScheme::from_primary(138.5);
+        let serialized = toml::to_string(&scheme).unwrap();
+        assert_eq!(
+            ThemePair::parse(&serialized, ThemeFormat::Toml).unwrap(),
+            ThemePair::from_scheme(&scheme)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "theme-files")]
+    fn styles_round_trip() {
+        use figures::units::Lp;
+
+        use crate::styles::components::{IntrinsicPadding, TextColor};
+        use crate::styles::{Dimension, Styles};
+
+        let styles = Styles::new()
+            .with(&TextColor, Color::RED)
+            .with(&IntrinsicPadding, Dimension::Lp(Lp::points(4)));
+        let serialized = toml::to_string(&styles).unwrap();
+        let deserialized: Styles = toml::from_str(&serialized).unwrap();
+        assert_eq!(toml::to_string(&deserialized).unwrap(), serialized);
+    }
+}
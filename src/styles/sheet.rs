@@ -0,0 +1,629 @@
+//! Selector-based style sheets.
+
+use std::any::{type_name, TypeId};
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use super::Styles;
+use crate::context::WidgetContext;
+use crate::names::Name;
+use crate::value::Value;
+use crate::widget::{Widget, WidgetInstance};
+
+/// A collection of [`Styles`] that are applied to widgets matching a
+/// [`Selector`].
+///
+/// Unlike [`Styles`], which are inherited by every widget beneath the widget
+/// they are attached to, a style sheet's rules only apply to the widgets their
+/// selectors match. Style sheets are attached to a widget hierarchy using
+/// [`MakeWidget::with_style_sheet`](crate::widget::MakeWidget::with_style_sheet),
+/// and rules from all style sheets attached to a widget's parents are
+/// considered. When a style sheet is provided as a
+/// [`Dynamic`](crate::value::Dynamic), affected widgets are restyled when it
+/// changes.
+///
+/// When multiple rules match a widget, rules with more specific selectors take
+/// precedence. If two matching rules are equally specific, the rule added last
+/// takes precedence. Components from [`Styles`] applied directly to a widget
+/// take precedence over style sheet rules, and style sheet rules take
+/// precedence over components inherited from the widget's parents.
+///
+/// ```rust
+/// use cushy::styles::components::TextColor;
+/// use cushy::styles::{Color, Selector, StyleSheet, Styles};
+/// use cushy::widgets::Button;
+///
+/// let sheet = StyleSheet::new()
+///     .with(
+///         Selector::widget::<Button>().class("danger"),
+///         Styles::new().with(&TextColor, Color::RED),
+///     )
+///     .with(
+///         Selector::widget::<Button>().class("danger").hovered(),
+///         Styles::new().with(&TextColor, Color::DARKRED),
+///     );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct StyleSheet(Arc<Vec<StyleRule>>);
+
+impl StyleSheet {
+    /// Returns an empty style sheet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule that applies `styles` to all widgets matching `selector`.
+    pub fn push(&mut self, selector: Selector, styles: Styles) {
+        Arc::make_mut(&mut self.0).push(StyleRule { selector, styles });
+    }
+
+    /// Adds a rule that applies `styles` to all widgets matching `selector`
+    /// and returns self.
+    #[must_use]
+    pub fn with(mut self, selector: Selector, styles: Styles) -> Self {
+        self.push(selector, styles);
+        self
+    }
+
+    /// Returns true if this style sheet contains no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of rules in this style sheet.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if any rule that could match `widget` depends on its
+    /// interaction state.
+    pub(crate) fn depends_on_state(&self, widget: &WidgetInstance) -> bool {
+        self.0.iter().any(|rule| {
+            rule.selector.states != StateFilter::default() && rule.selector.matches_widget(widget)
+        })
+    }
+
+    /// Returns the combined styles of all rules matching `widget` in
+    /// `states`.
+    #[cfg(test)]
+    pub(crate) fn resolve(&self, widget: &WidgetInstance, states: WidgetStates) -> Styles {
+        resolve_rules(self.0.iter(), widget, states)
+    }
+}
+
+/// Returns the combined styles of all `rules` matching `widget` in `states`.
+///
+/// Rules later in `rules` take precedence over equally specific rules before
+/// them.
+fn resolve_rules<'a>(
+    rules: impl Iterator<Item = &'a StyleRule>,
+    widget: &WidgetInstance,
+    states: WidgetStates,
+) -> Styles {
+    let mut matching = rules
+        .enumerate()
+        .filter(|(_, rule)| rule.selector.matches(widget, states))
+        .collect::<Vec<_>>();
+    match matching.len() {
+        0 => Styles::new(),
+        1 => matching[0].1.styles.clone(),
+        _ => {
+            matching.sort_by_key(|(index, rule)| (rule.selector.specificity(), *index));
+            let mut resolved = Styles::new();
+            for (_, rule) in matching {
+                resolved.overwrite_with(&rule.styles);
+            }
+            resolved
+        }
+    }
+}
+
+/// The style sheets attached to a widget's parents, ordered from the
+/// outermost parent to the nearest parent.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct StyleSheets(Arc<Vec<Value<StyleSheet>>>);
+
+impl StyleSheets {
+    /// Returns these style sheets followed by `style_sheet`.
+    pub fn with(&self, style_sheet: &Value<StyleSheet>) -> Self {
+        let mut style_sheets = Vec::with_capacity(self.0.len() + 1);
+        style_sheets.extend(self.0.iter().cloned());
+        style_sheets.push(style_sheet.clone());
+        Self(Arc::new(style_sheets))
+    }
+
+    /// Returns true if no style sheets are present.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns true if any rule that could match `widget` depends on its
+    /// interaction state.
+    pub fn depends_on_state(&self, widget: &WidgetInstance) -> bool {
+        self.0
+            .iter()
+            .any(|style_sheet| style_sheet.map(|style_sheet| style_sheet.depends_on_state(widget)))
+    }
+
+    /// Returns the combined styles of all rules matching `widget` in
+    /// `states`, invalidating `context` when any of the style sheets change.
+    pub fn resolve(
+        &self,
+        widget: &WidgetInstance,
+        states: WidgetStates,
+        context: &WidgetContext<'_>,
+    ) -> Styles {
+        let style_sheets = self
+            .0
+            .iter()
+            .map(|style_sheet| style_sheet.get_tracking_invalidate(context))
+            .collect::<Vec<_>>();
+        resolve_rules(
+            style_sheets
+                .iter()
+                .flat_map(|style_sheet| style_sheet.0.iter()),
+            widget,
+            states,
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+struct StyleRule {
+    selector: Selector,
+    styles: Styles,
+}
+
+/// A filter that matches widgets by their type, style classes, and interaction
+/// state.
+///
+/// A selector matches a widget only when every condition it specifies is met.
+/// A selector created with [`Selector::any()`] that has no other conditions
+/// matches every widget.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Selector {
+    widget: Option<WidgetType>,
+    classes: Vec<Name>,
+    states: StateFilter,
+}
+
+impl Selector {
+    /// Returns a selector that matches all widgets.
+    #[must_use]
+    pub fn any() -> Self {
+        Self::default()
+    }
+
+    /// Returns a selector that matches widgets of type `W`.
+    ///
+    /// The type is compared against the type the [`WidgetInstance`] was created
+    /// from. Wrapping a widget, for example by applying styles to it, produces
+    /// a new widget of a different type.
+    #[must_use]
+    pub fn widget<W: Widget>() -> Self {
+        Self {
+            widget: Some(WidgetType::of::<W>()),
+            ..Self::default()
+        }
+    }
+
+    /// Requires that matching widgets have the style class `class`, and
+    /// returns self.
+    ///
+    /// Style classes are added to widgets using
+    /// [`MakeWidget::with_class`](crate::widget::MakeWidget::with_class).
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Name>) -> Self {
+        self.classes.push(class.into());
+        self
+    }
+
+    /// Requires that matching widgets are hovered, and returns self.
+    #[must_use]
+    pub fn hovered(mut self) -> Self {
+        self.states.hovered = Some(true);
+        self
+    }
+
+    /// Requires that matching widgets are not hovered, and returns self.
+    #[must_use]
+    pub fn not_hovered(mut self) -> Self {
+        self.states.hovered = Some(false);
+        self
+    }
+
+    /// Requires that matching widgets are active, and returns self.
+    #[must_use]
+    pub fn active(mut self) -> Self {
+        self.states.active = Some(true);
+        self
+    }
+
+    /// Requires that matching widgets are not active, and returns self.
+    #[must_use]
+    pub fn not_active(mut self) -> Self {
+        self.states.active = Some(false);
+        self
+    }
+
+    /// Requires that matching widgets are focused, and returns self.
+    #[must_use]
+    pub fn focused(mut self) -> Self {
+        self.states.focused = Some(true);
+        self
+    }
+
+    /// Requires that matching widgets are not focused, and returns self.
+    #[must_use]
+    pub fn not_focused(mut self) -> Self {
+        self.states.focused = Some(false);
+        self
+    }
+
+    /// Requires that matching widgets are disabled, and returns self.
+    #[must_use]
+    pub fn disabled(mut self) -> Self {
+        self.states.disabled = Some(true);
+        self
+    }
+
+    /// Requires that matching widgets are enabled, and returns self.
+    #[must_use]
+    pub fn enabled(mut self) -> Self {
+        self.states.disabled = Some(false);
+        self
+    }
+
+    fn specificity(&self) -> usize {
+        usize::from(self.widget.is_some()) + self.classes.len() + self.states.len()
+    }
+
+    fn matches_widget(&self, widget: &WidgetInstance) -> bool {
+        self.widget
+            .as_ref()
+            .map_or(true, |ty| ty.id == widget.widget_type_id())
+            && self.classes.iter().all(|class| widget.has_class(class))
+    }
+
+    pub(crate) fn matches(&self, widget: &WidgetInstance, states: WidgetStates) -> bool {
+        self.states.matches(states) && self.matches_widget(widget)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct WidgetType {
+    id: TypeId,
+    name: &'static str,
+}
+
+impl WidgetType {
+    fn of<W: Widget>() -> Self {
+        Self {
+            id: TypeId::of::<W>(),
+            name: type_name::<W>(),
+        }
+    }
+}
+
+impl Debug for WidgetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl PartialEq for WidgetType {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct StateFilter {
+    hovered: Option<bool>,
+    active: Option<bool>,
+    focused: Option<bool>,
+    disabled: Option<bool>,
+}
+
+impl StateFilter {
+    fn len(&self) -> usize {
+        [self.hovered, self.active, self.focused, self.disabled]
+            .iter()
+            .filter(|state| state.is_some())
+            .count()
+    }
+
+    fn matches(&self, states: WidgetStates) -> bool {
+        fn state_matches(filter: Option<bool>, state: bool) -> bool {
+            filter.map_or(true, |filter| filter == state)
+        }
+
+        state_matches(self.hovered, states.hovered)
+            && state_matches(self.active, states.active)
+            && state_matches(self.focused, states.focused)
+            && state_matches(self.disabled, states.disabled)
+    }
+}
+
+/// The interaction state of a widget being matched against a [`Selector`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct WidgetStates {
+    pub hovered: bool,
+    pub active: bool,
+    pub focused: bool,
+    pub disabled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Point, Size};
+
+    use super::{Selector, StyleSheet, WidgetStates};
+    use crate::context::{EventContext, GraphicsContext, LayoutContext};
+    use crate::styles::components::TextColor;
+    use crate::styles::{Color, Component, NamedComponent, Styles};
+    use crate::value::{Destination, Dynamic, Source};
+    use crate::widget::{MakeWidget, MountedWidget, Widget, WidgetInstance};
+    use crate::widgets::{Button, Label};
+    use crate::window::{DeviceId, VirtualRecorder};
+    use crate::ConstraintLimit;
+
+    fn text_color(styles: &Styles) -> Option<Color> {
+        let text_color = TextColor.name().into_owned();
+        styles
+            .clone()
+            .into_iter()
+            .find(|(name, _)| name == &text_color)
+            .and_then(|(_, component)| match component.get() {
+                Component::Color(color) => Some(color),
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn selector_matching() {
+        let danger = Button::new("Delete").with_class("danger");
+        let plain = Button::new("Save").make_widget();
+        let label = Label::new("danger").with_class("danger");
+
+        let selector = Selector::widget::<Button>().class("danger");
+        assert!(selector.matches(&danger, WidgetStates::default()));
+        assert!(!selector.matches(&plain, WidgetStates::default()));
+        assert!(!selector.matches(&label, WidgetStates::default()));
+        assert!(Selector::any()
+            .class("danger")
+            .matches(&label, WidgetStates::default()));
+
+        let hovered = Selector::widget::<Button>().hovered();
+        assert!(!hovered.matches(&plain, WidgetStates::default()));
+        assert!(hovered.matches(
+            &plain,
+            WidgetStates {
+                hovered: true,
+                ..WidgetStates::default()
+            }
+        ));
+        assert!(Selector::any()
+            .enabled()
+            .matches(&plain, WidgetStates::default()));
+    }
+
+    #[test]
+    fn specificity() {
+        let sheet = StyleSheet::new()
+            .with(
+                Selector::widget::<Button>().class("danger").hovered(),
+                Styles::new().with(&TextColor, Color::DARKRED),
+            )
+            .with(
+                Selector::widget::<Button>().class("danger"),
+                Styles::new().with(&TextColor, Color::RED),
+            )
+            .with(Selector::any(), Styles::new().with(&TextColor, Color::BLUE))
+            .with(
+                Selector::widget::<Button>(),
+                Styles::new().with(&TextColor, Color::GREEN),
+            );
+        let danger: WidgetInstance = Button::new("Delete").with_class("danger");
+        let plain = Button::new("Save").make_widget();
+        let label = Label::new("Label").make_widget();
+        let hovered = WidgetStates {
+            hovered: true,
+            ..WidgetStates::default()
+        };
+
+        assert_eq!(
+            text_color(&sheet.resolve(&danger, WidgetStates::default())),
+            Some(Color::RED)
+        );
+        assert_eq!(
+            text_color(&sheet.resolve(&danger, hovered)),
+            Some(Color::DARKRED)
+        );
+        assert_eq!(
+            text_color(&sheet.resolve(&plain, hovered)),
+            Some(Color::GREEN)
+        );
+        assert_eq!(
+            text_color(&sheet.resolve(&label, hovered)),
+            Some(Color::BLUE)
+        );
+        assert!(sheet.depends_on_state(&danger));
+        assert!(!sheet.depends_on_state(&plain));
+    }
+
+    /// A focusable widget that stores the [`TextColor`] it receives through
+    /// [`WidgetContext::get`](crate::context::WidgetContext::get) each time it
+    /// is laid out.
+    #[derive(Debug)]
+    struct ColorProbe(Dynamic<Color>);
+
+    impl Widget for ColorProbe {
+        fn redraw(&mut self, _context: &mut GraphicsContext<'_, '_, '_, '_>) {}
+
+        fn layout(
+            &mut self,
+            _available_space: Size<ConstraintLimit>,
+            context: &mut LayoutContext<'_, '_, '_, '_>,
+        ) -> Size<UPx> {
+            self.0.set(context.get(&TextColor));
+            Size::new(UPx::new(50), UPx::new(50))
+        }
+
+        fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+            true
+        }
+
+        fn accept_focus(&mut self, _context: &mut EventContext<'_>) -> bool {
+            true
+        }
+    }
+
+    /// Returns a recorder showing a centered [`ColorProbe`] wrapped by
+    /// `wrap`, the probe's mounted widget, and the probe's text color.
+    fn probe(
+        wrap: impl FnOnce(WidgetInstance) -> WidgetInstance,
+    ) -> (VirtualRecorder, MountedWidget, Dynamic<Color>) {
+        let color = Dynamic::new(Color::CLEAR_BLACK);
+        let probe = ColorProbe(color.clone()).make_widget();
+        let id = probe.id();
+        let mut recorder = wrap(probe)
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let probe = recorder.window.widget(id).expect("probe mounted");
+        (recorder, probe, color)
+    }
+
+    #[test]
+    fn precedence() {
+        let sheet = StyleSheet::new().with(
+            Selector::widget::<ColorProbe>(),
+            Styles::new().with(&TextColor, Color::RED),
+        );
+
+        // Matching rules take precedence over inherited components.
+        let (_recorder, _probe, color) = probe(|probe| {
+            probe
+                .centered()
+                .with(&TextColor, Color::BLUE)
+                .with_style_sheet(sheet.clone())
+                .make_widget()
+        });
+        assert_eq!(color.get(), Color::RED);
+
+        // Components applied directly to the widget take precedence over
+        // matching rules.
+        let (_recorder, _probe, color) = probe(|probe| {
+            probe
+                .with(&TextColor, Color::GREEN)
+                .centered()
+                .with(&TextColor, Color::BLUE)
+                .with_style_sheet(sheet.clone())
+                .make_widget()
+        });
+        assert_eq!(color.get(), Color::GREEN);
+
+        // Inherited components still apply when no rules match.
+        let (_recorder, _probe, color) = probe(|probe| {
+            probe
+                .centered()
+                .with(&TextColor, Color::BLUE)
+                .with_style_sheet(StyleSheet::new().with(
+                    Selector::widget::<Label>(),
+                    Styles::new().with(&TextColor, Color::RED),
+                ))
+                .make_widget()
+        });
+        assert_eq!(color.get(), Color::BLUE);
+    }
+
+    #[test]
+    fn state_changes() {
+        let warning = Dynamic::new(false);
+        let sheet = StyleSheet::new()
+            .with(
+                Selector::widget::<ColorProbe>(),
+                Styles::new().with(&TextColor, Color::RED),
+            )
+            .with(
+                Selector::widget::<ColorProbe>().hovered(),
+                Styles::new().with(&TextColor, Color::GREEN),
+            )
+            .with(
+                Selector::widget::<ColorProbe>().focused(),
+                Styles::new().with(&TextColor, Color::BLUE),
+            )
+            .with(
+                Selector::widget::<ColorProbe>().class("warning"),
+                Styles::new().with(&TextColor, Color::ORANGE),
+            );
+        let (mut recorder, probe, color) = probe(|probe| {
+            probe
+                .with_class_when("warning", warning.clone())
+                .centered()
+                .with(&TextColor, Color::WHITE)
+                .with_style_sheet(sheet)
+                .make_widget()
+        });
+        assert_eq!(color.get(), Color::RED);
+
+        let layout = probe.last_layout().expect("probe laid out");
+        recorder.window.cursor_moved(
+            DeviceId::Virtual(0),
+            layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2),
+        );
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(color.get(), Color::GREEN);
+        recorder
+            .window
+            .cursor_moved(DeviceId::Virtual(0), Point::new(Px::new(1), Px::new(1)));
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(color.get(), Color::RED);
+
+        recorder.window.focus(&probe);
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(color.get(), Color::BLUE);
+
+        // The class rule is added last, so it takes precedence over the
+        // equally specific focus rule.
+        warning.set(true);
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(color.get(), Color::ORANGE);
+        warning.set(false);
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(color.get(), Color::BLUE);
+    }
+
+    #[test]
+    fn dynamic_style_sheet() {
+        let sheet = Dynamic::new(StyleSheet::new());
+        let (mut recorder, _probe, color) = probe(|probe| {
+            probe
+                .centered()
+                .with(&TextColor, Color::BLUE)
+                .with_style_sheet(sheet.clone())
+                .make_widget()
+        });
+        assert_eq!(color.get(), Color::BLUE);
+
+        // Style sheets do not implement PartialEq, so they are replaced
+        // through a lock rather than with set().
+        *sheet.lock() = StyleSheet::new().with(
+            Selector::widget::<ColorProbe>(),
+            Styles::new().with(&TextColor, Color::RED),
+        );
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(color.get(), Color::RED);
+
+        *sheet.lock() = StyleSheet::new();
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(color.get(), Color::BLUE);
+    }
+}
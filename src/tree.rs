@@ -9,7 +9,7 @@ use parking_lot::Mutex;
 #[cfg(feature = "localization")]
 use unic_langid::LanguageIdentifier;
 
-use crate::styles::{Styles, ThemePair, VisualOrder};
+use crate::styles::{StyleSheet, StyleSheets, Styles, ThemePair, VisualOrder};
 use crate::value::Value;
 use crate::widget::{MountedWidget, WidgetId, WidgetInstance};
 use crate::window::{ThemeMode, WindowHandle};
@@ -28,13 +28,15 @@ impl Tree {
     ) -> MountedWidget {
         let mut data = self.data.lock();
         let id = widget.id();
-        let (effective_styles, parent_id) = if let Some(parent) = parent {
+        let (effective_styles, effective_style_sheets, parent_id) = if let Some(parent) = parent {
+            let parent_node = &data.nodes[parent.node_id];
             (
-                data.nodes[parent.node_id].child_styles(),
+                parent_node.child_styles(),
+                parent_node.child_style_sheets(),
                 Some(parent.node_id),
             )
         } else {
-            (Styles::default(), None)
+            (Styles::default(), StyleSheets::default(), None)
         };
         let node_id = data.nodes.push(Node {
             widget: widget.clone(),
@@ -44,6 +46,8 @@ impl Tree {
             layout: None,
             associated_styles: None,
             effective_styles,
+            associated_style_sheet: None,
+            effective_style_sheets,
             theme: None,
             theme_mode: None,
             #[cfg(feature = "localization")]
@@ -221,6 +225,15 @@ impl Tree {
         ordered
     }
 
+    pub(crate) fn children(&self, parent: LotId) -> Vec<MountedWidget> {
+        let data = self.data.lock();
+        data.nodes[parent]
+            .children
+            .iter()
+            .filter_map(|child| data.widget_from_node(*child, self))
+            .collect()
+    }
+
     pub(crate) fn effective_styles(&self, id: LotId) -> Styles {
         let data = self.data.lock();
         data.nodes[id].effective_styles.clone()
@@ -378,6 +391,19 @@ impl Tree {
         data.attach_styles(id, styles);
     }
 
+    pub(crate) fn attach_style_sheet(&self, id: LotId, style_sheet: Value<StyleSheet>) {
+        let mut data = self.data.lock();
+        data.attach_style_sheet(id, style_sheet);
+    }
+
+    pub(crate) fn style_sheets(&self, id: LotId) -> StyleSheets {
+        let data = self.data.lock();
+        data.nodes
+            .get(id)
+            .map(|node| node.effective_style_sheets.clone())
+            .unwrap_or_default()
+    }
+
     pub(crate) fn attach_theme(&self, id: LotId, theme: Value<ThemePair>) {
         let mut data = self.data.lock();
         data.nodes.get_mut(id).expect("missing widget").theme = Some(theme);
@@ -497,6 +523,32 @@ impl TreeData {
         }
     }
 
+    fn attach_style_sheet(&mut self, id: LotId, style_sheet: Value<StyleSheet>) {
+        let node = &mut self.nodes[id];
+        node.associated_style_sheet = Some(style_sheet);
+        if !node.children.is_empty() {
+            let child_style_sheets = node.child_style_sheets();
+            let children = node.children.clone();
+            self.update_effective_style_sheets(&child_style_sheets, children);
+        }
+    }
+
+    fn update_effective_style_sheets(
+        &mut self,
+        effective_style_sheets: &StyleSheets,
+        nodes_to_update: Vec<LotId>,
+    ) {
+        for id in nodes_to_update {
+            let node = &mut self.nodes[id];
+            node.effective_style_sheets = effective_style_sheets.clone();
+            if !node.children.is_empty() {
+                let child_style_sheets = node.child_style_sheets();
+                let children = node.children.clone();
+                self.update_effective_style_sheets(&child_style_sheets, children);
+            }
+        }
+    }
+
     fn remove_child(&mut self, child: LotId, children_to_unmount: &mut Vec<WidgetId>) {
         let Some(removed_node) = self.nodes.remove(child) else {
             return;
@@ -650,6 +702,8 @@ struct Node {
     last_layout_query: Option<CachedLayoutQuery>,
     associated_styles: Option<Value<Styles>>,
     effective_styles: Styles,
+    associated_style_sheet: Option<Value<StyleSheet>>,
+    effective_style_sheets: StyleSheets,
     theme: Option<Value<ThemePair>>,
     theme_mode: Option<Value<ThemeMode>>,
     #[cfg(feature = "localization")]
@@ -668,6 +722,13 @@ impl Node {
         }
         effective_styles
     }
+
+    fn child_style_sheets(&self) -> StyleSheets {
+        match &self.associated_style_sheet {
+            Some(associated) => self.effective_style_sheets.with(associated),
+            None => self.effective_style_sheets.clone(),
+        }
+    }
 }
 
 struct CachedLayoutQuery {
@@ -1,6 +1,6 @@
 //! Types for creating reusable widgets (aka components or views).
 
-use std::any::Any;
+use std::any::{Any, TypeId};
 use std::clone::Clone;
 use std::fmt::{self, Debug};
 use std::ops::{ControlFlow, Deref, DerefMut};
@@ -26,17 +26,21 @@ use crate::context::sealed::Trackable as _;
 use crate::context::{
     AsEventContext, EventContext, GraphicsContext, LayoutContext, ManageWidget, WidgetContext,
 };
+use crate::names::Name;
 use crate::styles::components::{HorizontalAlignment, IntrinsicPadding, VerticalAlignment};
 use crate::styles::{
     ComponentDefinition, ContainerLevel, ContextFreeComponent, Dimension, DimensionRange, Edges,
-    FlexibleDimension, HorizontalAlign, IntoComponentValue, IntoDynamicComponentValue, Styles,
-    ThemePair, VisualOrder,
+    FlexibleDimension, HorizontalAlign, IntoComponentValue, IntoDynamicComponentValue, StyleSheet,
+    StyleSheets, Styles, ThemePair, VisualOrder,
 };
 use crate::tree::{Tree, WeakTree};
 use crate::value::{Dynamic, Generation, IntoDynamic, IntoValue, Source, Validation, Value};
 use crate::widgets::checkbox::{Checkable, CheckboxState};
+use crate::widgets::gestures::{Gesture, Gestures};
 use crate::widgets::layers::{OverlayLayer, Tooltipped};
 use crate::widgets::list::List;
+use crate::widgets::menu::{ContextMenu, Menu};
+use crate::widgets::scroll::ScrollIntoView;
 use crate::widgets::shortcuts::{ShortcutKey, Shortcuts};
 #[cfg(feature = "localization")]
 use crate::widgets::Localized;
@@ -194,6 +198,26 @@ use crate::ConstraintLimit;
 /// the tracking widget's [`mouse_up()`](Self::mouse_up) function will be
 /// called.
 ///
+/// # Touch Events
+///
+/// Each finger touching a window is identified by its own
+/// [`DeviceId::Finger`]. When a finger begins touching a window, the window
+/// calls [`touch_down()`](Self::touch_down) on the top-most widget at the
+/// touch location whose [`hit_test()`](Self::hit_test) returns true. Just like
+/// mouse button events, the event is offered to each parent until a widget
+/// returns [`HANDLED`], and that widget becomes the *tracking* widget for the
+/// finger. The tracking widget receives [`touch_moved()`](Self::touch_moved)
+/// as the finger moves and [`touch_up()`](Self::touch_up) once the finger is
+/// lifted or the touch is cancelled.
+///
+/// If no widget handles a touch and no other finger is touching the window,
+/// the touch is delivered as [`MouseButton::Left`] events. This allows widgets
+/// that only support the mouse to be used with touch screens.
+///
+/// [`GestureRecognizer`](crate::widgets::gestures::GestureRecognizer) can be
+/// used to recognize taps, long presses, panning, and pinching from touch
+/// events.
+///
 /// # User Input Focus
 ///
 /// A window can have a widget be *focused* for user input. For example, a text
@@ -278,6 +302,14 @@ pub trait Widget: Send + Debug + 'static {
         Debug::fmt(self, f)
     }
 
+    /// Returns the text this widget is currently displaying, if any.
+    ///
+    /// This is used to find widgets by their visible text, such as with
+    /// [`VirtualWindow::find_text()`](crate::window::VirtualWindow::find_text).
+    fn displayed_text(&self) -> Option<&str> {
+        None
+    }
+
     /// Returns true if this widget handles all built-in style components that
     /// apply.
     ///
@@ -448,6 +480,51 @@ pub trait Widget: Send + Debug + 'static {
     ) {
     }
 
+    /// A finger has begun touching the window at `location`. Returns whether
+    /// the event has been handled or not.
+    ///
+    /// If an event is handled, the widget will receive callbacks for
+    /// [`touch_moved`](Self::touch_moved) and [`touch_up`](Self::touch_up)
+    /// for `device_id`. See [Touch Events](Self#touch-events) for more
+    /// information on how touch events work in Cushy.
+    #[allow(unused_variables)]
+    fn touch_down(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        IGNORED
+    }
+
+    /// A finger tracked by this widget has moved to `location`.
+    ///
+    /// This function will only be invoked if [`Self::touch_down`] returns
+    /// [`HANDLED`].
+    #[allow(unused_variables)]
+    fn touch_moved(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) {
+    }
+
+    /// A finger tracked by this widget is no longer touching the window.
+    ///
+    /// `cancelled` is true when the operating system cancelled the touch
+    /// rather than the finger being lifted. This function will only be
+    /// invoked if [`Self::touch_down`] returns [`HANDLED`].
+    #[allow(unused_variables)]
+    fn touch_up(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        cancelled: bool,
+        context: &mut EventContext<'_>,
+    ) {
+    }
+
     /// A keyboard event has been sent to this widget. Returns whether the event
     /// has been handled or not.
     #[allow(unused_variables)]
@@ -487,6 +564,23 @@ pub trait Widget: Send + Debug + 'static {
         IGNORED
     }
 
+    /// Scrolls this widget's contents so that `region` is visible, if this
+    /// widget supports scrolling. Returns the location of `region` after
+    /// scrolling.
+    ///
+    /// `region` is in window coordinates. This function is invoked on each
+    /// ancestor of a widget being scrolled into view, starting with its parent.
+    /// See [`WidgetContext::scroll_into_view()`] for more information.
+    #[allow(unused_variables)]
+    fn scroll_into_view(
+        &mut self,
+        region: Rect<Px>,
+        options: &ScrollIntoView,
+        context: &mut EventContext<'_>,
+    ) -> Rect<Px> {
+        region
+    }
+
     /// Returns a reference to a single child widget if this widget is a widget
     /// that primarily wraps a single other widget to customize its behavior.
     #[must_use]
@@ -497,6 +591,43 @@ pub trait Widget: Send + Debug + 'static {
     ) -> Option<(RootBehavior, WidgetInstance)> {
         None
     }
+
+    /// Returns the accessibility information for this widget, or `None` if
+    /// this widget should not be exposed to assistive technologies.
+    ///
+    /// Only the role and widget-specific properties such as the label, value,
+    /// or toggled state need to be provided. Cushy fills in the bounds,
+    /// children, and disabled state when building the
+    /// [`AccessibilityTree`](crate::accessibility::AccessibilityTree). Widgets
+    /// that return `None` are transparent: their children are attached to the
+    /// nearest ancestor that provides a node.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    #[allow(unused_variables)]
+    fn accessibility_node(&mut self, context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        None
+    }
+
+    /// An assistive technology has requested that `request.action` be
+    /// performed on `request.target`, which is either this widget or one of
+    /// its descendants. Returns whether the request has been handled or not.
+    ///
+    /// Requests are offered to the target widget first and then to each of its
+    /// ancestors until one handles it. Unhandled [`Action::Click`] requests
+    /// activate the target widget as if it were activated using the keyboard,
+    /// and unhandled [`Action::Focus`] requests focus it.
+    ///
+    /// [`Action::Click`]: accesskit::Action::Click
+    /// [`Action::Focus`]: accesskit::Action::Focus
+    #[cfg(feature = "accessibility")]
+    #[allow(unused_variables)]
+    fn accessibility_action(
+        &mut self,
+        request: &accesskit::ActionRequest,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        IGNORED
+    }
 }
 
 // ANCHOR: run
@@ -615,6 +746,14 @@ pub trait WrapperWidget: Debug + Send + 'static {
         Debug::fmt(self, f)
     }
 
+    /// Returns the text this widget is currently displaying, if any.
+    ///
+    /// This is used to find widgets by their visible text, such as with
+    /// [`VirtualWindow::find_text()`](crate::window::VirtualWindow::find_text).
+    fn displayed_text(&self) -> Option<&str> {
+        None
+    }
+
     /// Returns the behavior this widget should apply when positioned at the
     /// root of the window.
     ///
@@ -626,6 +765,31 @@ pub trait WrapperWidget: Debug + Send + 'static {
         Some(RootBehavior::PassThrough)
     }
 
+    /// Returns the accessibility information for this widget, or `None` if
+    /// this widget should not be exposed to assistive technologies.
+    ///
+    /// The provided implementation returns `None`, which causes the wrapped
+    /// widget to be attached to this widget's nearest accessible ancestor.
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    #[allow(unused_variables)]
+    fn accessibility_node(&mut self, context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        None
+    }
+
+    /// An assistive technology has requested that `request.action` be
+    /// performed on `request.target`, which is either this widget or one of
+    /// its descendants. Returns whether the request has been handled or not.
+    #[cfg(feature = "accessibility")]
+    #[allow(unused_variables)]
+    fn accessibility_action(
+        &mut self,
+        request: &accesskit::ActionRequest,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        IGNORED
+    }
+
     /// Draws the background of the widget.
     ///
     /// This is invoked before the wrapped widget is drawn.
@@ -803,6 +967,46 @@ pub trait WrapperWidget: Debug + Send + 'static {
     ) {
     }
 
+    /// A finger has begun touching the window at `location`. Returns whether
+    /// the event has been handled or not.
+    ///
+    /// If an event is handled, the widget will receive callbacks for
+    /// [`touch_moved`](Self::touch_moved) and [`touch_up`](Self::touch_up)
+    /// for `device_id`.
+    #[allow(unused_variables)]
+    fn touch_down(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        IGNORED
+    }
+
+    /// A finger tracked by this widget has moved to `location`.
+    #[allow(unused_variables)]
+    fn touch_moved(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) {
+    }
+
+    /// A finger tracked by this widget is no longer touching the window.
+    ///
+    /// `cancelled` is true when the operating system cancelled the touch
+    /// rather than the finger being lifted.
+    #[allow(unused_variables)]
+    fn touch_up(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        cancelled: bool,
+        context: &mut EventContext<'_>,
+    ) {
+    }
+
     /// A keyboard event has been sent to this widget. Returns whether the event
     /// has been handled or not.
     #[allow(unused_variables)]
@@ -835,6 +1039,23 @@ pub trait WrapperWidget: Debug + Send + 'static {
     ) -> EventHandling {
         IGNORED
     }
+
+    /// Scrolls this widget's contents so that `region` is visible, if this
+    /// widget supports scrolling. Returns the location of `region` after
+    /// scrolling.
+    ///
+    /// `region` is in window coordinates. This function is invoked on each
+    /// ancestor of a widget being scrolled into view, starting with its parent.
+    /// See [`WidgetContext::scroll_into_view()`] for more information.
+    #[allow(unused_variables)]
+    fn scroll_into_view(
+        &mut self,
+        region: Rect<Px>,
+        options: &ScrollIntoView,
+        context: &mut EventContext<'_>,
+    ) -> Rect<Px> {
+        region
+    }
 }
 
 impl<T> Widget for T
@@ -944,6 +1165,34 @@ where
         T::mouse_up(self, location, device_id, button, context);
     }
 
+    fn touch_down(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        T::touch_down(self, location, device_id, context)
+    }
+
+    fn touch_moved(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) {
+        T::touch_moved(self, location, device_id, context);
+    }
+
+    fn touch_up(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        cancelled: bool,
+        context: &mut EventContext<'_>,
+    ) {
+        T::touch_up(self, location, device_id, cancelled, context);
+    }
+
     fn keyboard_input(
         &mut self,
         device_id: DeviceId,
@@ -968,6 +1217,15 @@ where
         T::mouse_wheel(self, device_id, delta, phase, context)
     }
 
+    fn scroll_into_view(
+        &mut self,
+        region: Rect<Px>,
+        options: &ScrollIntoView,
+        context: &mut EventContext<'_>,
+    ) -> Rect<Px> {
+        T::scroll_into_view(self, region, options, context)
+    }
+
     fn advance_focus(
         &mut self,
         direction: VisualOrder,
@@ -983,6 +1241,24 @@ where
     fn summarize(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         T::summarize(self, fmt)
     }
+
+    fn displayed_text(&self) -> Option<&str> {
+        T::displayed_text(self)
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&mut self, context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        T::accessibility_node(self, context)
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_action(
+        &mut self,
+        request: &accesskit::ActionRequest,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        T::accessibility_action(self, request, context)
+    }
 }
 
 /// A type that can create a [`WidgetInstance`].
@@ -1071,6 +1347,40 @@ pub trait MakeWidget: Sized {
         Style::new(Styles::new().with_local_dynamic(name, dynamic), self)
     }
 
+    /// Applies the rules in `style_sheet` to `self` and its children.
+    ///
+    /// See [`StyleSheet`] for more information.
+    fn with_style_sheet(self, style_sheet: impl IntoValue<StyleSheet>) -> Style {
+        Style::new(Styles::new(), self).with_style_sheet(style_sheet)
+    }
+
+    /// Adds the style class `class` to this widget.
+    ///
+    /// Style classes are used by [`Selector`](crate::styles::Selector)s to
+    /// choose which widgets [`StyleSheet`] rules apply to.
+    ///
+    /// # Panics
+    ///
+    /// This function can only be called when one instance of the widget exists.
+    /// If any clones exist, a panic will occur.
+    fn with_class(self, class: impl Into<Name>) -> WidgetInstance {
+        self.make_widget().with_class(class)
+    }
+
+    /// Adds the style class `class` to this widget while `condition` is true.
+    ///
+    /// # Panics
+    ///
+    /// This function can only be called when one instance of the widget exists.
+    /// If any clones exist, a panic will occur.
+    fn with_class_when(
+        self,
+        class: impl Into<Name>,
+        condition: impl IntoValue<bool>,
+    ) -> WidgetInstance {
+        self.make_widget().with_class_when(class, condition)
+    }
+
     /// Invokes `callback` when `key` is pressed while `modifiers` are pressed.
     ///
     /// This shortcut will only be invoked if focus is within `self` or a child
@@ -1492,6 +1802,30 @@ pub trait MakeWidget: Sized {
     fn tooltip(self, layer: &OverlayLayer, tip: impl MakeWidget) -> Tooltipped {
         layer.new_tooltip(tip, self)
     }
+
+    /// Returns a widget that shows the [`Menu`] returned from `menu_factory`
+    /// on `layer` when a context menu is requested for `self`.
+    ///
+    /// Context menus are requested by right clicking, or by pressing the Menu
+    /// key or Shift+F10 while `self` or one of its children is focused. The
+    /// factory is invoked each time the menu is opened. See [`ContextMenu`]
+    /// for more information.
+    fn with_context_menu<T, F>(self, layer: &OverlayLayer, menu_factory: F) -> ContextMenu<T>
+    where
+        T: Unpin + Debug + Send + Clone + 'static,
+        F: FnMut() -> Menu<T> + Send + 'static,
+    {
+        ContextMenu::new(layer, menu_factory, self)
+    }
+
+    /// Returns a widget that invokes `on_gesture` for each touch [`Gesture`]
+    /// performed on `self`.
+    fn on_gesture<F>(self, on_gesture: F) -> Gestures
+    where
+        F: FnMut(Gesture) + Send + 'static,
+    {
+        Gestures::new(on_gesture, self)
+    }
 }
 
 /// A type that can create a [`WidgetInstance`] with a preallocated
@@ -1588,9 +1922,17 @@ struct WidgetInstanceData {
     cancel: bool,
     next_focus: Value<Option<WidgetId>>,
     enabled: Value<bool>,
+    classes: Vec<StyleClass>,
+    widget_type: TypeId,
     widget: Box<Mutex<dyn AnyWidget>>,
 }
 
+#[derive(Debug)]
+struct StyleClass {
+    name: Name,
+    condition: Value<bool>,
+}
+
 impl WidgetInstance {
     /// Returns a new instance containing `widget` that is assigned the unique
     /// `id` provided.
@@ -1606,6 +1948,8 @@ impl WidgetInstance {
                 cancel: false,
                 widget: Box::new(Mutex::new(widget)),
                 enabled: Value::Constant(true),
+                classes: Vec::new(),
+                widget_type: TypeId::of::<W>(),
             }),
         }
     }
@@ -1661,6 +2005,61 @@ impl WidgetInstance {
         self
     }
 
+    /// Adds the style class `class` to this widget and returns self.
+    ///
+    /// Style classes are used by [`Selector`](crate::styles::Selector)s to
+    /// choose which widgets [`StyleSheet`] rules apply to.
+    ///
+    /// # Panics
+    ///
+    /// This function can only be called when one instance of the widget exists.
+    /// If any clones exist, a panic will occur.
+    #[must_use]
+    pub fn with_class(self, class: impl Into<Name>) -> WidgetInstance {
+        self.with_class_when(class, true)
+    }
+
+    /// Adds the style class `class` to this widget while `condition` is true,
+    /// and returns self.
+    ///
+    /// # Panics
+    ///
+    /// This function can only be called when one instance of the widget exists.
+    /// If any clones exist, a panic will occur.
+    #[must_use]
+    pub fn with_class_when(
+        mut self,
+        class: impl Into<Name>,
+        condition: impl IntoValue<bool>,
+    ) -> WidgetInstance {
+        let data = Arc::get_mut(&mut self.data)
+            .expect("with_class can only be called on newly created widget instances");
+        data.classes.push(StyleClass {
+            name: class.into(),
+            condition: condition.into_value(),
+        });
+        self
+    }
+
+    /// Returns true if this widget currently has the style class `class`.
+    #[must_use]
+    pub fn has_class(&self, class: &str) -> bool {
+        self.data
+            .classes
+            .iter()
+            .any(|style_class| &*style_class.name == class && style_class.condition.get())
+    }
+
+    pub(crate) fn widget_type_id(&self) -> TypeId {
+        self.data.widget_type
+    }
+
+    pub(crate) fn invalidate_when_classes_change(&self, context: &WidgetContext<'_>) {
+        for class in &self.data.classes {
+            context.invalidate_when_changed(&class.condition);
+        }
+    }
+
     /// Sets this widget as a "default" widget.
     ///
     /// Default widgets are automatically activated when the user signals they
@@ -2278,6 +2677,14 @@ impl MountedWidget {
         self.tree().attach_styles(self.node_id, styles);
     }
 
+    pub(crate) fn attach_style_sheet(&self, style_sheet: Value<StyleSheet>) {
+        self.tree().attach_style_sheet(self.node_id, style_sheet);
+    }
+
+    pub(crate) fn style_sheets(&self) -> StyleSheets {
+        self.tree().style_sheets(self.node_id)
+    }
+
     pub(crate) fn attach_theme(&self, theme: Value<ThemePair>) {
         self.tree().attach_theme(self.node_id, theme);
     }
@@ -2313,6 +2720,10 @@ impl MountedWidget {
     pub(crate) fn visually_ordered_children(&self, order: VisualOrder) -> Vec<MountedWidget> {
         self.tree().visually_ordered_children(self.node_id, order)
     }
+
+    pub(crate) fn children(&self) -> Vec<MountedWidget> {
+        self.tree().children(self.node_id)
+    }
 }
 
 impl AsRef<WidgetId> for MountedWidget {
@@ -3014,6 +3425,20 @@ impl WidgetId {
     }
 }
 
+#[cfg(feature = "accessibility")]
+impl From<WidgetId> for accesskit::NodeId {
+    fn from(id: WidgetId) -> Self {
+        Self(id.0)
+    }
+}
+
+#[cfg(feature = "accessibility")]
+impl From<accesskit::NodeId> for WidgetId {
+    fn from(id: accesskit::NodeId) -> Self {
+        Self(id.0)
+    }
+}
+
 /// A [`WidgetId`] that has not been assigned to a [`WidgetInstance`].
 ///
 /// This type is passed to [`MakeWidgetWithTag::make_with_tag()`] to create a
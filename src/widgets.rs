@@ -6,13 +6,16 @@ mod canvas;
 pub mod checkbox;
 mod collapse;
 pub mod color;
+pub mod combo_box;
 mod component_probe;
 pub mod container;
 mod custom;
 mod data;
 pub mod delimiter;
 pub mod disclose;
+pub mod drag;
 mod expand;
+pub mod gestures;
 pub mod grid;
 pub mod image;
 pub mod indicator;
@@ -22,22 +25,32 @@ pub mod layers;
 pub mod list;
 #[cfg(feature = "localization")]
 mod localized;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod menu;
 mod mode_switch;
+pub mod number_input;
 pub mod pile;
 pub mod progress;
 pub mod radio;
 mod resize;
+pub mod rich_text;
 pub mod scroll;
 pub mod select;
 pub mod shortcuts;
 pub mod slider;
 mod space;
+pub mod split;
 pub mod stack;
 mod style;
+#[cfg(feature = "svg")]
+pub mod svg;
 mod switcher;
+pub mod table;
+pub mod tabs;
 mod themed;
 mod tilemap;
+pub mod tree_view;
 pub mod validated;
 mod virtual_list;
 pub mod wrap;
@@ -47,13 +60,16 @@ pub use self::button::Button;
 pub use self::canvas::Canvas;
 pub use self::checkbox::Checkbox;
 pub use self::collapse::Collapse;
+pub use self::combo_box::ComboBox;
 pub use self::component_probe::ComponentProbe;
 pub use self::container::Container;
 pub use self::custom::Custom;
 pub use self::data::Data;
 pub use self::delimiter::Delimiter;
 pub use self::disclose::Disclose;
+pub use self::drag::{DragSource, DropTarget};
 pub use self::expand::Expand;
+pub use self::gestures::Gestures;
 pub use self::grid::Grid;
 pub use self::image::Image;
 pub use self::input::Input;
@@ -61,20 +77,30 @@ pub use self::label::Label;
 pub use self::layers::Layers;
 #[cfg(feature = "localization")]
 pub use self::localized::Localized;
+#[cfg(feature = "markdown")]
+pub use self::markdown::Markdown;
 pub use self::menu::Menu;
 pub use self::mode_switch::ThemedMode;
+pub use self::number_input::NumberInput;
 pub use self::progress::ProgressBar;
 pub use self::radio::Radio;
 pub use self::resize::Resize;
+pub use self::rich_text::RichLabel;
 pub use self::scroll::Scroll;
 pub use self::select::Select;
 pub use self::slider::Slider;
 pub use self::space::Space;
+pub use self::split::Split;
 pub use self::stack::Stack;
 pub use self::style::Style;
+#[cfg(feature = "svg")]
+pub use self::svg::Svg;
 pub use self::switcher::Switcher;
+pub use self::table::Table;
+pub use self::tabs::Tabs;
 pub use self::themed::Themed;
 pub use self::tilemap::TileMap;
+pub use self::tree_view::TreeView;
 pub use self::validated::Validated;
-pub use self::virtual_list::VirtualList;
+pub use self::virtual_list::{VirtualList, VirtualListScroller};
 pub use self::wrap::Wrap;
@@ -579,6 +579,20 @@ impl Widget for Button {
     fn unmounted(&mut self, context: &mut EventContext<'_>) {
         self.content.unmount_in(context);
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&mut self, context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        let mut node = accesskit::Node::new(if context.is_default() {
+            accesskit::Role::DefaultButton
+        } else {
+            accesskit::Role::Button
+        });
+        node.add_action(accesskit::Action::Click);
+        if self.focusable {
+            node.add_action(accesskit::Action::Focus);
+        }
+        Some(node)
+    }
 }
 
 define_components! {
@@ -236,6 +236,18 @@ impl IndicatorBehavior for CheckboxIndicator {
         let state = if is_active { !state } else { state };
         draw_checkbox(state, colors, selected_color, region, context);
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&self) -> Option<accesskit::Node> {
+        Some(checkbox_node(self.state.get()))
+    }
+}
+
+#[cfg(feature = "accessibility")]
+fn checkbox_node(state: CheckboxState) -> accesskit::Node {
+    let mut node = accesskit::Node::new(accesskit::Role::CheckBox);
+    node.set_toggled(state.into());
+    node
 }
 
 fn draw_checkbox(
@@ -398,6 +410,17 @@ impl From<Option<bool>> for CheckboxState {
     }
 }
 
+#[cfg(feature = "accessibility")]
+impl From<CheckboxState> for accesskit::Toggled {
+    fn from(value: CheckboxState) -> Self {
+        match value {
+            CheckboxState::Indeterminant => accesskit::Toggled::Mixed,
+            CheckboxState::Unchecked => accesskit::Toggled::False,
+            CheckboxState::Checked => accesskit::Toggled::True,
+        }
+    }
+}
+
 impl TryFrom<CheckboxState> for bool {
     type Error = CheckboxToBoolError;
 
@@ -490,6 +513,11 @@ impl Widget for CheckboxOrnament {
             .ceil();
         Size::squared(checkbox_size)
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&mut self, _context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        Some(checkbox_node(self.value.get()))
+    }
 }
 
 /// A value that can be used as a checkbox.
@@ -0,0 +1,515 @@
+//! A widget for choosing a value from a list of options shown in an overlay.
+
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use figures::units::Px;
+use figures::Zero;
+use kludgine::app::winit::keyboard::{Key, NamedKey};
+
+use crate::context::EventContext;
+use crate::value::{Destination, Dynamic, IntoDynamic, IntoValue, MapEach, Source};
+use crate::widget::{
+    EventHandling, MakeWidget, MakeWidgetWithTag, WidgetId, WidgetInstance, WidgetList, WidgetRef,
+    WidgetTag, WrapperWidget, HANDLED, IGNORED,
+};
+use crate::widgets::button::ButtonKind;
+use crate::widgets::layers::{OverlayHandle, OverlayLayer, Overlayable};
+use crate::widgets::Input;
+use crate::window::{DeviceId, KeyEvent};
+
+/// A widget that shows the currently selected value, allowing a new value to
+/// be chosen from a list of options.
+///
+/// The list of options is shown in an [`OverlayLayer`] below the combo box.
+/// While the list is open, the up and down arrow keys change the highlighted
+/// option, Enter selects the highlighted option, and Escape closes the list.
+/// Typing filters the list to the options whose text contains the typed text.
+///
+/// [`ComboBox::editable`] replaces the button with an [`Input`], allowing any
+/// text to be entered while showing the matching options as suggestions.
+#[derive(Debug)]
+pub struct ComboBox<T> {
+    value: Dynamic<T>,
+    options: Dynamic<Vec<T>>,
+    overlay: OverlayLayer,
+    text: Option<Dynamic<String>>,
+}
+
+impl<T> ComboBox<T> {
+    /// Returns a combo box that updates `value` to the option chosen from
+    /// `options`. The list of options is shown in `overlay`.
+    #[must_use]
+    pub fn new(
+        value: impl IntoDynamic<T>,
+        options: impl IntoValue<Vec<T>>,
+        overlay: &OverlayLayer,
+    ) -> Self {
+        Self {
+            value: value.into_dynamic(),
+            options: options.into_value().into_dynamic(),
+            overlay: overlay.clone(),
+            text: None,
+        }
+    }
+}
+
+impl ComboBox<String> {
+    /// Allows any text to be entered rather than only one of the options, and
+    /// returns self.
+    ///
+    /// The options containing the entered text are shown as suggestions while
+    /// typing.
+    #[must_use]
+    pub fn editable(mut self) -> Self {
+        self.text = Some(self.value.clone());
+        self
+    }
+}
+
+impl<T> MakeWidgetWithTag for ComboBox<T>
+where
+    T: Clone + Display + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let (field_tag, field) = WidgetTag::new();
+        let state = Arc::new(ComboBoxState {
+            value: self.value,
+            options: self.options,
+            filter: self.text.clone().unwrap_or_default(),
+            highlighted: Dynamic::new(None),
+            popup: Dynamic::new(None),
+            overlay: self.overlay,
+            field,
+            editable: self.text.is_some(),
+        });
+
+        let child = if let Some(text) = self.text {
+            Input::new(text)
+                .on_key({
+                    let state = state.clone();
+                    move |input| state.input_key(&input)
+                })
+                // The input stays focused while the suggestions are shown, so
+                // the suggestions are closed once the input loses focus.
+                .on_blur({
+                    let state = state.clone();
+                    move || state.dismiss()
+                })
+                .make_with_tag(field_tag)
+        } else {
+            state
+                .value
+                .map_each(ToString::to_string)
+                .align_left()
+                .expand()
+                .and("\u{25BE}")
+                .into_columns()
+                .into_button()
+                .on_click({
+                    let state = state.clone();
+                    move |_| state.open()
+                })
+                .make_with_tag(field_tag)
+        };
+
+        ComboBoxField {
+            child: WidgetRef::new(child),
+            state,
+        }
+        .make_with_tag(tag)
+    }
+}
+
+#[derive(Debug)]
+struct ComboBoxState<T> {
+    value: Dynamic<T>,
+    options: Dynamic<Vec<T>>,
+    filter: Dynamic<String>,
+    highlighted: Dynamic<Option<usize>>,
+    popup: Dynamic<Option<OverlayHandle>>,
+    overlay: OverlayLayer,
+    field: WidgetId,
+    editable: bool,
+}
+
+impl<T> ComboBoxState<T>
+where
+    T: Clone + Display + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn is_open(&self) -> bool {
+        self.popup.map_ref(Option::is_some)
+    }
+
+    fn filtered(&self) -> Vec<T> {
+        let filter = self.filter.map_ref(|filter| filter.to_lowercase());
+        self.options.map_ref(|options| {
+            options
+                .iter()
+                .filter(|option| matches_filter(option, &filter))
+                .cloned()
+                .collect()
+        })
+    }
+
+    fn open(self: &Arc<Self>) {
+        if self.is_open() {
+            return;
+        }
+
+        if !self.editable {
+            self.filter.set(String::new());
+        }
+        let current = self.value.get();
+        self.highlighted
+            .set(self.filtered().iter().position(|option| option == &current));
+
+        let handle = self
+            .overlay
+            .build_overlay(ComboBoxPopup::new(self.clone()))
+            .below(self.field)
+            .show();
+        *self.popup.lock() = Some(handle);
+    }
+
+    fn dismiss(&self) {
+        // The handle must be dropped after the lock is released, as dismissing
+        // the overlay drops the popup, which references this state.
+        let handle = self.popup.lock().take();
+        drop(handle);
+    }
+
+    fn select(&self, index: usize) {
+        if let Some(option) = self.filtered().into_iter().nth(index) {
+            self.value.set(option);
+        }
+        self.dismiss();
+    }
+
+    fn highlight_first(&self) {
+        let count = self.filtered().len();
+        self.highlighted.set((count > 0).then_some(0));
+    }
+
+    fn navigate(&self, key: NamedKey) -> bool {
+        let count = self.filtered().len();
+        match key {
+            NamedKey::ArrowDown => {
+                self.highlighted
+                    .set(step_highlight(self.highlighted.get(), count, true))
+            }
+            NamedKey::ArrowUp => {
+                self.highlighted
+                    .set(step_highlight(self.highlighted.get(), count, false))
+            }
+            NamedKey::Home => self.highlighted.set((count > 0).then_some(0)),
+            NamedKey::End => self.highlighted.set(count.checked_sub(1)),
+            NamedKey::Enter => match self.highlighted.get() {
+                Some(index) => self.select(index),
+                None => self.dismiss(),
+            },
+            NamedKey::Escape => self.dismiss(),
+            _ => return false,
+        }
+        true
+    }
+
+    fn input_key(self: &Arc<Self>, input: &KeyEvent) -> EventHandling {
+        let pressed = input.state.is_pressed();
+        match &input.logical_key {
+            Key::Named(NamedKey::ArrowDown) if pressed && !self.is_open() => self.open(),
+            Key::Named(key @ (NamedKey::ArrowDown | NamedKey::ArrowUp)) => {
+                if pressed {
+                    self.navigate(*key);
+                }
+            }
+            Key::Named(key @ (NamedKey::Enter | NamedKey::Escape)) if self.is_open() => {
+                if pressed {
+                    self.navigate(*key);
+                }
+            }
+            Key::Named(NamedKey::Backspace | NamedKey::Delete) => {
+                // The text is about to change, which invalidates the
+                // highlighted suggestion.
+                if pressed {
+                    self.highlighted.set(None);
+                }
+                return IGNORED;
+            }
+            _ => {
+                if pressed && search_text(input).is_some() {
+                    self.open();
+                    self.highlighted.set(None);
+                }
+                return IGNORED;
+            }
+        }
+        HANDLED
+    }
+
+    fn push_search(&self, input: &KeyEvent) -> bool {
+        let Some(text) = search_text(input) else {
+            return false;
+        };
+        self.filter.lock().push_str(text);
+        self.highlight_first();
+        true
+    }
+}
+
+fn search_text(input: &KeyEvent) -> Option<&str> {
+    if input.modifiers.state().control_key() || input.modifiers.state().super_key() {
+        return None;
+    }
+    input
+        .text
+        .as_deref()
+        .filter(|text| !text.is_empty() && !text.chars().any(char::is_control))
+}
+
+/// Returns true if `option` should be shown when filtering by
+/// `lowercase_filter`.
+fn matches_filter(option: &impl Display, lowercase_filter: &str) -> bool {
+    lowercase_filter.is_empty() || option.to_string().to_lowercase().contains(lowercase_filter)
+}
+
+/// Returns the index to highlight after moving `forward` or backward from
+/// `current` in a list of `count` options.
+fn step_highlight(current: Option<usize>, count: usize, forward: bool) -> Option<usize> {
+    let last = count.checked_sub(1)?;
+    Some(match (current, forward) {
+        (None, true) => 0,
+        (None, false) => last,
+        (Some(current), true) => current.saturating_add(1).min(last),
+        (Some(current), false) => current.min(last).saturating_sub(1),
+    })
+}
+
+#[derive(Debug)]
+struct ComboBoxField<T> {
+    child: WidgetRef,
+    state: Arc<ComboBoxState<T>>,
+}
+
+impl<T> WrapperWidget for ComboBoxField<T>
+where
+    T: Clone + Display + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        _context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if self.state.editable || !input.state.is_pressed() {
+            return IGNORED;
+        }
+
+        match &input.logical_key {
+            Key::Named(NamedKey::ArrowDown | NamedKey::ArrowUp) => self.state.open(),
+            _ if search_text(&input).is_some() => {
+                self.state.open();
+                self.state.push_search(&input);
+            }
+            _ => return IGNORED,
+        }
+        HANDLED
+    }
+}
+
+#[derive(Debug)]
+struct ComboBoxPopup<T> {
+    child: WidgetRef,
+    state: Arc<ComboBoxState<T>>,
+}
+
+impl<T> ComboBoxPopup<T>
+where
+    T: Clone + Display + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn new(state: Arc<ComboBoxState<T>>) -> Self {
+        let rows = (&state.options, &state.filter).map_each({
+            let state = state.clone();
+            move |(options, filter)| {
+                let filter = filter.to_lowercase();
+                options
+                    .iter()
+                    .filter(|option| matches_filter(option, &filter))
+                    .enumerate()
+                    .map(|(index, option)| {
+                        let kind = state.highlighted.map_each(move |highlighted| {
+                            if *highlighted == Some(index) {
+                                ButtonKind::Solid
+                            } else {
+                                ButtonKind::Transparent
+                            }
+                        });
+                        let state = state.clone();
+                        option
+                            .to_string()
+                            .align_left()
+                            .into_button()
+                            .kind(kind)
+                            .prevent_focus()
+                            .on_click(move |_| state.select(index))
+                            .make_widget()
+                    })
+                    .collect::<WidgetList>()
+            }
+        });
+        let list = rows.into_rows().gutter(Px::ZERO).vertical_scroll();
+        let contents = if state.editable {
+            list.make_widget()
+        } else {
+            state
+                .filter
+                .map_each(|filter| {
+                    if filter.is_empty() {
+                        String::from("Type to search")
+                    } else {
+                        format!("Search: {filter}")
+                    }
+                })
+                .and(list)
+                .into_rows()
+                .make_widget()
+        };
+
+        Self {
+            child: WidgetRef::new(contents.contain()),
+            state,
+        }
+    }
+}
+
+impl<T> WrapperWidget for ComboBoxPopup<T>
+where
+    T: Clone + Display + Debug + PartialEq + Send + Sync + 'static,
+{
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn accept_focus(&mut self, _context: &mut EventContext<'_>) -> bool {
+        !self.state.editable
+    }
+
+    fn mounted(&mut self, context: &mut EventContext<'_>) {
+        if !self.state.editable {
+            context.focus();
+        }
+    }
+
+    fn blur(&mut self, _context: &mut EventContext<'_>) {
+        if !self.state.editable {
+            self.state.dismiss();
+        }
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if self.state.editable || !input.state.is_pressed() {
+            return IGNORED;
+        }
+
+        let handled = match &input.logical_key {
+            Key::Named(NamedKey::Backspace) => {
+                self.state.filter.lock().pop();
+                self.state.highlight_first();
+                true
+            }
+            Key::Named(key) => self.state.navigate(*key),
+            _ => self.state.push_search(&input),
+        };
+        if !handled {
+            return IGNORED;
+        }
+
+        // Return focus to the combo box once the list has been closed.
+        if !self.state.is_open() {
+            if let Some(field) = self.state.field.find_in(context) {
+                context.for_other(&field).focus();
+            }
+        }
+        HANDLED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{matches_filter, step_highlight, ComboBox, ComboBoxField};
+    use crate::value::{Dynamic, Source};
+    use crate::widget::{MakeWidget, MountedWidget};
+    use crate::widgets::layers::OverlayLayer;
+    use crate::widgets::{Button, Input};
+
+    #[test]
+    fn filtering() {
+        assert!(matches_filter(&"Apple", ""));
+        assert!(matches_filter(&"Apple", "pp"));
+        assert!(matches_filter(&"Apple", "apple"));
+        assert!(!matches_filter(&"Apple", "pear"));
+    }
+
+    #[test]
+    fn stepping() {
+        assert_eq!(step_highlight(None, 0, true), None);
+        assert_eq!(step_highlight(Some(2), 0, false), None);
+        assert_eq!(step_highlight(None, 3, true), Some(0));
+        assert_eq!(step_highlight(None, 3, false), Some(2));
+        assert_eq!(step_highlight(Some(0), 3, true), Some(1));
+        assert_eq!(step_highlight(Some(2), 3, true), Some(2));
+        assert_eq!(step_highlight(Some(0), 3, false), Some(0));
+        // The highlight is clamped when the list has shrunk.
+        assert_eq!(step_highlight(Some(5), 3, false), Some(1));
+    }
+
+    #[test]
+    fn editable_dismisses_on_blur() {
+        let overlay = OverlayLayer::default();
+        let text = Dynamic::new(String::new());
+        let options = vec![String::from("Apple"), String::from("Banana")];
+        let mut recorder = ComboBox::new(text.clone(), options, &overlay)
+            .editable()
+            .and("Other".into_button())
+            .into_rows()
+            .and(overlay)
+            .into_layers()
+            .build_recorder()
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+
+        let window = &mut recorder.window;
+        let field = window
+            .find_widget::<ComboBoxField<String>>()
+            .expect("combo box");
+        let is_open = |field: &MountedWidget| {
+            field
+                .lock()
+                .downcast_ref::<ComboBoxField<String>>()
+                .expect("combo box")
+                .state
+                .is_open()
+        };
+        let input = window.find_widget::<Input<String>>().expect("input");
+        window.type_text(&input, "a");
+        assert_eq!(text.get(), "a");
+        assert!(is_open(&field));
+
+        let other = window.find_widget::<Button>().expect("other button");
+        window.focus(&other);
+        recorder.refresh().expect("error refreshing");
+        assert!(!is_open(&field));
+    }
+}
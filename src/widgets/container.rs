@@ -7,19 +7,26 @@ use figures::{Abs, Angle, IntoSigned, IntoUnsigned, Point, Rect, Round, ScreenSc
 use kludgine::shapes::{CornerRadii, PathBuilder, Shape};
 use kludgine::Color;
 
+pub use self::background::{Gradient, GradientColor, GradientKind, GradientStop, NineSlice};
+use crate::animation::{LinearInterpolate, ZeroToOne};
 use crate::context::{EventContext, GraphicsContext, LayoutContext, WidgetContext};
-use crate::styles::components::{CornerRadius, IntrinsicPadding, Opacity, SurfaceColor};
+use crate::styles::components::{
+    BorderColor, BorderStyle, BorderWidth, CornerRadius, IntrinsicPadding, Opacity, SurfaceColor,
+};
 use crate::styles::{Component, ContainerLevel, Dimension, Edges, RequireInvalidation, Styles};
 use crate::value::{Dynamic, IntoValue, Source, Value};
 use crate::widget::{MakeWidget, RootBehavior, Widget, WidgetInstance, WidgetRef};
 use crate::ConstraintLimit;
 
+mod background;
+mod border;
+
 /// A visual container widget, optionally applying padding and a background
 /// color.
 ///
 /// # Background Color Selection
 ///
-/// This widget has three different modes for coloring its background:
+/// This widget has several different modes for filling its background:
 ///
 /// - [`ContainerBackground::Auto`]: The background color is automatically
 ///   selected by using the [next](ContainerLevel::next) level from the next
@@ -32,6 +39,19 @@ use crate::ConstraintLimit;
 /// - [`ContainerBackground::Level`]: The
 ///   [`SurfaceTheme`](crate::styles::SurfaceTheme) container color associated
 ///   with the given level will be used.
+/// - [`ContainerBackground::Gradient`]: The specified [`Gradient`] will be
+///   drawn.
+/// - [`ContainerBackground::Image`]: The specified [`NineSlice`] image will be
+///   stretched to fill the container.
+///
+/// # Borders
+///
+/// A border is drawn inside of the container's background using the
+/// [`BorderWidth`], [`BorderColor`], and [`BorderStyle`] components. Each edge
+/// of the border can have its own width, color, and
+/// [`LineStyle`](crate::styles::LineStyle). The border follows the
+/// container's [`CornerRadius`], and the contained widget is padded by the
+/// border's width in addition to the container's padding.
 #[derive(Debug)]
 pub struct Container {
     /// The configured background selection.
@@ -48,7 +68,7 @@ pub struct Container {
 }
 
 /// A strategy of applying a background to a [`Container`].
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum ContainerBackground {
     /// Automatically select a [`ContainerLevel`] by picking the
     /// [next](ContainerLevel::next) level after the previous parent
@@ -84,6 +104,40 @@ pub enum ContainerBackground {
     /// [hh]: ContainerLevel::Highest
     /// [hhc]: crate::styles::SurfaceTheme::highest_container
     Level(ContainerLevel),
+    /// Fills the background with a gradient.
+    ///
+    /// Widgets contained within will treat the color at the middle of the
+    /// gradient as the container's background color.
+    Gradient(Gradient),
+    /// Fills the background by stretching an image.
+    ///
+    /// The image is drawn without rounding the container's corners. Widgets
+    /// contained within will use the same background as the parent
+    /// container.
+    Image(NineSlice),
+}
+
+impl LinearInterpolate for ContainerBackground {
+    fn lerp(&self, target: &Self, percent: f32) -> Self {
+        match (self, target) {
+            (ContainerBackground::Color(start), ContainerBackground::Color(end)) => {
+                ContainerBackground::Color(start.lerp(end, percent))
+            }
+            (ContainerBackground::Gradient(start), ContainerBackground::Gradient(end)) => {
+                ContainerBackground::Gradient(start.lerp(end, percent))
+            }
+            // Colors transition to and from gradients by treating the color as
+            // a gradient of a single color.
+            (ContainerBackground::Color(start), ContainerBackground::Gradient(end)) => {
+                ContainerBackground::Gradient(end.recolored(*start).lerp(end, percent))
+            }
+            (ContainerBackground::Gradient(start), ContainerBackground::Color(end)) => {
+                ContainerBackground::Gradient(start.lerp(&start.recolored(*end), percent))
+            }
+            _ if percent < 0.5 => self.clone(),
+            _ => target.clone(),
+        }
+    }
 }
 
 impl From<ContainerLevel> for ContainerBackground {
@@ -98,6 +152,18 @@ impl From<Color> for ContainerBackground {
     }
 }
 
+impl From<Gradient> for ContainerBackground {
+    fn from(value: Gradient) -> Self {
+        Self::Gradient(value)
+    }
+}
+
+impl From<NineSlice> for ContainerBackground {
+    fn from(value: NineSlice) -> Self {
+        Self::Image(value)
+    }
+}
+
 impl Container {
     /// Returns a new container wrapping `child` with default padding and a
     /// background color automatically selected by the theme.
@@ -151,6 +217,37 @@ impl Container {
         self
     }
 
+    /// Sets this container to render the specified `gradient` background, and
+    /// then returns the updated container.
+    #[must_use]
+    pub fn background_gradient(mut self, gradient: impl IntoValue<Gradient>) -> Self {
+        self.background = gradient
+            .into_value()
+            .map_each(|gradient| ContainerBackground::from(gradient.clone()));
+        self
+    }
+
+    /// Sets this container to render the specified nine-slice `image` as its
+    /// background, and then returns the updated container.
+    #[must_use]
+    pub fn background_image(mut self, image: impl IntoValue<NineSlice>) -> Self {
+        self.background = image
+            .into_value()
+            .map_each(|image| ContainerBackground::from(image.clone()));
+        self
+    }
+
+    /// Sets this container's background, and then returns the updated
+    /// container.
+    ///
+    /// When `background` is a [`Dynamic`], transitioning it using an
+    /// animation will smoothly blend between colors and gradients.
+    #[must_use]
+    pub fn background(mut self, background: impl IntoValue<ContainerBackground>) -> Self {
+        self.background = background.into_value();
+        self
+    }
+
     /// Renders `shadow` behind the container's background.
     #[must_use]
     pub fn shadow(mut self, shadow: impl IntoValue<ContainerShadow>) -> Self {
@@ -166,16 +263,47 @@ impl Container {
         .map(|dim| dim.into_px(context.gfx.scale()).round())
     }
 
-    fn effective_background_color(&mut self, context: &WidgetContext<'_>) -> kludgine::Color {
-        let background = match self.background.get() {
-            ContainerBackground::Color(color) => EffectiveBackground::Color(color),
-            ContainerBackground::Level(level) => EffectiveBackground::Level(level),
+    fn border(context: &GraphicsContext<'_, '_, '_, '_>) -> border::Border {
+        border::Border {
+            widths: context
+                .get(&BorderWidth)
+                .map(|width| width.into_px(context.gfx.scale()).round()),
+            colors: context.get(&BorderColor),
+            styles: context.get(&BorderStyle),
+        }
+    }
+
+    fn effective_background(&mut self, context: &WidgetContext<'_>) -> ResolvedBackground {
+        let (background, resolved) = match self.background.get_tracking_redraw(context) {
+            ContainerBackground::Color(color) => (
+                EffectiveBackground::Color(color),
+                ResolvedBackground::Color(color),
+            ),
+            ContainerBackground::Level(level) => (
+                EffectiveBackground::Level(level),
+                ResolvedBackground::Color(level_color(level, context)),
+            ),
             ContainerBackground::Auto => {
-                EffectiveBackground::Level(match context.get(&CurrentContainerBackground) {
+                let level = match context.get(&CurrentContainerBackground) {
                     EffectiveBackground::Color(_) => ContainerLevel::default(),
                     EffectiveBackground::Level(level) => level.next().unwrap_or_default(),
-                })
+                };
+                (
+                    EffectiveBackground::Level(level),
+                    ResolvedBackground::Color(level_color(level, context)),
+                )
             }
+            ContainerBackground::Gradient(gradient) => {
+                let gradient = gradient.resolve(context);
+                (
+                    EffectiveBackground::Color(gradient.color_at(0.5)),
+                    ResolvedBackground::Gradient(gradient),
+                )
+            }
+            ContainerBackground::Image(image) => (
+                context.get(&CurrentContainerBackground),
+                ResolvedBackground::Image(image),
+            ),
         };
 
         if self.applied_background != Some(background) {
@@ -183,16 +311,38 @@ impl Container {
             self.applied_background = Some(background);
         }
 
-        match background {
-            EffectiveBackground::Color(color) => color,
-            EffectiveBackground::Level(level) => match level {
-                ContainerLevel::Lowest => context.theme().surface.lowest_container,
-                ContainerLevel::Low => context.theme().surface.low_container,
-                ContainerLevel::Mid => context.theme().surface.container,
-                ContainerLevel::High => context.theme().surface.high_container,
-                ContainerLevel::Highest => context.theme().surface.highest_container,
-            },
-        }
+        resolved
+    }
+}
+
+fn level_color(level: ContainerLevel, context: &WidgetContext<'_>) -> Color {
+    match level {
+        ContainerLevel::Lowest => context.theme().surface.lowest_container,
+        ContainerLevel::Low => context.theme().surface.low_container,
+        ContainerLevel::Mid => context.theme().surface.container,
+        ContainerLevel::High => context.theme().surface.high_container,
+        ContainerLevel::Highest => context.theme().surface.highest_container,
+    }
+}
+
+/// A [`ContainerBackground`] that is ready to be drawn.
+enum ResolvedBackground {
+    Color(Color),
+    Gradient(background::ResolvedGradient),
+    Image(NineSlice),
+}
+
+impl ResolvedBackground {
+    /// Returns the color to base the container's shadow on, after applying
+    /// `opacity`, or None if nothing is drawn.
+    fn shadow_basis(&self, opacity: ZeroToOne) -> Option<Color> {
+        let basis = match self {
+            ResolvedBackground::Color(color) => *color,
+            ResolvedBackground::Gradient(gradient) => Color::BLACK.with_alpha(gradient.max_alpha()),
+            ResolvedBackground::Image(_) => Color::BLACK,
+        };
+        let basis = basis.with_alpha_f32(basis.alpha_f32() * *opacity);
+        (basis.alpha() > 0).then_some(basis)
     }
 }
 
@@ -218,36 +368,48 @@ impl Widget for Container {
     fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
         let opacity = context.get(&Opacity);
 
-        let background = self.effective_background_color(context);
-        let background = background.with_alpha_f32(background.alpha_f32() * *opacity);
-        if background.alpha() > 0 {
-            let shadow = self
-                .shadow
-                .get_tracking_invalidate(context)
-                .into_px(context.gfx.scale())
-                .ceil();
+        let shadow = self
+            .shadow
+            .get_tracking_invalidate(context)
+            .into_px(context.gfx.scale())
+            .ceil();
 
-            let child_shadow_offset = shadow.offset.min(Point::ZERO).abs().ceil();
-            let child_size = context.gfx.region().size - shadow.spread * 2 - shadow.offset.abs();
-            let child_area = Rect::new(child_shadow_offset + shadow.spread, child_size);
+        let child_shadow_offset = shadow.offset.min(Point::ZERO).abs().ceil();
+        let child_size = context.gfx.region().size - shadow.spread * 2 - shadow.offset.abs();
+        let child_area = Rect::new(child_shadow_offset + shadow.spread, child_size);
 
-            let corner_radii = context
-                .get(&CornerRadius)
-                .into_px(context.gfx.scale())
-                .ceil();
+        let corner_radii = context
+            .get(&CornerRadius)
+            .into_px(context.gfx.scale())
+            .ceil();
 
+        let mut background = self.effective_background(context);
+        if let Some(shadow_basis) = background.shadow_basis(opacity) {
             // check if the shadow would be obscured before we try to draw it.
             if child_area.origin != Point::ZERO || child_size != context.gfx.region().size {
-                render_shadow(&child_area, corner_radii, &shadow, background, context);
+                render_shadow(&child_area, corner_radii, &shadow, shadow_basis, context);
             }
 
-            context.gfx.draw_shape(&Shape::filled_round_rect(
-                child_area,
-                corner_radii,
-                background,
-            ));
+            match &mut background {
+                ResolvedBackground::Color(_) => {
+                    context.gfx.draw_shape(&Shape::filled_round_rect(
+                        child_area,
+                        corner_radii,
+                        shadow_basis,
+                    ));
+                }
+                ResolvedBackground::Gradient(gradient) => {
+                    gradient.apply_opacity(opacity);
+                    gradient.draw(child_area, corner_radii, context);
+                }
+                ResolvedBackground::Image(image) => {
+                    image.draw(child_area, opacity, context);
+                }
+            }
         }
 
+        Self::border(context).draw(child_area, corner_radii, opacity, context);
+
         let child = self.child.mounted(context);
         context.for_other(&child).redraw();
     }
@@ -287,6 +449,9 @@ impl Widget for Container {
             .bottom
             .max(corner_radii.bottom_right / std::f32::consts::PI)
             .max(corner_radii.bottom_left / std::f32::consts::PI);
+        padding += context
+            .get(&BorderWidth)
+            .map(|width| width.into_upx(context.gfx.scale()).round());
         let padding_amount = padding.size();
 
         let shadow = self
@@ -323,6 +488,9 @@ impl Widget for Container {
             .as_ref()
             .map(|padding| padding.get().into_px(context.kludgine.scale()))
             .unwrap_or_default();
+        padding += context
+            .get(&BorderWidth)
+            .map(|width| width.into_px(context.kludgine.scale()).round());
         let shadow = self
             .shadow
             .get_tracking_invalidate(context)
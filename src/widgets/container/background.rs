@@ -0,0 +1,893 @@
+use std::f32::consts::{PI, TAU};
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use figures::units::{Px, UPx};
+use figures::{Angle, FloatConversion, IntoSigned, Point, Rect, Size, Zero};
+use kludgine::shapes::{CornerRadii, PathBuilder, Shape};
+use kludgine::{AnyTexture, Color};
+
+use crate::animation::{LinearInterpolate, ZeroToOne};
+use crate::context::{GraphicsContext, WidgetContext};
+use crate::styles::{Component, DynamicComponent, DynamicComponentResolver, Edges};
+
+/// The number of segments used to approximate round gradients.
+const ROUND_SEGMENTS: u16 = 90;
+/// The number of segments used to approximate each rounded corner.
+pub(super) const CORNER_SEGMENTS: u16 = 8;
+
+/// A smooth transition between multiple colors.
+///
+/// Gradients can be animated. Interpolating between two gradients of the same
+/// [`GradientKind`] interpolates their geometry and each pair of color stops.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    /// The shape of the gradient.
+    pub kind: GradientKind,
+    /// The colors of the gradient, ordered by position.
+    pub stops: Vec<GradientStop>,
+}
+
+impl Gradient {
+    /// Returns a gradient with no stops that changes color along `angle`.
+    ///
+    /// An angle of 0 degrees transitions from left to right, and an angle of
+    /// 90 degrees transitions from top to bottom.
+    #[must_use]
+    pub const fn linear(angle: Angle) -> Self {
+        Self {
+            kind: GradientKind::Linear { angle },
+            stops: Vec::new(),
+        }
+    }
+
+    /// Returns a gradient with no stops that radiates outward from the center
+    /// of the filled area.
+    #[must_use]
+    pub fn radial() -> Self {
+        Self::radial_at(Point::squared(ZeroToOne::new(0.5)))
+    }
+
+    /// Returns a gradient with no stops that radiates outward from `center`.
+    ///
+    /// `center` is relative to the size of the filled area.
+    #[must_use]
+    pub const fn radial_at(center: Point<ZeroToOne>) -> Self {
+        Self {
+            kind: GradientKind::Radial { center },
+            stops: Vec::new(),
+        }
+    }
+
+    /// Returns a gradient with no stops that sweeps clockwise around the center
+    /// of the filled area, starting at the right edge.
+    #[must_use]
+    pub fn conic() -> Self {
+        Self::conic_at(Point::squared(ZeroToOne::new(0.5)), Angle::degrees(0))
+    }
+
+    /// Returns a gradient with no stops that sweeps clockwise around `center`,
+    /// beginning at `start`.
+    ///
+    /// `center` is relative to the size of the filled area.
+    #[must_use]
+    pub const fn conic_at(center: Point<ZeroToOne>, start: Angle) -> Self {
+        Self {
+            kind: GradientKind::Conic { center, start },
+            stops: Vec::new(),
+        }
+    }
+
+    /// Adds a color stop at `position` and returns self.
+    #[must_use]
+    pub fn with_stop(
+        mut self,
+        position: impl Into<ZeroToOne>,
+        color: impl Into<GradientColor>,
+    ) -> Self {
+        self.push_stop(position, color);
+        self
+    }
+
+    /// Adds a color stop at `position`.
+    ///
+    /// Stops are kept ordered by their position. Stops sharing a position are
+    /// kept in the order they were added, allowing hard transitions between
+    /// colors.
+    pub fn push_stop(&mut self, position: impl Into<ZeroToOne>, color: impl Into<GradientColor>) {
+        let stop = GradientStop {
+            position: position.into(),
+            color: color.into(),
+        };
+        let index = self
+            .stops
+            .partition_point(|existing| existing.position <= stop.position);
+        self.stops.insert(index, stop);
+    }
+
+    /// Returns a gradient with the same shape and stops as `self`, except that
+    /// every stop is `color`.
+    pub(super) fn recolored(&self, color: Color) -> Self {
+        Self {
+            kind: self.kind,
+            stops: self
+                .stops
+                .iter()
+                .map(|stop| GradientStop {
+                    position: stop.position,
+                    color: GradientColor::Color(color),
+                })
+                .collect(),
+        }
+    }
+
+    pub(super) fn resolve(&self, context: &WidgetContext<'_>) -> ResolvedGradient {
+        ResolvedGradient {
+            kind: self.kind,
+            stops: self
+                .stops
+                .iter()
+                .map(|stop| (*stop.position, stop.color.resolve(context)))
+                .collect(),
+        }
+    }
+}
+
+impl LinearInterpolate for Gradient {
+    fn lerp(&self, target: &Self, percent: f32) -> Self {
+        let Some(kind) = self.kind.lerp_kind(&target.kind, percent) else {
+            return if percent < 0.5 {
+                self.clone()
+            } else {
+                target.clone()
+            };
+        };
+
+        // When the number of stops differ, the last stop of the shorter list
+        // is repeated.
+        let stop_count = self.stops.len().max(target.stops.len());
+        let stops = (0..stop_count)
+            .filter_map(|index| {
+                let start = self.stops.get(index).or_else(|| self.stops.last());
+                let end = target.stops.get(index).or_else(|| target.stops.last());
+                match (start, end) {
+                    (Some(start), Some(end)) => Some(start.lerp(end, percent)),
+                    (Some(stop), None) | (None, Some(stop)) => Some(stop.clone()),
+                    (None, None) => None,
+                }
+            })
+            .collect();
+        Self { kind, stops }
+    }
+}
+
+/// The shape of a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GradientKind {
+    /// Colors change along a straight line.
+    ///
+    /// The gradient spans the entire filled area along `angle`.
+    Linear {
+        /// The direction the gradient travels, measured clockwise from
+        /// pointing right.
+        angle: Angle,
+    },
+    /// Colors change in circles radiating outward from `center`.
+    ///
+    /// The end of the gradient is placed at the corner of the filled area
+    /// furthest from `center`.
+    Radial {
+        /// The center of the gradient, relative to the size of the filled
+        /// area.
+        center: Point<ZeroToOne>,
+    },
+    /// Colors change while sweeping clockwise around `center`.
+    Conic {
+        /// The center of the gradient, relative to the size of the filled
+        /// area.
+        center: Point<ZeroToOne>,
+        /// The angle the gradient begins at, measured clockwise from pointing
+        /// right.
+        start: Angle,
+    },
+}
+
+impl GradientKind {
+    fn lerp_kind(&self, target: &Self, percent: f32) -> Option<Self> {
+        match (self, target) {
+            (GradientKind::Linear { angle: start }, GradientKind::Linear { angle: end }) => {
+                Some(GradientKind::Linear {
+                    angle: start.lerp(end, percent),
+                })
+            }
+            (GradientKind::Radial { center: start }, GradientKind::Radial { center: end }) => {
+                Some(GradientKind::Radial {
+                    center: start.lerp(end, percent),
+                })
+            }
+            (
+                GradientKind::Conic {
+                    center: start_center,
+                    start: start_angle,
+                },
+                GradientKind::Conic {
+                    center: end_center,
+                    start: end_angle,
+                },
+            ) => Some(GradientKind::Conic {
+                center: start_center.lerp(end_center, percent),
+                start: start_angle.lerp(end_angle, percent),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A color at a specific position within a [`Gradient`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientStop {
+    /// The position of this stop, where 0.0 is the start of the gradient and
+    /// 1.0 is the end of the gradient.
+    pub position: ZeroToOne,
+    /// The color of the gradient at this stop.
+    pub color: GradientColor,
+}
+
+impl LinearInterpolate for GradientStop {
+    fn lerp(&self, target: &Self, percent: f32) -> Self {
+        Self {
+            position: self.position.lerp(&target.position, percent),
+            color: self.color.lerp(&target.color, percent),
+        }
+    }
+}
+
+/// The color of a [`GradientStop`].
+#[derive(Clone, PartialEq)]
+pub enum GradientColor {
+    /// A specific color.
+    Color(Color),
+    /// A color that is resolved from the widget's styles when drawn, such as a
+    /// theme color.
+    Dynamic(DynamicComponent),
+}
+
+impl GradientColor {
+    fn resolve(&self, context: &WidgetContext<'_>) -> Color {
+        match self {
+            GradientColor::Color(color) => *color,
+            GradientColor::Dynamic(dynamic) => match dynamic.resolve(context) {
+                Some(Component::Color(color)) => color,
+                _ => Color::CLEAR_BLACK,
+            },
+        }
+    }
+}
+
+impl Debug for GradientColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Color(color) => Debug::fmt(color, f),
+            Self::Dynamic(dynamic) => Debug::fmt(dynamic, f),
+        }
+    }
+}
+
+impl LinearInterpolate for GradientColor {
+    fn lerp(&self, target: &Self, percent: f32) -> Self {
+        match (self, target) {
+            (GradientColor::Color(start), GradientColor::Color(end)) => {
+                GradientColor::Color(start.lerp(end, percent))
+            }
+            _ if percent < 0.5 => self.clone(),
+            _ => target.clone(),
+        }
+    }
+}
+
+impl From<Color> for GradientColor {
+    fn from(color: Color) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl From<DynamicComponent> for GradientColor {
+    fn from(dynamic: DynamicComponent) -> Self {
+        Self::Dynamic(dynamic)
+    }
+}
+
+impl<T> From<T> for GradientColor
+where
+    T: DynamicComponentResolver,
+{
+    fn from(component: T) -> Self {
+        Self::Dynamic(DynamicComponent::from(component))
+    }
+}
+
+/// A texture drawn by stretching its center while preserving its edges.
+///
+/// The texture is split into nine regions using `insets`. The corners are
+/// drawn without scaling, the edges are stretched along one axis, and the
+/// center is stretched to fill the remaining area.
+#[derive(Clone)]
+pub struct NineSlice {
+    texture: Arc<AnyTexture>,
+    /// The size of each edge of the texture that is preserved.
+    pub insets: Edges<UPx>,
+}
+
+impl NineSlice {
+    /// Returns a new nine-slice image from `texture`, preserving `insets` of
+    /// each edge.
+    pub fn new(texture: impl Into<AnyTexture>, insets: impl Into<Edges<UPx>>) -> Self {
+        Self {
+            texture: Arc::new(texture.into()),
+            insets: insets.into(),
+        }
+    }
+
+    /// Returns the texture being drawn.
+    #[must_use]
+    pub fn texture(&self) -> &AnyTexture {
+        &self.texture
+    }
+
+    pub(super) fn draw(
+        &self,
+        area: Rect<Px>,
+        opacity: ZeroToOne,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+    ) {
+        let texture_size = self.texture.size();
+        let source_columns = slice_axis(
+            UPx::ZERO,
+            texture_size.width,
+            self.insets.left,
+            self.insets.right,
+        );
+        let source_rows = slice_axis(
+            UPx::ZERO,
+            texture_size.height,
+            self.insets.top,
+            self.insets.bottom,
+        );
+        let dest_columns = slice_axis(
+            area.origin.x,
+            area.size.width,
+            self.insets.left.into_signed(),
+            self.insets.right.into_signed(),
+        );
+        let dest_rows = slice_axis(
+            area.origin.y,
+            area.size.height,
+            self.insets.top.into_signed(),
+            self.insets.bottom.into_signed(),
+        );
+
+        for (source_row, dest_row) in source_rows.iter().zip(&dest_rows) {
+            for (source_column, dest_column) in source_columns.iter().zip(&dest_columns) {
+                let source = Rect::new(
+                    Point::new(source_column.0, source_row.0),
+                    Size::new(source_column.1, source_row.1),
+                );
+                let dest = Rect::new(
+                    Point::new(dest_column.0, dest_row.0),
+                    Size::new(dest_column.1, dest_row.1),
+                );
+                if source.size.width > 0
+                    && source.size.height > 0
+                    && dest.size.width > 0
+                    && dest.size.height > 0
+                {
+                    context.gfx.draw_textured_shape(
+                        &Shape::textured_rect(dest, source),
+                        &*self.texture,
+                        opacity,
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Debug for NineSlice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NineSlice")
+            .field("texture_size", &self.texture.size())
+            .field("insets", &self.insets)
+            .finish()
+    }
+}
+
+impl PartialEq for NineSlice {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.texture, &other.texture) && self.insets == other.insets
+    }
+}
+
+/// Splits the span starting at `origin` with `length` into the start, middle,
+/// and end segments, returning each segment's origin and length.
+fn slice_axis<Unit>(origin: Unit, length: Unit, start: Unit, end: Unit) -> [(Unit, Unit); 3]
+where
+    Unit: Copy
+        + Ord
+        + Zero
+        + std::ops::Add<Output = Unit>
+        + std::ops::Sub<Output = Unit>
+        + std::ops::Div<i32, Output = Unit>,
+{
+    let (start, end) = if start + end > length {
+        // The edges don't fit, so each edge is given half of the space.
+        let half = length / 2;
+        (half.min(start), (length - half).min(end))
+    } else {
+        (start, end)
+    };
+    let middle = length - start - end;
+    [
+        (origin, start),
+        (origin + start, middle),
+        (origin + start + middle, end),
+    ]
+}
+
+/// A [`Gradient`] whose colors have been resolved for drawing.
+pub(super) struct ResolvedGradient {
+    kind: GradientKind,
+    stops: Vec<(f32, Color)>,
+}
+
+impl ResolvedGradient {
+    /// Returns the color of the gradient at `position`.
+    pub fn color_at(&self, position: f32) -> Color {
+        let Some(first) = self.stops.first() else {
+            return Color::CLEAR_BLACK;
+        };
+        if position <= first.0 {
+            return first.1;
+        }
+        for pair in self.stops.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            if position <= end.0 {
+                let span = end.0 - start.0;
+                return if span > 0. {
+                    start.1.lerp(&end.1, (position - start.0) / span)
+                } else {
+                    end.1
+                };
+            }
+        }
+        self.stops.last().expect("at least one stop").1
+    }
+
+    /// Returns the largest alpha value of any color in this gradient.
+    pub fn max_alpha(&self) -> u8 {
+        self.stops
+            .iter()
+            .map(|(_, color)| color.alpha())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn apply_opacity(&mut self, opacity: ZeroToOne) {
+        for (_, color) in &mut self.stops {
+            *color = color.with_alpha_f32(color.alpha_f32() * *opacity);
+        }
+    }
+
+    /// Fills `area` with rounded corners of `radii` with this gradient.
+    pub fn draw(
+        &self,
+        area: Rect<Px>,
+        radii: CornerRadii<Px>,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+    ) {
+        if self.stops.is_empty() || area.size.width <= 0 || area.size.height <= 0 {
+            return;
+        }
+
+        let outline = rounded_rect_outline(area, radii);
+        let origin = point_to_f32(area.origin);
+        let size = Point::new(area.size.width.into_float(), area.size.height.into_float());
+        let breakpoints = self.breakpoints();
+        let cells = match self.kind {
+            GradientKind::Linear { angle } => linear_cells(origin, size, angle, &breakpoints),
+            GradientKind::Radial { center } => radial_cells(
+                relative_point(origin, size, center),
+                origin,
+                size,
+                &breakpoints,
+            ),
+            GradientKind::Conic { center, start } => conic_cells(
+                relative_point(origin, size, center),
+                origin,
+                size,
+                start,
+                &breakpoints,
+            ),
+        };
+
+        for cell in cells {
+            let clipped = clip_convex(&cell, &outline);
+            if clipped.len() < 3 {
+                continue;
+            }
+            let mut vertices = clipped.iter().map(|vertex| {
+                (
+                    Point::new(Px::from_float(vertex.0.x), Px::from_float(vertex.0.y)),
+                    self.color_at(vertex.1),
+                )
+            });
+            let mut path = PathBuilder::new(vertices.next().expect("at least 3 vertices"));
+            for vertex in vertices {
+                path = path.line_to(vertex);
+            }
+            context.gfx.draw_shape(&path.close().filled());
+        }
+    }
+
+    /// Returns the sorted, de-duplicated positions where the gradient's color
+    /// changes direction, including 0.0 and 1.0.
+    fn breakpoints(&self) -> Vec<f32> {
+        let mut breakpoints = Vec::with_capacity(self.stops.len() + 2);
+        breakpoints.push(0.);
+        breakpoints.extend(self.stops.iter().map(|(position, _)| *position));
+        breakpoints.push(1.);
+        breakpoints.sort_by(f32::total_cmp);
+        breakpoints.dedup();
+        breakpoints
+    }
+}
+
+/// A point paired with its position within a gradient.
+type GradientVertex = (Point<f32>, f32);
+
+fn point_to_f32(point: Point<Px>) -> Point<f32> {
+    Point::new(point.x.into_float(), point.y.into_float())
+}
+
+fn relative_point(origin: Point<f32>, size: Point<f32>, relative: Point<ZeroToOne>) -> Point<f32> {
+    Point::new(
+        origin.x + size.x * *relative.x,
+        origin.y + size.y * *relative.y,
+    )
+}
+
+fn corners(origin: Point<f32>, size: Point<f32>) -> [Point<f32>; 4] {
+    [
+        origin,
+        Point::new(origin.x + size.x, origin.y),
+        Point::new(origin.x + size.x, origin.y + size.y),
+        Point::new(origin.x, origin.y + size.y),
+    ]
+}
+
+fn distance(a: Point<f32>, b: Point<f32>) -> f32 {
+    (a.x - b.x).hypot(a.y - b.y)
+}
+
+/// Returns a clockwise outline of a rectangle with rounded corners.
+fn rounded_rect_outline(area: Rect<Px>, radii: CornerRadii<Px>) -> Vec<Point<f32>> {
+    let max_radius = area.size.width.min(area.size.height).into_float() / 2.;
+    let origin = point_to_f32(area.origin);
+    let size = Point::new(area.size.width.into_float(), area.size.height.into_float());
+    let [top_left, top_right, bottom_right, bottom_left] = corners(origin, size);
+    let mut outline = Vec::new();
+    // Each corner is listed with the direction pointing from the arc's center
+    // to the corner, and the angle its arc begins at.
+    for (corner, radius, direction, start_angle) in [
+        (top_left, radii.top_left, Point::new(-1., -1.), PI),
+        (top_right, radii.top_right, Point::new(1., -1.), PI * 1.5),
+        (bottom_right, radii.bottom_right, Point::new(1., 1.), 0.),
+        (
+            bottom_left,
+            radii.bottom_left,
+            Point::new(-1., 1.),
+            PI * 0.5,
+        ),
+    ] {
+        let radius = radius.into_float().min(max_radius);
+        if radius <= 0. {
+            outline.push(corner);
+            continue;
+        }
+        let center = Point::new(
+            corner.x - direction.x * radius,
+            corner.y - direction.y * radius,
+        );
+        for segment in 0..=CORNER_SEGMENTS {
+            let angle = start_angle + PI / 2. * f32::from(segment) / f32::from(CORNER_SEGMENTS);
+            outline.push(Point::new(
+                center.x + radius * angle.cos(),
+                center.y + radius * angle.sin(),
+            ));
+        }
+    }
+    outline
+}
+
+fn linear_cells(
+    origin: Point<f32>,
+    size: Point<f32>,
+    angle: Angle,
+    breakpoints: &[f32],
+) -> Vec<Vec<GradientVertex>> {
+    let radians = angle.into_degrees::<f32>().to_radians();
+    let direction = Point::new(radians.cos(), radians.sin());
+    let normal = Point::new(-direction.y, direction.x);
+    let project = |point: Point<f32>, axis: Point<f32>| point.x * axis.x + point.y * axis.y;
+
+    let corners = corners(origin, size);
+    let (mut start, mut end) = (f32::MAX, f32::MIN);
+    let (mut side_start, mut side_end) = (f32::MAX, f32::MIN);
+    for corner in corners {
+        let along = project(corner, direction);
+        start = start.min(along);
+        end = end.max(along);
+        let across = project(corner, normal);
+        side_start = side_start.min(across);
+        side_end = side_end.max(across);
+    }
+    let length = end - start;
+    if length <= 0. {
+        return Vec::new();
+    }
+    // Extend the cells past the filled area to avoid gaps from rounding.
+    let (side_start, side_end) = (side_start - 1., side_end + 1.);
+    let at = |position: f32, across: f32| {
+        let along = start + length * position;
+        Point::new(
+            direction.x * along + normal.x * across,
+            direction.y * along + normal.y * across,
+        )
+    };
+
+    breakpoints
+        .windows(2)
+        .map(|pair| {
+            let (from, to) = (pair[0], pair[1]);
+            // The first and last cells are stretched beyond the area to cover
+            // any pixels lost to rounding.
+            let (from_at, to_at) = (
+                if from <= 0. { -0.01 } else { from },
+                if to >= 1. { 1.01 } else { to },
+            );
+            vec![
+                (at(from_at, side_start), from_at),
+                (at(to_at, side_start), to_at),
+                (at(to_at, side_end), to_at),
+                (at(from_at, side_end), from_at),
+            ]
+        })
+        .collect()
+}
+
+fn radial_cells(
+    center: Point<f32>,
+    origin: Point<f32>,
+    size: Point<f32>,
+    breakpoints: &[f32],
+) -> Vec<Vec<GradientVertex>> {
+    let radius = corners(origin, size)
+        .into_iter()
+        .map(|corner| distance(center, corner))
+        .fold(0., f32::max);
+    if radius <= 0. {
+        return Vec::new();
+    }
+    let segment_angle = TAU / f32::from(ROUND_SEGMENTS);
+    // The outer ring is enlarged so that its chords fully contain the circle.
+    let outer_scale = 1. / (segment_angle / 2.).cos();
+    let at = |position: f32, segment: u16| {
+        let angle = segment_angle * f32::from(segment);
+        let distance = radius * position;
+        Point::new(
+            center.x + distance * angle.cos(),
+            center.y + distance * angle.sin(),
+        )
+    };
+
+    let mut cells = Vec::new();
+    for pair in breakpoints.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        let to_at = if to >= 1. { outer_scale } else { to };
+        for segment in 0..ROUND_SEGMENTS {
+            let mut cell = Vec::with_capacity(4);
+            if from > 0. {
+                cell.push((at(from, segment), from));
+            } else {
+                cell.push((center, 0.));
+            }
+            cell.push((at(to_at, segment), to));
+            cell.push((at(to_at, segment + 1), to));
+            if from > 0. {
+                cell.push((at(from, segment + 1), from));
+            }
+            cells.push(cell);
+        }
+    }
+    cells
+}
+
+fn conic_cells(
+    center: Point<f32>,
+    origin: Point<f32>,
+    size: Point<f32>,
+    start: Angle,
+    breakpoints: &[f32],
+) -> Vec<Vec<GradientVertex>> {
+    let radius = corners(origin, size)
+        .into_iter()
+        .map(|corner| distance(center, corner))
+        .fold(0., f32::max)
+        + 1.;
+    let start = start.into_degrees::<f32>().to_radians();
+    let segment_size = 1. / f32::from(ROUND_SEGMENTS);
+
+    // Each stop needs to begin a new segment to ensure hard transitions are
+    // preserved.
+    let mut positions = breakpoints.to_vec();
+    positions.extend((1..ROUND_SEGMENTS).map(|segment| f32::from(segment) * segment_size));
+    positions.sort_by(f32::total_cmp);
+    positions.dedup();
+
+    let outer_scale = 1. / (PI * segment_size).cos();
+    let at = |position: f32| {
+        let angle = start + TAU * position;
+        Point::new(
+            center.x + radius * outer_scale * angle.cos(),
+            center.y + radius * outer_scale * angle.sin(),
+        )
+    };
+
+    positions
+        .windows(2)
+        .filter(|pair| pair[1] > pair[0])
+        .map(|pair| {
+            let (from, to) = (pair[0], pair[1]);
+            vec![(center, (from + to) / 2.), (at(from), from), (at(to), to)]
+        })
+        .collect()
+}
+
+/// Clips `subject` to the inside of the convex, clockwise polygon `clip`,
+/// interpolating each vertex's gradient position.
+fn clip_convex(subject: &[GradientVertex], clip: &[Point<f32>]) -> Vec<GradientVertex> {
+    let mut output = subject.to_vec();
+    for (index, edge_start) in clip.iter().enumerate() {
+        if output.is_empty() {
+            break;
+        }
+        let edge_end = clip[(index + 1) % clip.len()];
+        if *edge_start == edge_end {
+            continue;
+        }
+        // In screen coordinates, the inside of a clockwise polygon is to the
+        // right of each edge, which has a positive cross product.
+        let inside = |point: Point<f32>| {
+            (edge_end.x - edge_start.x) * (point.y - edge_start.y)
+                - (edge_end.y - edge_start.y) * (point.x - edge_start.x)
+        };
+        let input = std::mem::take(&mut output);
+        for (vertex_index, current) in input.iter().enumerate() {
+            let previous = input[(vertex_index + input.len() - 1) % input.len()];
+            let current_side = inside(current.0);
+            let previous_side = inside(previous.0);
+            if (current_side >= 0.) != (previous_side >= 0.) {
+                let percent = previous_side / (previous_side - current_side);
+                output.push((
+                    Point::new(
+                        previous.0.x + (current.0.x - previous.0.x) * percent,
+                        previous.0.y + (current.0.y - previous.0.y) * percent,
+                    ),
+                    previous.1 + (current.1 - previous.1) * percent,
+                ));
+            }
+            if current_side >= 0. {
+                output.push(*current);
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+    use figures::Angle;
+    use kludgine::Color;
+
+    use super::{clip_convex, slice_axis, Gradient, GradientColor, GradientKind};
+    use crate::animation::LinearInterpolate;
+
+    #[test]
+    fn stops_are_ordered() {
+        let gradient = Gradient::linear(Angle::degrees(0))
+            .with_stop(1., Color::BLUE)
+            .with_stop(0., Color::RED)
+            .with_stop(0.5, Color::GREEN);
+        let colors = gradient
+            .stops
+            .iter()
+            .map(|stop| stop.color.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            colors,
+            [
+                GradientColor::Color(Color::RED),
+                GradientColor::Color(Color::GREEN),
+                GradientColor::Color(Color::BLUE)
+            ]
+        );
+    }
+
+    #[test]
+    fn lerp() {
+        let start = Gradient::linear(Angle::degrees(0))
+            .with_stop(0., Color::BLACK)
+            .with_stop(1., Color::BLACK);
+        let end = Gradient::linear(Angle::degrees(90))
+            .with_stop(0., Color::WHITE)
+            .with_stop(1., Color::WHITE);
+        let middle = start.lerp(&end, 0.5);
+        assert_eq!(
+            middle.kind,
+            GradientKind::Linear {
+                angle: Angle::degrees(45)
+            }
+        );
+        assert_eq!(
+            middle.stops[0].color,
+            GradientColor::Color(Color::BLACK.lerp(&Color::WHITE, 0.5))
+        );
+
+        // Different kinds switch halfway through.
+        let radial = Gradient::radial().with_stop(0., Color::RED);
+        assert_eq!(start.lerp(&radial, 0.25), start);
+        assert_eq!(start.lerp(&radial, 0.75), radial);
+    }
+
+    #[test]
+    fn clipping() {
+        let square = [
+            figures::Point::new(0., 0.),
+            figures::Point::new(10., 0.),
+            figures::Point::new(10., 10.),
+            figures::Point::new(0., 10.),
+        ];
+        let band = [
+            (figures::Point::new(-5., 2.), 0.),
+            (figures::Point::new(15., 2.), 0.),
+            (figures::Point::new(15., 4.), 1.),
+            (figures::Point::new(-5., 4.), 1.),
+        ];
+        let clipped = clip_convex(&band, &square);
+        assert_eq!(clipped.len(), 4);
+        assert!(clipped
+            .iter()
+            .all(|(point, _)| (0. ..=10.).contains(&point.x)));
+    }
+
+    #[test]
+    fn nine_slice_axis() {
+        assert_eq!(
+            slice_axis(UPx::new(0), UPx::new(100), UPx::new(10), UPx::new(20)),
+            [
+                (UPx::new(0), UPx::new(10)),
+                (UPx::new(10), UPx::new(70)),
+                (UPx::new(80), UPx::new(20))
+            ]
+        );
+        // When the edges don't fit, they share the available space.
+        assert_eq!(
+            slice_axis(UPx::new(0), UPx::new(10), UPx::new(8), UPx::new(8)),
+            [
+                (UPx::new(0), UPx::new(5)),
+                (UPx::new(5), UPx::new(0)),
+                (UPx::new(5), UPx::new(5))
+            ]
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use figures::units::Px;
+use figures::{FloatConversion, Point, Rect};
+use kludgine::shapes::{CornerRadii, PathBuilder, Shape};
+use kludgine::{Color, DrawableExt, Origin};
+
+use super::background::CORNER_SEGMENTS;
+use crate::animation::ZeroToOne;
+use crate::context::GraphicsContext;
+use crate::styles::{Edges, LineStyle};
+
+/// The length of each dash, relative to the width of the border.
+const DASH_LENGTH: f32 = 3.;
+/// The length of the gap between each dash, relative to the width of the
+/// border.
+const DASH_GAP: f32 = 2.;
+/// The distance between the centers of each dot, relative to the width of the
+/// border.
+const DOT_SPACING: f32 = 2.;
+
+/// The resolved appearance of a [`Container`](super::Container)'s border.
+pub(super) struct Border {
+    pub widths: Edges<Px>,
+    pub colors: Edges<Color>,
+    pub styles: Edges<LineStyle>,
+}
+
+impl Border {
+    /// Returns true if this border has no visible edges.
+    pub fn is_invisible(&self) -> bool {
+        [
+            (self.widths.left, self.colors.left),
+            (self.widths.top, self.colors.top),
+            (self.widths.right, self.colors.right),
+            (self.widths.bottom, self.colors.bottom),
+        ]
+        .into_iter()
+        .all(|(width, color)| width <= 0 || color.alpha() == 0)
+    }
+
+    /// Draws this border inside of `area` whose corners are rounded by
+    /// `radii`.
+    pub fn draw(
+        &self,
+        area: Rect<Px>,
+        radii: CornerRadii<Px>,
+        opacity: ZeroToOne,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+    ) {
+        if self.is_invisible() || area.size.width <= 0 || area.size.height <= 0 {
+            return;
+        }
+
+        let widths = self.widths.map(|width| width.into_float().max(0.));
+        let max_radius = area.size.width.min(area.size.height).into_float() / 2.;
+        let radii = radii.map(|radius| radius.into_float().clamp(0., max_radius));
+        let outer_left = area.origin.x.into_float();
+        let outer_top = area.origin.y.into_float();
+        let outer_right = outer_left + area.size.width.into_float();
+        let outer_bottom = outer_top + area.size.height.into_float();
+
+        // Each corner is described by the position of the corner, the
+        // direction the corner is from the arc's center, its radius, and the
+        // widths of the edges it joins.
+        let top_left = Corner::new(
+            Point::new(outer_left, outer_top),
+            Point::new(-1., -1.),
+            radii.top_left,
+            widths.left,
+            widths.top,
+            180.,
+        );
+        let top_right = Corner::new(
+            Point::new(outer_right, outer_top),
+            Point::new(1., -1.),
+            radii.top_right,
+            widths.right,
+            widths.top,
+            270.,
+        );
+        let bottom_right = Corner::new(
+            Point::new(outer_right, outer_bottom),
+            Point::new(1., 1.),
+            radii.bottom_right,
+            widths.right,
+            widths.bottom,
+            0.,
+        );
+        let bottom_left = Corner::new(
+            Point::new(outer_left, outer_bottom),
+            Point::new(-1., 1.),
+            radii.bottom_left,
+            widths.left,
+            widths.bottom,
+            90.,
+        );
+
+        // Each edge begins where the previous corner was split, and ends where
+        // the next corner is split.
+        for (start, end, width, color, style) in [
+            (
+                &top_left,
+                &top_right,
+                widths.top,
+                self.colors.top,
+                self.styles.top,
+            ),
+            (
+                &top_right,
+                &bottom_right,
+                widths.right,
+                self.colors.right,
+                self.styles.right,
+            ),
+            (
+                &bottom_right,
+                &bottom_left,
+                widths.bottom,
+                self.colors.bottom,
+                self.styles.bottom,
+            ),
+            (
+                &bottom_left,
+                &top_left,
+                widths.left,
+                self.colors.left,
+                self.styles.left,
+            ),
+        ] {
+            let color = color.with_alpha_f32(color.alpha_f32() * *opacity);
+            if width <= 0. || color.alpha() == 0 {
+                continue;
+            }
+
+            let mut edge = start.second_half();
+            edge.extend(end.first_half());
+            let edge = EdgePath::new(edge);
+
+            match style {
+                LineStyle::Solid => edge.fill_between(0., edge.length(), color, context),
+                LineStyle::Dashed => {
+                    let period = (DASH_LENGTH + DASH_GAP) * width;
+                    let dashes = ((edge.length() + DASH_GAP * width) / period)
+                        .round()
+                        .max(1.);
+                    let period = (edge.length() + DASH_GAP * width) / dashes;
+                    let dash = period * DASH_LENGTH / (DASH_LENGTH + DASH_GAP);
+                    let mut start = 0.;
+                    while start < edge.length() {
+                        edge.fill_between(start, (start + dash).min(edge.length()), color, context);
+                        start += period;
+                    }
+                }
+                LineStyle::Dotted => {
+                    let spacing = DOT_SPACING * width;
+                    let dots = (edge.length() / spacing).round().max(1.);
+                    let spacing = edge.length() / dots;
+                    let radius = Px::from_float(width / 2.);
+                    let mut position = spacing / 2.;
+                    while position < edge.length() {
+                        let (outer, inner) = edge.at(position);
+                        let center = Point::new(
+                            Px::from_float((outer.x + inner.x) / 2.),
+                            Px::from_float((outer.y + inner.y) / 2.),
+                        );
+                        context.gfx.draw_shape(
+                            Shape::filled_circle(radius, color, Origin::Center)
+                                .translate_by(center),
+                        );
+                        position += spacing;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A rounded corner of a border.
+struct Corner {
+    outer_center: Point<f32>,
+    outer_radius: f32,
+    inner_center: Point<f32>,
+    inner_radii: Point<f32>,
+    start_angle: f32,
+    split_angle: f32,
+}
+
+impl Corner {
+    /// Returns a new corner at `corner`.
+    ///
+    /// `direction` points from the center of the arc towards `corner`.
+    /// `horizontal_width` is the width of the left or right edge the corner
+    /// joins, and `vertical_width` is the width of the top or bottom edge.
+    /// `start_angle` is the angle, in degrees, where the corner's arc begins
+    /// when traveling clockwise.
+    fn new(
+        corner: Point<f32>,
+        direction: Point<f32>,
+        radius: f32,
+        horizontal_width: f32,
+        vertical_width: f32,
+        start_angle: f32,
+    ) -> Self {
+        let outer_center = Point::new(
+            corner.x - direction.x * radius,
+            corner.y - direction.y * radius,
+        );
+        let inner_radii = Point::new(
+            (radius - horizontal_width).max(0.),
+            (radius - vertical_width).max(0.),
+        );
+        let inner_corner = Point::new(
+            corner.x - direction.x * horizontal_width,
+            corner.y - direction.y * vertical_width,
+        );
+        let inner_center = Point::new(
+            inner_corner.x - direction.x * inner_radii.x,
+            inner_corner.y - direction.y * inner_radii.y,
+        );
+        // The edge that the arc begins on alternates between the horizontal
+        // and vertical edges.
+        let (starting_width, ending_width) = if start_angle % 180. == 0. {
+            (horizontal_width, vertical_width)
+        } else {
+            (vertical_width, horizontal_width)
+        };
+        // The corner is divided between the two edges it joins proportionally
+        // to their widths, matching the diagonal between the outer and inner
+        // corners.
+        let split_angle = start_angle + starting_width.atan2(ending_width).to_degrees();
+        Self {
+            outer_center,
+            outer_radius: radius,
+            inner_center,
+            inner_radii,
+            start_angle,
+            split_angle,
+        }
+    }
+
+    /// Returns the pairs of outer and inner points from the start of the arc
+    /// to where the corner is split.
+    fn first_half(&self) -> Vec<(Point<f32>, Point<f32>)> {
+        self.arc(self.start_angle, self.split_angle)
+    }
+
+    /// Returns the pairs of outer and inner points from where the corner is
+    /// split to the end of the arc.
+    fn second_half(&self) -> Vec<(Point<f32>, Point<f32>)> {
+        self.arc(self.split_angle, self.start_angle + 90.)
+    }
+
+    fn arc(&self, start: f32, end: f32) -> Vec<(Point<f32>, Point<f32>)> {
+        let segments = ((end - start) / 90. * f32::from(CORNER_SEGMENTS))
+            .ceil()
+            .max(1.);
+        // The loop bound is always a small, positive whole number.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let segments = segments as u16;
+        (0..=segments)
+            .map(|segment| {
+                let angle =
+                    (start + (end - start) * f32::from(segment) / f32::from(segments)).to_radians();
+                let (sin, cos) = angle.sin_cos();
+                (
+                    Point::new(
+                        self.outer_center.x + self.outer_radius * cos,
+                        self.outer_center.y + self.outer_radius * sin,
+                    ),
+                    Point::new(
+                        self.inner_center.x + self.inner_radii.x * cos,
+                        self.inner_center.y + self.inner_radii.y * sin,
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// A single edge of a border, described by pairs of outer and inner points.
+struct EdgePath {
+    points: Vec<(Point<f32>, Point<f32>)>,
+    /// The distance along the center of the edge for each pair of points.
+    distances: Vec<f32>,
+}
+
+impl EdgePath {
+    fn new(points: Vec<(Point<f32>, Point<f32>)>) -> Self {
+        let mut distances = Vec::with_capacity(points.len());
+        let mut distance = 0.;
+        let mut previous: Option<Point<f32>> = None;
+        for (outer, inner) in &points {
+            let center = Point::new((outer.x + inner.x) / 2., (outer.y + inner.y) / 2.);
+            if let Some(previous) = previous.replace(center) {
+                distance += (center.x - previous.x).hypot(center.y - previous.y);
+            }
+            distances.push(distance);
+        }
+        Self { points, distances }
+    }
+
+    fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or_default()
+    }
+
+    /// Returns the outer and inner points at `distance` along this edge.
+    fn at(&self, distance: f32) -> (Point<f32>, Point<f32>) {
+        let index = self
+            .distances
+            .partition_point(|existing| *existing < distance)
+            .clamp(1, self.points.len() - 1);
+        let (start_distance, end_distance) = (self.distances[index - 1], self.distances[index]);
+        let (start, end) = (self.points[index - 1], self.points[index]);
+        let span = end_distance - start_distance;
+        let percent = if span > 0. {
+            (distance - start_distance) / span
+        } else {
+            0.
+        };
+        let lerp = |a: Point<f32>, b: Point<f32>| {
+            Point::new(a.x + (b.x - a.x) * percent, a.y + (b.y - a.y) * percent)
+        };
+        (lerp(start.0, end.0), lerp(start.1, end.1))
+    }
+
+    /// Fills the portion of this edge between `start` and `end`.
+    fn fill_between(
+        &self,
+        start: f32,
+        end: f32,
+        color: Color,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+    ) {
+        if self.points.len() < 2 || end <= start {
+            return;
+        }
+        let mut section = vec![self.at(start)];
+        section.extend(
+            self.points
+                .iter()
+                .zip(&self.distances)
+                .filter(|(_, distance)| **distance > start && **distance < end)
+                .map(|(points, _)| *points),
+        );
+        section.push(self.at(end));
+
+        let to_px =
+            |point: Point<f32>| Point::new(Px::from_float(point.x), Px::from_float(point.y));
+        let mut path = PathBuilder::new(to_px(section[0].0));
+        for (outer, _) in &section[1..] {
+            path = path.line_to(to_px(*outer));
+        }
+        for (_, inner) in section.iter().rev() {
+            path = path.line_to(to_px(*inner));
+        }
+        context.gfx.draw_shape(&path.close().fill(color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::Point;
+
+    use super::{Corner, EdgePath};
+
+    #[test]
+    fn corner_split() {
+        // Equal widths split the corner in half.
+        let corner = Corner::new(Point::new(0., 0.), Point::new(-1., -1.), 10., 2., 2., 180.);
+        assert!((corner.split_angle - 225.).abs() < 0.001);
+        // When the horizontal edge has no width, the vertical edge covers the
+        // entire corner.
+        let corner = Corner::new(Point::new(0., 0.), Point::new(-1., -1.), 10., 2., 0., 180.);
+        assert!((corner.split_angle - 270.).abs() < 0.001);
+        // Thick borders produce square inner corners.
+        let corner = Corner::new(Point::new(0., 0.), Point::new(-1., -1.), 4., 8., 6., 180.);
+        assert_eq!(corner.inner_radii, Point::new(0., 0.));
+        assert_eq!(corner.inner_center, Point::new(8., 6.));
+    }
+
+    #[test]
+    fn edge_distances() {
+        let edge = EdgePath::new(vec![
+            (Point::new(0., 0.), Point::new(0., 2.)),
+            (Point::new(10., 0.), Point::new(10., 2.)),
+            (Point::new(20., 0.), Point::new(20., 2.)),
+        ]);
+        assert!((edge.length() - 20.).abs() < 0.001);
+        let (outer, inner) = edge.at(15.);
+        assert_eq!(outer, Point::new(15., 0.));
+        assert_eq!(inner, Point::new(15., 2.));
+    }
+}
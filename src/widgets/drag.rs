@@ -0,0 +1,784 @@
+//! Widgets for dragging payloads between widgets within a window.
+
+use std::any::{Any, TypeId};
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use figures::units::{Lp, Px};
+use figures::{Point, Round, ScreenScale};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::shapes::StrokeOptions;
+use kludgine::Color;
+use parking_lot::Mutex;
+
+use crate::context::{EventContext, GraphicsContext};
+use crate::styles::components::{HighlightColor, OutlineWidth};
+use crate::styles::Dimension;
+use crate::value::{Destination, Dynamic, IntoDynamic, Source};
+use crate::widget::{
+    EventHandling, MakeWidget, MountedWidget, WidgetId, WidgetInstance, WidgetRef, WrapperWidget,
+    HANDLED, IGNORED,
+};
+use crate::widgets::layers::{OverlayHandle, OverlayLayer, Overlayable};
+use crate::window::DeviceId;
+
+/// A widget that allows its child to be dragged onto a [`DropTarget`].
+///
+/// When the left mouse button is pressed on this widget and the cursor is
+/// moved further than [`DragThreshold`], a drag begins. The payload for the
+/// drag is created by invoking the function provided to [`DragSource::new`].
+/// While the button is held, the [`DropTarget`]s beneath the cursor are
+/// notified as the drag enters, moves over, and leaves them. Releasing the
+/// button over a target that accepts the payload drops it onto the target.
+///
+/// A preview of the dragged content can be shown on an [`OverlayLayer`] using
+/// [`DragSource::preview`].
+pub struct DragSource {
+    child: WidgetRef,
+    payload: Box<dyn FnMut() -> Payload + Send>,
+    preview: Option<DragPreview>,
+    dragging: Dynamic<bool>,
+    pressed: Option<Point<Px>>,
+    drag: Option<ActiveDrag>,
+}
+
+impl DragSource {
+    /// Returns a widget that allows `child` to be dragged, creating the
+    /// dragged payload using `payload` each time a drag begins.
+    ///
+    /// Only [`DropTarget`]s that accept payloads of type `T` will be able to
+    /// receive the drag.
+    pub fn new<T, F>(child: impl MakeWidget, mut payload: F) -> Self
+    where
+        T: Send + Sync + 'static,
+        F: FnMut() -> T + Send + 'static,
+    {
+        Self {
+            child: WidgetRef::new(child),
+            payload: Box::new(move || Payload::new(payload())),
+            preview: None,
+            dragging: Dynamic::default(),
+            pressed: None,
+            drag: None,
+        }
+    }
+
+    /// Shows the widget returned from `make_preview` on `overlay` beneath the
+    /// cursor while this widget is being dragged.
+    ///
+    /// `make_preview` is invoked each time a drag begins.
+    #[must_use]
+    pub fn preview<F, W>(mut self, overlay: &OverlayLayer, mut make_preview: F) -> Self
+    where
+        F: FnMut() -> W + Send + 'static,
+        W: MakeWidget,
+    {
+        self.preview = Some(DragPreview {
+            overlay: overlay.clone(),
+            make_widget: Box::new(move || make_preview().make_widget()),
+        });
+        self
+    }
+
+    /// Sets `dragging` to be updated with whether this widget is currently
+    /// being dragged.
+    #[must_use]
+    pub fn dragging(mut self, dragging: impl IntoDynamic<bool>) -> Self {
+        self.dragging = dragging.into_dynamic();
+        self
+    }
+
+    fn begin_drag(&mut self, grab_offset: Point<Px>, context: &mut EventContext<'_>) {
+        let payload = (self.payload)();
+        let origin = context
+            .last_layout()
+            .map_or(Point::default(), |layout| layout.origin);
+        let preview = self.preview.as_mut().map(|preview| {
+            preview
+                .overlay
+                .build_overlay((preview.make_widget)())
+                .at(origin)
+                .show()
+        });
+        self.drag = Some(ActiveDrag {
+            payload,
+            grab_offset,
+            target: None,
+            preview,
+        });
+        self.dragging.set(true);
+    }
+
+    fn end_drag(&mut self, location: Option<Point<Px>>, context: &mut EventContext<'_>) {
+        self.pressed = None;
+        let Some(mut drag) = self.drag.take() else {
+            return;
+        };
+
+        if let Some(location) = location {
+            drag.update_target(location, context);
+        }
+
+        if let Some(target) = drag.target.take() {
+            if target.accepted {
+                let location = location.and_then(|location| target.relative(location, context));
+                if let Some(location) = location {
+                    target
+                        .shared
+                        .dropped(&drag.payload, location, context.widget().id());
+                } else {
+                    target.shared.left();
+                }
+            }
+        }
+
+        self.dragging.set(false);
+    }
+}
+
+impl Debug for DragSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragSource")
+            .field("child", &self.child)
+            .field("dragging", &self.dragging)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WrapperWidget for DragSource {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        self.end_drag(None, context);
+        self.child.unmount_in(context);
+    }
+
+    fn hit_test(&mut self, _location: Point<Px>, context: &mut EventContext<'_>) -> bool {
+        context.enabled()
+    }
+
+    fn hover(
+        &mut self,
+        _location: Point<Px>,
+        context: &mut EventContext<'_>,
+    ) -> Option<CursorIcon> {
+        context.enabled().then_some(CursorIcon::Grab)
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left || !context.enabled() {
+            return IGNORED;
+        }
+
+        self.pressed = Some(location);
+        HANDLED
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        let Some(pressed) = self.pressed else {
+            return;
+        };
+
+        if self.drag.is_none() {
+            let threshold = context
+                .get(&DragThreshold)
+                .into_px(context.kludgine.scale())
+                .round();
+            let delta = location - pressed;
+            if delta.x.get().abs().max(delta.y.get().abs()) < threshold.get() {
+                return;
+            }
+            self.begin_drag(pressed, context);
+        }
+
+        if let Some(drag) = &mut self.drag {
+            drag.update_target(location, context);
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        self.end_drag(location, context);
+    }
+}
+
+struct DragPreview {
+    overlay: OverlayLayer,
+    make_widget: Box<dyn FnMut() -> WidgetInstance + Send>,
+}
+
+struct ActiveDrag {
+    payload: Payload,
+    grab_offset: Point<Px>,
+    target: Option<CurrentTarget>,
+    preview: Option<OverlayHandle>,
+}
+
+impl ActiveDrag {
+    /// Updates the preview and the target beneath `location`, which is
+    /// relative to the drag source.
+    fn update_target(&mut self, location: Point<Px>, context: &mut EventContext<'_>) {
+        let Some(source_layout) = context.last_layout() else {
+            return;
+        };
+        let window_location = source_layout.origin + location;
+        if let Some(preview) = &self.preview {
+            preview.move_to(window_location - self.grab_offset);
+        }
+
+        let source = context.widget().id();
+        let hovered = drop_target_at(window_location, &self.payload, context);
+        match (&mut self.target, hovered) {
+            (Some(current), Some((widget, _))) if current.widget.id() == widget.id() => {}
+            (current, hovered) => {
+                if let Some(previous) = current.take() {
+                    if previous.accepted {
+                        previous.shared.left();
+                    }
+                }
+
+                *current = hovered.map(|(widget, shared)| {
+                    let relative = window_location
+                        - widget
+                            .last_layout()
+                            .map_or(Point::default(), |layout| layout.origin);
+                    let accepted = shared.entered(&self.payload, relative, source);
+                    CurrentTarget {
+                        widget,
+                        shared,
+                        accepted,
+                    }
+                });
+            }
+        }
+
+        if let Some(target) = &self.target {
+            if target.accepted {
+                let relative = window_location - target.origin();
+                target.shared.over(&self.payload, relative, source);
+            }
+        }
+    }
+}
+
+/// Returns the top-most [`DropTarget`] at `location` that accepts `payload`.
+fn drop_target_at(
+    location: Point<Px>,
+    payload: &Payload,
+    context: &mut EventContext<'_>,
+) -> Option<(MountedWidget, Arc<DropShared>)> {
+    let source = context.widget().id();
+    context
+        .tree
+        .widgets_under_point(location)
+        .into_iter()
+        // The source is locked while it is handling this event, and it can
+        // never be a drop target.
+        .filter(|widget| widget.id() != source)
+        .find_map(|widget| {
+            let shared = widget
+                .lock()
+                .downcast_ref::<DropTarget>()
+                .map(|target| target.shared.clone())?;
+            shared.accepts(payload).then_some((widget, shared))
+        })
+}
+
+struct CurrentTarget {
+    widget: MountedWidget,
+    shared: Arc<DropShared>,
+    accepted: bool,
+}
+
+impl CurrentTarget {
+    fn origin(&self) -> Point<Px> {
+        self.widget
+            .last_layout()
+            .map_or(Point::default(), |layout| layout.origin)
+    }
+
+    /// Converts `location`, relative to the drag source, to be relative to
+    /// this target.
+    fn relative(&self, location: Point<Px>, context: &EventContext<'_>) -> Option<Point<Px>> {
+        let source_layout = context.last_layout()?;
+        Some(source_layout.origin + location - self.origin())
+    }
+}
+
+/// A type-erased payload of a drag operation.
+struct Payload {
+    value: Box<dyn Any + Send + Sync>,
+    type_id: TypeId,
+}
+
+impl Payload {
+    fn new<T>(value: T) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        Self {
+            value: Box::new(value),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: 'static,
+    {
+        self.value.downcast_ref()
+    }
+}
+
+/// Information about a drag operation over a [`DropTarget`].
+#[derive(Debug)]
+pub struct DragEvent<'a, T> {
+    /// The payload being dragged.
+    pub payload: &'a T,
+    /// The location of the cursor relative to the [`DropTarget`].
+    pub location: Point<Px>,
+    /// The id of the [`DragSource`] the payload is being dragged from.
+    pub source: WidgetId,
+}
+
+/// A widget that can receive payloads dragged from a [`DragSource`].
+///
+/// A drop target only accepts payloads whose type has been registered using
+/// [`DropTarget::on_drop`]. When an accepted payload is dragged over this
+/// widget, the callbacks registered with [`DropTarget::on_enter`],
+/// [`DropTarget::on_over`], and [`DropTarget::on_leave`] are invoked as the
+/// cursor enters, moves across, and leaves this widget. If the payload is
+/// released over this widget, the matching `on_drop` callback is invoked
+/// instead of `on_leave`.
+///
+/// While an accepted payload is over this widget, an outline is drawn using
+/// [`DropHighlightColor`].
+///
+/// When drop targets are nested, the innermost target that accepts the payload
+/// receives the drag.
+pub struct DropTarget {
+    child: WidgetRef,
+    shared: Arc<DropShared>,
+}
+
+impl DropTarget {
+    /// Returns a widget that allows payloads to be dropped onto `child`.
+    #[must_use]
+    pub fn new(child: impl MakeWidget) -> Self {
+        Self {
+            child: WidgetRef::new(child),
+            shared: Arc::new(DropShared {
+                handlers: Mutex::default(),
+                hovering: Dynamic::default(),
+            }),
+        }
+    }
+
+    /// Invokes `on_enter` when a payload of type `T` is dragged into this
+    /// widget.
+    ///
+    /// If `on_enter` returns false, the payload is rejected and no further
+    /// callbacks will be invoked until the drag leaves and re-enters this
+    /// widget.
+    #[must_use]
+    pub fn on_enter<T, F>(self, on_enter: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(DragEvent<'_, T>) -> bool + Send + 'static,
+    {
+        self.shared
+            .handlers
+            .lock()
+            .enter
+            .push(PayloadHandler::new(on_enter));
+        self
+    }
+
+    /// Invokes `on_over` each time an accepted payload of type `T` is moved
+    /// within this widget.
+    #[must_use]
+    pub fn on_over<T, F>(self, on_over: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(DragEvent<'_, T>) + Send + 'static,
+    {
+        self.shared
+            .handlers
+            .lock()
+            .over
+            .push(PayloadHandler::new(on_over));
+        self
+    }
+
+    /// Invokes `on_leave` when an accepted payload leaves this widget without
+    /// being dropped.
+    #[must_use]
+    pub fn on_leave<F>(self, on_leave: F) -> Self
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.shared.handlers.lock().leave.push(Box::new(on_leave));
+        self
+    }
+
+    /// Accepts payloads of type `T`, invoking `on_drop` when one is dropped
+    /// onto this widget.
+    #[must_use]
+    pub fn on_drop<T, F>(self, on_drop: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(DragEvent<'_, T>) + Send + 'static,
+    {
+        let mut handlers = self.shared.handlers.lock();
+        handlers.accepts.push(TypeId::of::<T>());
+        handlers.drop.push(PayloadHandler::new(on_drop));
+        drop(handlers);
+        self
+    }
+}
+
+impl Debug for DropTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DropTarget")
+            .field("child", &self.child)
+            .field("hovering", &self.shared.hovering)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WrapperWidget for DropTarget {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn redraw_foreground(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        if self.shared.hovering.get_tracking_redraw(context) {
+            let color = context.get(&DropHighlightColor);
+            let width = context
+                .get(&OutlineWidth)
+                .into_px(context.gfx.scale())
+                .ceil();
+            context.stroke_outline(color, StrokeOptions::px_wide(width));
+        }
+    }
+}
+
+struct DropShared {
+    handlers: Mutex<DropHandlers>,
+    hovering: Dynamic<bool>,
+}
+
+impl DropShared {
+    fn accepts(&self, payload: &Payload) -> bool {
+        self.handlers.lock().accepts.contains(&payload.type_id)
+    }
+
+    fn entered(&self, payload: &Payload, location: Point<Px>, source: WidgetId) -> bool {
+        let mut handlers = self.handlers.lock();
+        let mut accepted = true;
+        for handler in &mut handlers.enter {
+            if handler.invoke(payload, location, source) == Some(false) {
+                accepted = false;
+            }
+        }
+        drop(handlers);
+        self.hovering.set(accepted);
+        accepted
+    }
+
+    fn over(&self, payload: &Payload, location: Point<Px>, source: WidgetId) {
+        for handler in &mut self.handlers.lock().over {
+            handler.invoke(payload, location, source);
+        }
+    }
+
+    fn left(&self) {
+        for handler in &mut self.handlers.lock().leave {
+            handler();
+        }
+        self.hovering.set(false);
+    }
+
+    fn dropped(&self, payload: &Payload, location: Point<Px>, source: WidgetId) {
+        for handler in &mut self.handlers.lock().drop {
+            handler.invoke(payload, location, source);
+        }
+        self.hovering.set(false);
+    }
+}
+
+#[derive(Default)]
+struct DropHandlers {
+    accepts: Vec<TypeId>,
+    enter: Vec<PayloadHandler<bool>>,
+    over: Vec<PayloadHandler<()>>,
+    leave: Vec<Box<dyn FnMut() + Send>>,
+    drop: Vec<PayloadHandler<()>>,
+}
+
+/// A callback that is only invoked for payloads of a specific type.
+struct PayloadHandler<R>(Box<dyn FnMut(&Payload, Point<Px>, WidgetId) -> Option<R> + Send>);
+
+impl<R> PayloadHandler<R> {
+    fn new<T, F>(mut handler: F) -> Self
+    where
+        T: 'static,
+        F: FnMut(DragEvent<'_, T>) -> R + Send + 'static,
+    {
+        Self(Box::new(move |payload, location, source| {
+            let payload = payload.downcast_ref::<T>()?;
+            Some(handler(DragEvent {
+                payload,
+                location,
+                source,
+            }))
+        }))
+    }
+
+    fn invoke(&mut self, payload: &Payload, location: Point<Px>, source: WidgetId) -> Option<R> {
+        (self.0)(payload, location, source)
+    }
+}
+
+define_components! {
+    Drag {
+        /// The distance the cursor must move while pressed before a
+        /// [`DragSource`] begins dragging.
+        DragThreshold(Dimension, "drag_threshold", Dimension::Lp(Lp::points(4)))
+        /// The [`Color`] of the outline drawn around a [`DropTarget`] while an
+        /// accepted payload is dragged over it.
+        DropHighlightColor(Color, "drop_highlight_color", @HighlightColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Point, Size, Zero};
+    use kludgine::app::winit::event::{ElementState, MouseButton};
+
+    use super::{DragEvent, DragSource, DropTarget};
+    use crate::value::{Dynamic, Source};
+    use crate::widget::{MakeWidget, WidgetId};
+    use crate::widgets::Space;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    /// The events a [`DropTarget`] observed, in order.
+    type EventLog = Dynamic<Vec<&'static str>>;
+
+    /// Returns a drop target that logs its events, and that accepts payloads
+    /// when they enter if `accept` is true.
+    fn logging_target(label: &str, log: &EventLog, accept: bool) -> DropTarget {
+        DropTarget::new(label.to_string().contain().expand())
+            .on_enter({
+                let log = log.clone();
+                move |_: DragEvent<'_, u32>| {
+                    log.lock().push("enter");
+                    accept
+                }
+            })
+            .on_over({
+                let log = log.clone();
+                move |_: DragEvent<'_, u32>| {
+                    // Each movement invokes this callback, so consecutive
+                    // movements are logged once.
+                    let mut log = log.lock();
+                    if log.last() != Some(&"over") {
+                        log.push("over");
+                    }
+                }
+            })
+            .on_leave({
+                let log = log.clone();
+                move || log.lock().push("leave")
+            })
+            .on_drop({
+                let log = log.clone();
+                move |_: DragEvent<'_, u32>| log.lock().push("drop")
+            })
+    }
+
+    struct TestDrag {
+        recorder: VirtualRecorder,
+        accepting: EventLog,
+        rejecting: EventLog,
+        dragging: Dynamic<bool>,
+        dropped: Dynamic<Option<(u32, WidgetId)>>,
+    }
+
+    impl TestDrag {
+        /// Returns a drag source followed by a target that accepts the
+        /// payload, an empty space, and a target that rejects the payload.
+        fn new() -> Self {
+            let accepting = EventLog::default();
+            let rejecting = EventLog::default();
+            let dragging = Dynamic::new(false);
+            let dropped = Dynamic::new(None);
+            let mut recorder = DragSource::new("Source".contain(), || 7_u32)
+                .dragging(dragging.clone())
+                .expand()
+                .and(
+                    logging_target("Accepting", &accepting, true)
+                        .on_drop({
+                            let dropped = dropped.clone();
+                            move |event: DragEvent<'_, u32>| {
+                                dropped.set(Some((*event.payload, event.source)));
+                            }
+                        })
+                        .expand(),
+                )
+                .and(Space::clear().expand())
+                .and(logging_target("Rejecting", &rejecting, false).expand())
+                .into_columns()
+                .build_recorder()
+                .size(Size::new(UPx::new(400), UPx::new(100)))
+                .finish()
+                .expect("error creating recorder");
+            recorder.refresh().expect("error refreshing");
+            Self {
+                recorder,
+                accepting,
+                rejecting,
+                dragging,
+                dropped,
+            }
+        }
+
+        /// Returns the center of the widget displaying `text`.
+        fn center_of(&self, text: &str) -> Point<Px> {
+            let layout = self
+                .recorder
+                .window
+                .find_text(text)
+                .and_then(|widget| widget.last_layout())
+                .unwrap_or_else(|| panic!("{text} not displayed"));
+            layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2)
+        }
+
+        fn gap(&self) -> Point<Px> {
+            let layout = self
+                .recorder
+                .window
+                .find_widget::<Space>()
+                .and_then(|widget| widget.last_layout())
+                .expect("space laid out");
+            layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2)
+        }
+
+        fn source_id(&self) -> WidgetId {
+            self.recorder
+                .window
+                .find_widget::<DragSource>()
+                .expect("drag source")
+                .id()
+        }
+
+        fn press(&mut self, location: Point<Px>) {
+            let window = &mut self.recorder.window;
+            window.cursor_moved(DeviceId::Virtual(0), location);
+            let _ = window.mouse_input(
+                DeviceId::Virtual(0),
+                ElementState::Pressed,
+                MouseButton::Left,
+            );
+        }
+
+        fn move_to(&mut self, location: Point<Px>) {
+            self.recorder
+                .window
+                .cursor_moved(DeviceId::Virtual(0), location);
+        }
+
+        fn release(&mut self) {
+            let _ = self.recorder.window.mouse_input(
+                DeviceId::Virtual(0),
+                ElementState::Released,
+                MouseButton::Left,
+            );
+            self.recorder.refresh().expect("error refreshing");
+        }
+    }
+
+    #[test]
+    fn drop_events() {
+        let mut drag = TestDrag::new();
+        let source = drag.center_of("Source");
+        let target = drag.center_of("Accepting");
+        let gap = drag.gap();
+
+        drag.press(source);
+        drag.move_to(target);
+        assert!(drag.dragging.get());
+        drag.move_to(target + Point::new(Px::new(5), Px::new(5)));
+        drag.move_to(gap);
+        drag.move_to(target);
+        drag.release();
+
+        assert_eq!(
+            drag.accepting.get(),
+            ["enter", "over", "leave", "enter", "over", "drop"]
+        );
+        assert_eq!(drag.dropped.get(), Some((7, drag.source_id())));
+        assert!(drag.rejecting.get().is_empty());
+        assert!(!drag.dragging.get());
+    }
+
+    #[test]
+    fn rejected_drop() {
+        let mut drag = TestDrag::new();
+        let source = drag.center_of("Source");
+        let rejecting = drag.center_of("Rejecting");
+
+        // Releasing over a target that rejected the payload does not drop it.
+        drag.press(source);
+        drag.move_to(rejecting);
+        drag.move_to(rejecting + Point::new(Px::new(5), Px::ZERO));
+        drag.release();
+        assert_eq!(drag.rejecting.get(), ["enter"]);
+        assert_eq!(drag.dropped.get(), None);
+
+        // A rejected payload can still be dropped on another target.
+        let accepting = drag.center_of("Accepting");
+        drag.press(source);
+        drag.move_to(rejecting);
+        drag.move_to(accepting);
+        drag.release();
+        assert_eq!(drag.rejecting.get(), ["enter", "enter"]);
+        assert_eq!(drag.accepting.get(), ["enter", "over", "drop"]);
+        assert_eq!(drag.dropped.get(), Some((7, drag.source_id())));
+    }
+
+    #[test]
+    fn drag_threshold() {
+        let mut drag = TestDrag::new();
+        let source = drag.center_of("Source");
+
+        // Moving less than the threshold does not begin a drag.
+        drag.press(source);
+        drag.move_to(source + Point::new(Px::new(1), Px::ZERO));
+        assert!(!drag.dragging.get());
+        drag.release();
+        assert!(drag.accepting.get().is_empty());
+        assert!(drag.rejecting.get().is_empty());
+    }
+}
@@ -0,0 +1,786 @@
+//! Touch gesture recognition.
+
+use std::f32::consts::{PI, TAU};
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use figures::units::{Lp, Px};
+use figures::{FloatConversion, Fraction, Point, ScreenScale};
+use parking_lot::Mutex;
+
+use crate::animation::{AnimationHandle, IntoAnimate, Spawn};
+use crate::context::EventContext;
+use crate::widget::{EventHandling, MakeWidget, SharedCallback, WidgetRef, WrapperWidget, HANDLED};
+use crate::window::DeviceId;
+
+/// The duration a finger must remain still before a [`Gesture::LongPress`] is
+/// recognized.
+pub const LONG_PRESS_DURATION: Duration = Duration::from_millis(500);
+/// The maximum duration between two taps for them to be recognized as a
+/// [`Gesture::DoubleTap`].
+pub const DOUBLE_TAP_INTERVAL: Duration = Duration::from_millis(300);
+/// Touches that have not moved within this duration are considered to have
+/// stopped moving when computing a [`Pan::velocity`].
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// A gesture recognized by a [`GestureRecognizer`].
+///
+/// All locations are in the same coordinate space as the locations provided
+/// to the recognizer. When used from a widget's touch events, locations are
+/// relative to the widget.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    /// A finger was briefly touched and lifted without moving.
+    Tap(Point<Px>),
+    /// A tap occurred shortly after and near another tap.
+    ///
+    /// The first tap is reported as a [`Gesture::Tap`].
+    DoubleTap(Point<Px>),
+    /// A finger touched and remained still for [`LONG_PRESS_DURATION`].
+    LongPress(Point<Px>),
+    /// A single finger is being dragged.
+    Pan(Pan),
+    /// Two fingers are moving relative to each other.
+    Pinch(Pinch),
+}
+
+/// The stage of a continuous [`Gesture`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GesturePhase {
+    /// The gesture has begun.
+    Started,
+    /// The gesture is continuing.
+    Changed,
+    /// The gesture has finished.
+    Ended,
+}
+
+/// A single finger being dragged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pan {
+    /// The stage of this gesture.
+    pub phase: GesturePhase,
+    /// The current location of the finger.
+    pub location: Point<Px>,
+    /// The distance the finger has moved since the previous event of this
+    /// gesture.
+    pub delta: Point<Px>,
+    /// The velocity of the finger in pixels per second.
+    ///
+    /// When the gesture ends, this can be used to continue movement with
+    /// momentum. If the finger stopped moving before it was lifted, the
+    /// velocity will be zero.
+    pub velocity: Point<f32>,
+}
+
+/// Two fingers moving relative to each other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pinch {
+    /// The stage of this gesture.
+    pub phase: GesturePhase,
+    /// The point halfway between the two fingers.
+    pub center: Point<Px>,
+    /// The change in distance between the fingers since the previous event of
+    /// this gesture, as a multiplier. Values above 1.0 indicate the fingers
+    /// are moving apart.
+    pub scale: f32,
+    /// The change in the angle between the fingers since the previous event
+    /// of this gesture, in radians. Positive values indicate a clockwise
+    /// rotation.
+    pub rotation: f32,
+}
+
+/// Recognizes [`Gesture`]s from touch events.
+///
+/// Widgets feed touch events into a recognizer from their
+/// [`touch_down`](crate::widget::Widget::touch_down),
+/// [`touch_moved`](crate::widget::Widget::touch_moved), and
+/// [`touch_up`](crate::widget::Widget::touch_up) functions. Each function
+/// returns the gesture recognized from the event, if any.
+///
+/// A recognizer can only detect a [`Gesture::LongPress`] when it is notified
+/// about the passage of time. Widgets that are interested in long presses
+/// should call [`long_press()`](Self::long_press) once
+/// [`LONG_PRESS_DURATION`] has elapsed after a touch begins. If the finger is
+/// lifted before `long_press()` is called, the long press is reported when
+/// the touch ends.
+#[derive(Debug, Clone)]
+pub struct GestureRecognizer {
+    touches: Vec<TrackedTouch>,
+    mode: Mode,
+    last_tap: Option<(Instant, Point<Px>)>,
+    scale: Fraction,
+}
+
+impl Default for GestureRecognizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GestureRecognizer {
+    /// Returns a new recognizer with no active touches.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            touches: Vec::new(),
+            mode: Mode::Idle,
+            last_tap: None,
+            scale: Fraction::ONE,
+        }
+    }
+
+    /// Sets the display scale used to convert movement thresholds into
+    /// pixels.
+    pub fn set_scale(&mut self, scale: Fraction) {
+        self.scale = scale;
+    }
+
+    /// Returns true if any fingers are currently being tracked.
+    #[must_use]
+    pub fn is_tracking(&self) -> bool {
+        !self.touches.is_empty()
+    }
+
+    /// Returns the distance a finger can move before a touch is no longer
+    /// considered a tap.
+    fn tap_slop(&self) -> f32 {
+        Lp::mm(2).into_px(self.scale).into_float()
+    }
+
+    /// Returns the maximum distance between two taps that form a double tap.
+    fn double_tap_slop(&self) -> f32 {
+        Lp::mm(8).into_px(self.scale).into_float()
+    }
+
+    /// Notifies the recognizer that `device_id` has begun touching at
+    /// `location`.
+    pub fn touch_down(
+        &mut self,
+        device_id: DeviceId,
+        location: Point<Px>,
+        now: Instant,
+    ) -> Option<Gesture> {
+        if self
+            .touches
+            .iter()
+            .any(|touch| touch.device_id == device_id)
+        {
+            return None;
+        }
+        self.touches.push(TrackedTouch {
+            device_id,
+            location,
+            last_moved: now,
+            velocity: Point::default(),
+        });
+
+        match self.touches.len() {
+            1 => {
+                self.mode = Mode::Pending {
+                    started: now,
+                    start: location,
+                };
+                None
+            }
+            2 => {
+                let pinch = self.new_pinch();
+                let previous = std::mem::replace(&mut self.mode, pinch);
+                match previous {
+                    Mode::Panning => Some(Gesture::Pan(Pan {
+                        phase: GesturePhase::Ended,
+                        location: self.touches[0].location,
+                        delta: Point::default(),
+                        velocity: Point::default(),
+                    })),
+                    Mode::Finished => {
+                        self.mode = Mode::Finished;
+                        None
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Notifies the recognizer that `device_id` has moved to `location`.
+    pub fn touch_moved(
+        &mut self,
+        device_id: DeviceId,
+        location: Point<Px>,
+        now: Instant,
+    ) -> Option<Gesture> {
+        let index = self
+            .touches
+            .iter()
+            .position(|touch| touch.device_id == device_id)?;
+        let delta = self.touches[index].move_to(location, now);
+
+        match &mut self.mode {
+            Mode::Pending { start, .. } => {
+                let moved = location - *start;
+                if length(moved) <= self.tap_slop() {
+                    return None;
+                }
+                self.mode = Mode::Panning;
+                Some(Gesture::Pan(Pan {
+                    phase: GesturePhase::Started,
+                    location,
+                    delta: moved,
+                    velocity: self.touches[index].velocity,
+                }))
+            }
+            Mode::Panning => Some(Gesture::Pan(Pan {
+                phase: GesturePhase::Changed,
+                location,
+                delta,
+                velocity: self.touches[index].velocity,
+            })),
+            Mode::Pinching(_) if index > 1 => None,
+            Mode::Pinching(pinch) => {
+                let (center, distance, angle) =
+                    pinch_metrics(self.touches[0].location, self.touches[1].location);
+                if distance <= 0. || pinch.distance <= 0. {
+                    return None;
+                }
+                let scale = distance / pinch.distance;
+                let rotation = normalize_angle(angle - pinch.angle);
+                let phase = if pinch.started {
+                    GesturePhase::Changed
+                } else {
+                    GesturePhase::Started
+                };
+                pinch.started = true;
+                pinch.distance = distance;
+                pinch.angle = angle;
+                Some(Gesture::Pinch(Pinch {
+                    phase,
+                    center,
+                    scale,
+                    rotation,
+                }))
+            }
+            Mode::Idle | Mode::Finished => None,
+        }
+    }
+
+    /// Notifies the recognizer that `device_id` is no longer touching.
+    ///
+    /// `cancelled` should be true if the touch was cancelled rather than the
+    /// finger being lifted. Cancelled touches never produce taps, and end any
+    /// pan without velocity.
+    pub fn touch_up(
+        &mut self,
+        device_id: DeviceId,
+        location: Point<Px>,
+        cancelled: bool,
+        now: Instant,
+    ) -> Option<Gesture> {
+        let index = self
+            .touches
+            .iter()
+            .position(|touch| touch.device_id == device_id)?;
+        let mut touch = self.touches.remove(index);
+        let delta = touch.move_to(location, now);
+
+        let gesture = match self.mode {
+            Mode::Pending { started, .. } if !cancelled => {
+                if now.saturating_duration_since(started) >= LONG_PRESS_DURATION {
+                    Some(Gesture::LongPress(location))
+                } else if self.last_tap.is_some_and(|(tapped, tap_location)| {
+                    now.saturating_duration_since(tapped) <= DOUBLE_TAP_INTERVAL
+                        && length(location - tap_location) <= self.double_tap_slop()
+                }) {
+                    self.last_tap = None;
+                    Some(Gesture::DoubleTap(location))
+                } else {
+                    self.last_tap = Some((now, location));
+                    Some(Gesture::Tap(location))
+                }
+            }
+            Mode::Panning => Some(Gesture::Pan(Pan {
+                phase: GesturePhase::Ended,
+                location,
+                delta,
+                velocity: if cancelled
+                    || now.saturating_duration_since(touch.last_moved) > VELOCITY_TIMEOUT
+                {
+                    Point::default()
+                } else {
+                    touch.velocity
+                },
+            })),
+            Mode::Pinching(pinch) if index < 2 => {
+                self.mode = Mode::Finished;
+                pinch.started.then(|| {
+                    Gesture::Pinch(Pinch {
+                        phase: GesturePhase::Ended,
+                        center: location,
+                        scale: 1.,
+                        rotation: 0.,
+                    })
+                })
+            }
+            _ => None,
+        };
+
+        if self.touches.is_empty() {
+            self.mode = Mode::Idle;
+        } else if matches!(self.mode, Mode::Pending { .. } | Mode::Panning) {
+            self.mode = Mode::Finished;
+        }
+
+        gesture
+    }
+
+    /// Checks whether the current touch has become a long press.
+    ///
+    /// Returns [`Gesture::LongPress`] if a single finger has been touching
+    /// without moving for at least [`LONG_PRESS_DURATION`]. Once a long press
+    /// is returned, no other gestures are recognized until all fingers are
+    /// lifted.
+    pub fn long_press(&mut self, now: Instant) -> Option<Gesture> {
+        match self.mode {
+            Mode::Pending { started, .. }
+                if now.saturating_duration_since(started) >= LONG_PRESS_DURATION =>
+            {
+                self.mode = Mode::Finished;
+                Some(Gesture::LongPress(self.touches.first()?.location))
+            }
+            _ => None,
+        }
+    }
+
+    fn new_pinch(&self) -> Mode {
+        let (_, distance, angle) =
+            pinch_metrics(self.touches[0].location, self.touches[1].location);
+        Mode::Pinching(PinchState {
+            distance,
+            angle,
+            started: false,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mode {
+    Idle,
+    Pending {
+        started: Instant,
+        start: Point<Px>,
+    },
+    Panning,
+    Pinching(PinchState),
+    /// A gesture has completed, and no further gestures will be recognized
+    /// until all touches end.
+    Finished,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PinchState {
+    distance: f32,
+    angle: f32,
+    started: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TrackedTouch {
+    device_id: DeviceId,
+    location: Point<Px>,
+    last_moved: Instant,
+    velocity: Point<f32>,
+}
+
+impl TrackedTouch {
+    /// Moves this touch to `location`, updating its velocity, and returns the
+    /// distance moved.
+    fn move_to(&mut self, location: Point<Px>, now: Instant) -> Point<Px> {
+        let delta = location - self.location;
+        if delta == Point::default() {
+            return delta;
+        }
+
+        let elapsed = now.saturating_duration_since(self.last_moved).as_secs_f32();
+        if elapsed > 0. {
+            let instant = Point::new(
+                delta.x.into_float() / elapsed,
+                delta.y.into_float() / elapsed,
+            );
+            // Smooth the velocity to reduce the effect of jittery events.
+            self.velocity = Point::new(
+                self.velocity.x * 0.2 + instant.x * 0.8,
+                self.velocity.y * 0.2 + instant.y * 0.8,
+            );
+        }
+        self.location = location;
+        self.last_moved = now;
+        delta
+    }
+}
+
+fn length(point: Point<Px>) -> f32 {
+    point.x.into_float().hypot(point.y.into_float())
+}
+
+/// Returns the center, distance, and angle between two touches.
+fn pinch_metrics(a: Point<Px>, b: Point<Px>) -> (Point<Px>, f32, f32) {
+    let center = Point::new((a.x + b.x) / 2, (a.y + b.y) / 2);
+    let difference = b - a;
+    let angle = difference.y.into_float().atan2(difference.x.into_float());
+    (center, length(difference), angle)
+}
+
+/// Normalizes `angle` to be within -PI..=PI.
+fn normalize_angle(angle: f32) -> f32 {
+    let angle = angle.rem_euclid(TAU);
+    if angle > PI {
+        angle - TAU
+    } else {
+        angle
+    }
+}
+
+/// A widget that recognizes touch [`Gesture`]s performed on its child.
+///
+/// Use [`MakeWidget::on_gesture`] to create this widget.
+pub struct Gestures {
+    child: WidgetRef,
+    recognizer: Arc<Mutex<GestureRecognizer>>,
+    on_gesture: SharedCallback<Gesture>,
+    long_press: AnimationHandle,
+}
+
+impl Gestures {
+    /// Returns a widget that invokes `on_gesture` for each gesture performed
+    /// on `child`.
+    pub fn new<F>(on_gesture: F, child: impl MakeWidget) -> Self
+    where
+        F: FnMut(Gesture) + Send + 'static,
+    {
+        Self {
+            child: WidgetRef::new(child),
+            recognizer: Arc::default(),
+            on_gesture: SharedCallback::new(on_gesture),
+            long_press: AnimationHandle::default(),
+        }
+    }
+
+    fn notify(&self, gesture: Option<Gesture>) {
+        if let Some(gesture) = gesture {
+            self.on_gesture.invoke(gesture);
+        }
+    }
+}
+
+impl Debug for Gestures {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gestures")
+            .field("child", &self.child)
+            .field("recognizer", &self.recognizer)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WrapperWidget for Gestures {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    // Touches anywhere within the child are recognized, even if the child
+    // does not respond to input itself.
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        true
+    }
+
+    fn touch_down(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let mut recognizer = self.recognizer.lock();
+        recognizer.set_scale(context.kludgine.scale());
+        let gesture = recognizer.touch_down(device_id, location, Instant::now());
+        drop(recognizer);
+        self.notify(gesture);
+
+        let recognizer = self.recognizer.clone();
+        let on_gesture = self.on_gesture.clone();
+        self.long_press = LONG_PRESS_DURATION
+            .on_complete(move || {
+                let gesture = recognizer.lock().long_press(Instant::now());
+                if let Some(gesture) = gesture {
+                    on_gesture.invoke(gesture);
+                }
+            })
+            .spawn();
+        HANDLED
+    }
+
+    fn touch_moved(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        _context: &mut EventContext<'_>,
+    ) {
+        let gesture = self
+            .recognizer
+            .lock()
+            .touch_moved(device_id, location, Instant::now());
+        self.notify(gesture);
+    }
+
+    fn touch_up(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        cancelled: bool,
+        _context: &mut EventContext<'_>,
+    ) {
+        let mut recognizer = self.recognizer.lock();
+        let gesture = recognizer.touch_up(device_id, location, cancelled, Instant::now());
+        if !recognizer.is_tracking() {
+            self.long_press.clear();
+        }
+        drop(recognizer);
+        self.notify(gesture);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use figures::units::{Px, UPx};
+    use figures::{Point, Px2D, Size};
+    use kludgine::app::winit::event::TouchPhase;
+
+    use super::{
+        Gesture, GesturePhase, GestureRecognizer, Pan, Pinch, DOUBLE_TAP_INTERVAL,
+        LONG_PRESS_DURATION,
+    };
+    use crate::value::{Destination, Dynamic, Source};
+    use crate::widget::MakeWidget;
+    use crate::widgets::Space;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    const FIRST: DeviceId = DeviceId::finger(None, 0);
+    const SECOND: DeviceId = DeviceId::finger(None, 1);
+
+    #[test]
+    fn taps() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+        let location = Point::px(10, 10);
+        assert_eq!(recognizer.touch_down(FIRST, location, start), None);
+        assert_eq!(
+            recognizer.touch_up(FIRST, location, false, start + Duration::from_millis(50)),
+            Some(Gesture::Tap(location))
+        );
+
+        let second = start + DOUBLE_TAP_INTERVAL / 2;
+        recognizer.touch_down(FIRST, location, second);
+        assert_eq!(
+            recognizer.touch_up(FIRST, location, false, second + Duration::from_millis(50)),
+            Some(Gesture::DoubleTap(location))
+        );
+
+        // Cancelled touches are never taps.
+        let third = second + DOUBLE_TAP_INTERVAL * 2;
+        recognizer.touch_down(FIRST, location, third);
+        assert_eq!(recognizer.touch_up(FIRST, location, true, third), None);
+    }
+
+    #[test]
+    fn long_press() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+        let location = Point::px(10, 10);
+        recognizer.touch_down(FIRST, location, start);
+        assert_eq!(recognizer.long_press(start), None);
+        assert_eq!(
+            recognizer.long_press(start + LONG_PRESS_DURATION),
+            Some(Gesture::LongPress(location))
+        );
+        assert_eq!(
+            recognizer.touch_up(FIRST, location, false, start + LONG_PRESS_DURATION * 2),
+            None
+        );
+        assert!(!recognizer.is_tracking());
+    }
+
+    #[test]
+    fn pan() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+        let later = |ms| start + Duration::from_millis(ms);
+        recognizer.touch_down(FIRST, Point::px(0, 0), start);
+        // Small movements are still considered part of a tap.
+        assert_eq!(
+            recognizer.touch_moved(FIRST, Point::px(1, 1), later(10)),
+            None
+        );
+        let Some(Gesture::Pan(Pan {
+            phase: GesturePhase::Started,
+            delta,
+            ..
+        })) = recognizer.touch_moved(FIRST, Point::px(0, 50), later(20))
+        else {
+            unreachable!("pan not started")
+        };
+        assert_eq!(delta, Point::px(0, 50));
+        let Some(Gesture::Pan(Pan {
+            phase: GesturePhase::Changed,
+            delta,
+            ..
+        })) = recognizer.touch_moved(FIRST, Point::px(0, 100), later(30))
+        else {
+            unreachable!("pan not continued")
+        };
+        assert_eq!(delta, Point::px(0, 50));
+        let Some(Gesture::Pan(Pan {
+            phase: GesturePhase::Ended,
+            velocity,
+            ..
+        })) = recognizer.touch_up(FIRST, Point::px(0, 100), false, later(35))
+        else {
+            unreachable!("pan not ended")
+        };
+        assert!(velocity.y > 0.);
+        assert!(!recognizer.is_tracking());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn pinch() {
+        let mut recognizer = GestureRecognizer::new();
+        let start = Instant::now();
+        recognizer.touch_down(FIRST, Point::px(100, 100), start);
+        recognizer.touch_down(SECOND, Point::px(200, 100), start);
+        let Some(Gesture::Pinch(Pinch {
+            phase: GesturePhase::Started,
+            center,
+            scale,
+            ..
+        })) = recognizer.touch_moved(SECOND, Point::px(300, 100), start)
+        else {
+            unreachable!("pinch not started")
+        };
+        assert_eq!(center, Point::new(Px::new(200), Px::new(100)));
+        assert_eq!(scale, 2.);
+
+        let Some(Gesture::Pinch(Pinch {
+            phase: GesturePhase::Changed,
+            rotation,
+            ..
+        })) = recognizer.touch_moved(SECOND, Point::px(100, 300), start)
+        else {
+            unreachable!("pinch not continued")
+        };
+        assert!((rotation - std::f32::consts::FRAC_PI_2).abs() < 0.001);
+
+        assert!(matches!(
+            recognizer.touch_up(FIRST, Point::px(100, 100), false, start),
+            Some(Gesture::Pinch(Pinch {
+                phase: GesturePhase::Ended,
+                ..
+            }))
+        ));
+        // The remaining finger does not begin a new gesture.
+        assert_eq!(recognizer.touch_moved(SECOND, Point::px(0, 0), start), None);
+        assert_eq!(
+            recognizer.touch_up(SECOND, Point::px(0, 0), false, start),
+            None
+        );
+    }
+
+    /// Returns a recorder containing a 200px square that records every
+    /// recognized gesture.
+    fn recording_gestures() -> (VirtualRecorder, Dynamic<Vec<Gesture>>) {
+        let gestures = Dynamic::new(Vec::new());
+        let mut recorder = Space::clear()
+            .on_gesture({
+                let gestures = gestures.clone();
+                move |gesture| gestures.lock().push(gesture)
+            })
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        (recorder, gestures)
+    }
+
+    #[test]
+    fn window_taps() {
+        let (mut recorder, gestures) = recording_gestures();
+        let window = &mut recorder.window;
+        window.touch(FIRST, TouchPhase::Started, Point::px(50, 60));
+        window.touch(FIRST, TouchPhase::Ended, Point::px(50, 60));
+        window.touch(FIRST, TouchPhase::Started, Point::px(52, 60));
+        window.touch(FIRST, TouchPhase::Ended, Point::px(52, 60));
+        assert_eq!(
+            gestures.take(),
+            [
+                Gesture::Tap(Point::px(50, 60)),
+                Gesture::DoubleTap(Point::px(52, 60))
+            ]
+        );
+    }
+
+    #[test]
+    fn window_pan() {
+        let (mut recorder, gestures) = recording_gestures();
+        let window = &mut recorder.window;
+        window.touch(FIRST, TouchPhase::Started, Point::px(50, 50));
+        window.touch(FIRST, TouchPhase::Moved, Point::px(50, 100));
+        window.touch(FIRST, TouchPhase::Moved, Point::px(60, 120));
+        window.touch(FIRST, TouchPhase::Ended, Point::px(60, 120));
+        let gestures = gestures.take();
+        let pans = gestures
+            .iter()
+            .map(|gesture| match gesture {
+                Gesture::Pan(pan) => (pan.phase, pan.location, pan.delta),
+                other => unreachable!("unexpected gesture {other:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pans,
+            [
+                (GesturePhase::Started, Point::px(50, 100), Point::px(0, 50)),
+                (GesturePhase::Changed, Point::px(60, 120), Point::px(10, 20)),
+                (GesturePhase::Ended, Point::px(60, 120), Point::px(0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn window_pinch() {
+        let (mut recorder, gestures) = recording_gestures();
+        let window = &mut recorder.window;
+        window.touch(FIRST, TouchPhase::Started, Point::px(50, 100));
+        window.touch(SECOND, TouchPhase::Started, Point::px(100, 100));
+        window.touch(SECOND, TouchPhase::Moved, Point::px(150, 100));
+        window.touch(FIRST, TouchPhase::Ended, Point::px(50, 100));
+        window.touch(SECOND, TouchPhase::Ended, Point::px(150, 100));
+
+        let gestures = gestures.take();
+        let [Gesture::Pinch(Pinch {
+            phase: GesturePhase::Started,
+            center,
+            scale,
+            ..
+        }), Gesture::Pinch(Pinch {
+            phase: GesturePhase::Ended,
+            ..
+        })] = gestures.as_slice()
+        else {
+            unreachable!("unexpected gestures {gestures:?}")
+        };
+        assert_eq!(*center, Point::px(100, 100));
+        assert_eq!(*scale, 2.);
+    }
+}
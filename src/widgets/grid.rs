@@ -238,6 +238,14 @@ impl Orientation {
         }
     }
 
+    /// Splits a point into its measured and other parts.
+    pub(crate) fn split_point<U>(self, p: Point<U>) -> (U, U) {
+        match self {
+            Orientation::Row => (p.y, p.x),
+            Orientation::Column => (p.x, p.y),
+        }
+    }
+
     /// Combines split values into a [`Point`].
     pub(crate) fn make_point<U>(self, measured: U, other: U) -> Point<U> {
         match self {
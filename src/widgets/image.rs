@@ -1,5 +1,7 @@
 //! A widget that displays an image/texture.
 
+use std::time::Instant;
+
 use figures::units::{Px, UPx};
 use figures::{FloatConversion, IntoSigned, IntoUnsigned, Point, Rect, ScreenScale, Size, Zero};
 use kludgine::shapes::{CornerRadii, Shape};
@@ -8,12 +10,20 @@ use kludgine::{
 };
 
 use crate::animation::ZeroToOne;
-use crate::context::{LayoutContext, Trackable};
+use crate::context::{EventContext, LayoutContext, Trackable};
 use crate::styles::Dimension;
-use crate::value::{IntoValue, Source, Value};
-use crate::widget::Widget;
+use crate::value::{Destination, Dynamic, IntoDynamic, IntoValue, Source, Value};
+use crate::widget::{EventHandling, Widget, HANDLED, IGNORED};
+use crate::widgets::gestures::{Gesture, GesturePhase, GestureRecognizer};
+use crate::window::DeviceId;
 use crate::ConstraintLimit;
 
+#[cfg(feature = "animated-image")]
+mod animated;
+
+#[cfg(feature = "animated-image")]
+pub use self::animated::{AnimatedImage, AnimationFrame, LoopCount};
+
 /// A widget that displays an image/texture.
 #[derive(Debug)]
 pub struct Image {
@@ -23,6 +33,10 @@ pub struct Image {
     pub scaling: Value<ImageScaling>,
     /// The opacity to render the image with.
     pub opacity: Value<ZeroToOne>,
+    zoom: Option<Dynamic<f32>>,
+    zoom_offset: Point<f32>,
+    pinch_center: Point<Px>,
+    gestures: GestureRecognizer,
 }
 
 impl Image {
@@ -33,6 +47,10 @@ impl Image {
             contents: contents.into_value(),
             scaling: Value::default(),
             opacity: Value::Constant(ZeroToOne::ONE),
+            zoom: None,
+            zoom_offset: Point::default(),
+            pinch_center: Point::default(),
+            gestures: GestureRecognizer::new(),
         }
     }
 
@@ -50,6 +68,17 @@ impl Image {
         self
     }
 
+    /// Allows zooming into the image using a pinch gesture, and returns self.
+    ///
+    /// `zoom` is a multiplier applied on top of the scaling strategy, and is
+    /// never less than 1.0. Zooming is centered around the fingers performing
+    /// the pinch, and double tapping the image resets the zoom.
+    #[must_use]
+    pub fn pinch_to_zoom(mut self, zoom: impl IntoDynamic<f32>) -> Self {
+        self.zoom = Some(zoom.into_dynamic());
+        self
+    }
+
     /// Applies the aspect-fit scaling strategy and returns self.
     ///
     /// The aspect-fit scaling strategy scales the image to be the largest size
@@ -132,9 +161,33 @@ impl Widget for Image {
         let radii = context.get(&ImageCornerRadius);
         let radii = radii.map(|r| r.into_px(context.gfx.scale()));
         let scaling = self.scaling.get_tracking_invalidate(context);
+        let zoom = match &self.zoom {
+            Some(zoom) => zoom.get_tracking_redraw(context).max(1.),
+            None => 1.,
+        };
+        let size = context.gfx.size().into_signed();
+        self.zoom_offset = Point::new(
+            self.zoom_offset
+                .x
+                .clamp(size.width.into_float() * (1. - zoom), 0.),
+            self.zoom_offset
+                .y
+                .clamp(size.height.into_float() * (1. - zoom), 0.),
+        );
+        let offset = self.zoom_offset;
 
         self.contents.map(|texture| {
             let rect = scaling.render_area(texture.size(), context.gfx.size());
+            let rect = Rect::new(
+                Point::new(
+                    Px::from(rect.origin.x.into_float() * zoom + offset.x),
+                    Px::from(rect.origin.y.into_float() * zoom + offset.y),
+                ),
+                Size::new(
+                    Px::from(rect.size.width.into_float() * zoom),
+                    Px::from(rect.size.height.into_float() * zoom),
+                ),
+            );
             if radii.is_zero() {
                 context.gfx.draw_texture(texture, rect, opacity);
             } else {
@@ -161,6 +214,78 @@ impl Widget for Image {
         self.contents
             .map(|texture| scaling.layout_size(texture.size(), available_space))
     }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        self.zoom.is_some()
+    }
+
+    fn touch_down(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if self.zoom.is_none() {
+            return IGNORED;
+        }
+        self.gestures.set_scale(context.kludgine.scale());
+        self.gestures
+            .touch_down(device_id, location, Instant::now());
+        HANDLED
+    }
+
+    fn touch_moved(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) {
+        let Some(zoom) = &self.zoom else {
+            return;
+        };
+        let Some(Gesture::Pinch(pinch)) =
+            self.gestures
+                .touch_moved(device_id, location, Instant::now())
+        else {
+            return;
+        };
+        let previous_center = if pinch.phase == GesturePhase::Started {
+            pinch.center
+        } else {
+            self.pinch_center
+        };
+        self.pinch_center = pinch.center;
+
+        let current = zoom.get().max(1.);
+        let new_zoom = (current * pinch.scale).max(1.);
+        let applied = new_zoom / current;
+        // Keep the point under the previous center under the new center.
+        self.zoom_offset = Point::new(
+            pinch.center.x.into_float()
+                + applied * (self.zoom_offset.x - previous_center.x.into_float()),
+            pinch.center.y.into_float()
+                + applied * (self.zoom_offset.y - previous_center.y.into_float()),
+        );
+        zoom.set(new_zoom);
+        context.set_needs_redraw();
+    }
+
+    fn touch_up(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        cancelled: bool,
+        context: &mut EventContext<'_>,
+    ) {
+        let gesture = self
+            .gestures
+            .touch_up(device_id, location, cancelled, Instant::now());
+        if let (Some(zoom), Some(Gesture::DoubleTap(_))) = (&self.zoom, gesture) {
+            zoom.set(1.);
+            self.zoom_offset = Point::default();
+            context.set_needs_redraw();
+        }
+    }
 }
 
 /// A scaling strategy for an [`Image`] widget.
@@ -305,3 +430,65 @@ define_components! {
         ImageCornerRadius(CornerRadii<Dimension>, "corner_radius", CornerRadii::ZERO)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+    use figures::{Point, Px2D, Size};
+    use image::{DynamicImage, RgbaImage};
+    use kludgine::app::winit::event::TouchPhase;
+    use kludgine::wgpu::FilterMode;
+    use kludgine::{AnyTexture, LazyTexture};
+
+    use super::Image;
+    use crate::value::{Destination, Dynamic, Source};
+    use crate::widget::MakeWidget;
+    use crate::window::DeviceId;
+
+    const FIRST: DeviceId = DeviceId::finger(None, 0);
+    const SECOND: DeviceId = DeviceId::finger(None, 1);
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn pinch_to_zoom() {
+        let zoom = Dynamic::new(1.0_f32);
+        let texture = AnyTexture::from(LazyTexture::from_image(
+            DynamicImage::ImageRgba8(RgbaImage::new(100, 100)),
+            FilterMode::Linear,
+        ));
+        let mut recorder = Image::new(texture)
+            .pinch_to_zoom(zoom.clone())
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let window = &mut recorder.window;
+
+        // Spreading two fingers zooms in by the change in their distance.
+        window.touch(FIRST, TouchPhase::Started, Point::px(50, 100));
+        window.touch(SECOND, TouchPhase::Started, Point::px(100, 100));
+        window.touch(SECOND, TouchPhase::Moved, Point::px(150, 100));
+        assert_eq!(zoom.get(), 2.);
+        window.touch(FIRST, TouchPhase::Moved, Point::px(0, 100));
+        assert_eq!(zoom.get(), 3.);
+        window.touch(FIRST, TouchPhase::Ended, Point::px(0, 100));
+        window.touch(SECOND, TouchPhase::Ended, Point::px(150, 100));
+
+        // Pinching can not zoom out beyond the image's original size.
+        window.touch(FIRST, TouchPhase::Started, Point::px(0, 100));
+        window.touch(SECOND, TouchPhase::Started, Point::px(200, 100));
+        window.touch(SECOND, TouchPhase::Moved, Point::px(20, 100));
+        assert_eq!(zoom.get(), 1.);
+        window.touch(FIRST, TouchPhase::Ended, Point::px(0, 100));
+        window.touch(SECOND, TouchPhase::Ended, Point::px(20, 100));
+
+        // Double tapping resets the zoom.
+        zoom.set(2.5);
+        for _ in 0..2 {
+            window.touch(FIRST, TouchPhase::Started, Point::px(100, 100));
+            window.touch(FIRST, TouchPhase::Ended, Point::px(100, 100));
+        }
+        assert_eq!(zoom.get(), 1.);
+    }
+}
@@ -0,0 +1,473 @@
+use std::fmt::{self, Debug};
+use std::io::Cursor;
+use std::ops::ControlFlow;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::{AnimationDecoder, DynamicImage, Frame, ImageFormat, ImageResult};
+use kludgine::wgpu::FilterMode;
+use kludgine::{AnyTexture, LazyTexture};
+use parking_lot::Mutex;
+
+use super::Image;
+use crate::animation::Spawn;
+use crate::value::{
+    CallbackDisconnected, Destination, Dynamic, IntoDynamic, IntoValue, Source, Value, WeakDynamic,
+};
+use crate::widget::{MakeWidgetWithTag, WidgetInstance, WidgetTag};
+
+/// Frame delays at or below this duration are treated as
+/// [`DEFAULT_FRAME_DELAY`], matching the behavior of web browsers.
+const MINIMUM_FRAME_DELAY: Duration = Duration::from_millis(10);
+/// The delay used for frames that do not specify a usable delay.
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// An animated image that can be displayed by an [`Image`].
+///
+/// GIF, animated PNG (APNG), and animated WebP images are supported. Images
+/// in other formats, or images that contain only a single frame, are loaded as
+/// a single still frame.
+///
+/// Playback is driven by Cushy's animation runtime and is controlled through
+/// three [`Dynamic`]s:
+///
+/// - [`playing`](Self::playing): whether the animation is currently advancing.
+///   Setting this to `false` pauses playback, and setting it to `true` resumes
+///   playback. Once all loops have completed, this is set to `false`. Setting
+///   it to `true` again restarts playback from the first frame.
+/// - [`loops`](Self::loops): how many times to play the animation.
+/// - [`frame`](Self::frame): the index of the frame being displayed. This can
+///   be set to seek to a specific frame.
+///
+/// Playback stops once all widgets displaying this image have been dropped.
+///
+/// Cloning an animated image is cheap, as the decoded frames are shared.
+/// Clones also share the same playback controls.
+#[derive(Clone)]
+pub struct AnimatedImage {
+    frames: Arc<[AnimationFrame]>,
+    playing: Dynamic<bool>,
+    loops: Value<LoopCount>,
+    frame: Dynamic<usize>,
+}
+
+impl AnimatedImage {
+    /// Decodes an animated image from `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format of `data` cannot be determined or if
+    /// the image cannot be decoded.
+    pub fn from_data(data: &[u8]) -> ImageResult<Self> {
+        let frames = match image::guess_format(data)? {
+            ImageFormat::Gif => GifDecoder::new(Cursor::new(data))?
+                .into_frames()
+                .collect_frames()?,
+            ImageFormat::Png => {
+                let decoder = PngDecoder::new(Cursor::new(data))?;
+                if decoder.is_apng()? {
+                    decoder.apng()?.into_frames().collect_frames()?
+                } else {
+                    vec![Frame::new(
+                        DynamicImage::from_decoder(decoder)?.into_rgba8(),
+                    )]
+                }
+            }
+            ImageFormat::WebP => {
+                let decoder = WebPDecoder::new(Cursor::new(data))?;
+                if decoder.has_animation() {
+                    decoder.into_frames().collect_frames()?
+                } else {
+                    vec![Frame::new(
+                        DynamicImage::from_decoder(decoder)?.into_rgba8(),
+                    )]
+                }
+            }
+            _ => vec![Frame::new(image::load_from_memory(data)?.into_rgba8())],
+        };
+
+        Ok(Self::from_frames(frames))
+    }
+
+    /// Reads and decodes the animated image at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or decoded.
+    pub fn open(path: impl AsRef<Path>) -> ImageResult<Self> {
+        Self::from_data(&std::fs::read(path)?)
+    }
+
+    /// Returns a new animated image from a list of decoded frames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is empty.
+    #[must_use]
+    pub fn from_frames(frames: impl IntoIterator<Item = Frame>) -> Self {
+        let frames = frames
+            .into_iter()
+            .map(AnimationFrame::from)
+            .collect::<Arc<[_]>>();
+        assert!(
+            !frames.is_empty(),
+            "an animated image needs at least one frame"
+        );
+        Self {
+            frames,
+            playing: Dynamic::new(true),
+            loops: Value::Constant(LoopCount::Forever),
+            frame: Dynamic::new(0),
+        }
+    }
+
+    /// Controls whether this image is playing using `playing`, and returns
+    /// self.
+    ///
+    /// By default, animated images begin playing as soon as they are
+    /// displayed.
+    #[must_use]
+    pub fn playing(mut self, playing: impl IntoDynamic<bool>) -> Self {
+        self.playing = playing.into_dynamic();
+        self
+    }
+
+    /// Sets the number of times to play the animation to `loops`, and returns
+    /// self.
+    ///
+    /// By default, animated images loop forever.
+    #[must_use]
+    pub fn loops(mut self, loops: impl IntoValue<LoopCount>) -> Self {
+        self.loops = loops.into_value();
+        self
+    }
+
+    /// Uses `frame` to store the index of the currently displayed frame, and
+    /// returns self.
+    ///
+    /// Setting `frame` displays the frame at the new index. While playing,
+    /// playback continues from the new frame.
+    #[must_use]
+    pub fn frame(mut self, frame: impl IntoDynamic<usize>) -> Self {
+        self.frame = frame.into_dynamic();
+        self
+    }
+
+    /// Returns the frames of this animation.
+    #[must_use]
+    pub fn frames(&self) -> &[AnimationFrame] {
+        &self.frames
+    }
+
+    /// Returns the total duration of a single loop of this animation.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+
+    /// Returns a new [`Image`] that displays this animation.
+    #[must_use]
+    pub fn into_image(self) -> Image {
+        Image::new(self)
+    }
+}
+
+impl Debug for AnimatedImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnimatedImage")
+            .field("frames", &self.frames.len())
+            .field("playing", &self.playing)
+            .field("loops", &self.loops)
+            .field("frame", &self.frame)
+            .finish()
+    }
+}
+
+impl IntoValue<AnyTexture> for AnimatedImage {
+    fn into_value(self) -> Value<AnyTexture> {
+        let first = self.frame.get().min(self.frames.len() - 1);
+        let texture = Dynamic::new(self.frames[first].texture.clone());
+
+        let weak_texture = texture.downgrade();
+        let frames = self.frames.clone();
+        texture.set_source(self.frame.for_each_cloned_try(move |index| {
+            let texture = weak_texture.upgrade().ok_or(CallbackDisconnected)?;
+            if let Some(frame) = frames.get(index) {
+                *texture.lock() = frame.texture.clone();
+            }
+            Ok(())
+        }));
+
+        if self.frames.len() > 1 {
+            let playback = Playback {
+                frames: self.frames,
+                playing: self.playing.clone(),
+                loops: self.loops,
+                frame: self.frame,
+                texture: texture.downgrade(),
+                state: Arc::default(),
+            };
+            texture.set_source(self.playing.for_each_cloned(move |playing| {
+                if playing {
+                    playback.start();
+                }
+            }));
+        }
+
+        Value::Dynamic(texture)
+    }
+}
+
+impl MakeWidgetWithTag for AnimatedImage {
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        self.into_image().make_with_tag(tag)
+    }
+}
+
+/// A single frame of an [`AnimatedImage`].
+#[derive(Debug, Clone)]
+pub struct AnimationFrame {
+    /// The image of this frame.
+    pub texture: AnyTexture,
+    /// The duration this frame is displayed.
+    pub delay: Duration,
+}
+
+impl From<Frame> for AnimationFrame {
+    fn from(frame: Frame) -> Self {
+        let delay = Duration::from(frame.delay());
+        let delay = if delay <= MINIMUM_FRAME_DELAY {
+            DEFAULT_FRAME_DELAY
+        } else {
+            delay
+        };
+        Self {
+            texture: AnyTexture::from(LazyTexture::from_image(
+                DynamicImage::ImageRgba8(frame.into_buffer()),
+                FilterMode::Linear,
+            )),
+            delay,
+        }
+    }
+}
+
+/// The number of times an [`AnimatedImage`] is played.
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LoopCount {
+    /// The animation repeats until it is paused.
+    #[default]
+    Forever,
+    /// The animation is played this many times before stopping on its last
+    /// frame.
+    Times(usize),
+}
+
+impl IntoValue<LoopCount> for usize {
+    fn into_value(self) -> Value<LoopCount> {
+        Value::Constant(LoopCount::Times(self))
+    }
+}
+
+#[derive(Clone)]
+struct Playback {
+    frames: Arc<[AnimationFrame]>,
+    playing: Dynamic<bool>,
+    loops: Value<LoopCount>,
+    frame: Dynamic<usize>,
+    texture: WeakDynamic<AnyTexture>,
+    state: Arc<Mutex<PlaybackState>>,
+}
+
+impl Playback {
+    fn start(&self) {
+        let mut state = self.state.lock();
+        if state.running {
+            return;
+        }
+        state.running = true;
+        if state.finished {
+            state.finished = false;
+            state.completed_loops = 0;
+            state.frame_elapsed = Duration::ZERO;
+            state.frame = 0;
+            drop(state);
+            self.frame.set(0);
+        } else {
+            drop(state);
+        }
+
+        let playback = self.clone();
+        (move |elapsed| playback.step(elapsed)).launch();
+    }
+
+    fn step(&self, elapsed: Duration) -> ControlFlow<Duration> {
+        let mut state = self.state.lock();
+        if self.texture.upgrade().is_none() || !self.playing.get() {
+            state.running = false;
+            return ControlFlow::Break(Duration::ZERO);
+        }
+
+        let mut index = self.frame.get();
+        if index >= self.frames.len() {
+            index = 0;
+        }
+        if index != state.frame {
+            // The frame was changed outside of playback.
+            state.frame = index;
+            state.frame_elapsed = Duration::ZERO;
+        }
+
+        state.frame_elapsed += elapsed;
+        while state.frame_elapsed >= self.frames[index].delay {
+            state.frame_elapsed -= self.frames[index].delay;
+            if index + 1 < self.frames.len() {
+                index += 1;
+            } else {
+                state.completed_loops += 1;
+                if let LoopCount::Times(loops) = self.loops.get() {
+                    if state.completed_loops >= loops {
+                        state.running = false;
+                        state.finished = true;
+                        state.frame = index;
+                        drop(state);
+                        self.frame.set(index);
+                        self.playing.set(false);
+                        return ControlFlow::Break(Duration::ZERO);
+                    }
+                }
+                index = 0;
+            }
+        }
+
+        state.frame = index;
+        drop(state);
+        self.frame.set(index);
+        ControlFlow::Continue(())
+    }
+}
+
+#[derive(Default)]
+struct PlaybackState {
+    running: bool,
+    finished: bool,
+    frame: usize,
+    frame_elapsed: Duration,
+    completed_loops: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::ControlFlow;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use image::{Delay, Frame, RgbaImage};
+    use kludgine::AnyTexture;
+
+    use super::{AnimationFrame, LoopCount, Playback, DEFAULT_FRAME_DELAY};
+    use crate::value::{Destination, Dynamic, Source, Value};
+
+    fn frame(delay_ms: u32) -> AnimationFrame {
+        AnimationFrame::from(Frame::from_parts(
+            RgbaImage::new(1, 1),
+            0,
+            0,
+            Delay::from_numer_denom_ms(delay_ms, 1),
+        ))
+    }
+
+    /// Returns a playback of frames with `delays`, and the texture that keeps
+    /// the playback running.
+    fn playback(delays: &[u32], loops: LoopCount) -> (Playback, Dynamic<AnyTexture>) {
+        let frames = delays.iter().copied().map(frame).collect::<Arc<[_]>>();
+        let texture = Dynamic::new(frames[0].texture.clone());
+        let playback = Playback {
+            frames,
+            playing: Dynamic::new(true),
+            loops: Value::Constant(loops),
+            frame: Dynamic::new(0),
+            texture: texture.downgrade(),
+            state: Arc::default(),
+        };
+        (playback, texture)
+    }
+
+    fn step(playback: &Playback, ms: u64) -> ControlFlow<Duration> {
+        playback.step(Duration::from_millis(ms))
+    }
+
+    #[test]
+    fn frame_delays() {
+        assert_eq!(frame(0).delay, DEFAULT_FRAME_DELAY);
+        assert_eq!(frame(10).delay, DEFAULT_FRAME_DELAY);
+        assert_eq!(frame(20).delay, Duration::from_millis(20));
+    }
+
+    #[test]
+    fn frame_timing() {
+        let (playback, _texture) = playback(&[100, 200, 100], LoopCount::Forever);
+
+        assert_eq!(step(&playback, 50), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 0);
+        assert_eq!(step(&playback, 60), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 1);
+        // The 10ms carried over from the first frame counts towards the
+        // second frame.
+        assert_eq!(step(&playback, 190), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 2);
+        // Long steps skip frames, and looping returns to the first frame.
+        assert_eq!(step(&playback, 300), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 1);
+
+        // Seeking restarts the timing of the new frame.
+        playback.frame.set(0);
+        assert_eq!(step(&playback, 90), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 0);
+        assert_eq!(step(&playback, 10), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 1);
+    }
+
+    #[test]
+    fn pause() {
+        let (playback, _texture) = playback(&[100, 100], LoopCount::Forever);
+        playback.state.lock().running = true;
+
+        playback.playing.set(false);
+        assert_eq!(step(&playback, 150), ControlFlow::Break(Duration::ZERO));
+        assert_eq!(playback.frame.get(), 0);
+        assert!(!playback.state.lock().running);
+        assert!(!playback.state.lock().finished);
+
+        // Dropping the texture also stops playback.
+        let (playback, texture) = self::playback(&[100, 100], LoopCount::Forever);
+        drop(texture);
+        assert_eq!(step(&playback, 150), ControlFlow::Break(Duration::ZERO));
+        assert_eq!(playback.frame.get(), 0);
+    }
+
+    #[test]
+    fn loop_count() {
+        let (playback, _texture) = playback(&[100, 100], LoopCount::Times(2));
+        playback.state.lock().running = true;
+
+        assert_eq!(step(&playback, 100), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 1);
+        assert_eq!(step(&playback, 100), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 0);
+        assert_eq!(step(&playback, 100), ControlFlow::Continue(()));
+        assert_eq!(playback.frame.get(), 1);
+
+        // Completing the last loop stops on the last frame.
+        assert_eq!(step(&playback, 150), ControlFlow::Break(Duration::ZERO));
+        assert_eq!(playback.frame.get(), 1);
+        assert!(!playback.playing.get());
+        let state = playback.state.lock();
+        assert!(state.finished);
+        assert!(!state.running);
+        assert_eq!(state.completed_loops, 2);
+    }
+}
@@ -59,6 +59,13 @@ pub trait IndicatorBehavior: Send + Debug + 'static {
     );
     /// Returns the size of this indicator.
     fn size(&self, context: &mut GraphicsContext<'_, '_, '_, '_>) -> Size<UPx>;
+    /// Returns the accessibility node describing this indicator.
+    ///
+    /// [`Indicator`] adds the actions and label for the returned node.
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&self) -> Option<accesskit::Node> {
+        None
+    }
 }
 
 /// The current state of an [`Indicator`] widget.
@@ -395,4 +402,14 @@ where
         }
         self.update_colors(context, true);
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&mut self, _context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        let mut node = self.behavior.accessibility_node()?;
+        node.add_action(accesskit::Action::Click);
+        if self.focusable {
+            node.add_action(accesskit::Action::Focus);
+        }
+        Some(node)
+    }
 }
@@ -27,6 +27,7 @@ use crate::styles::components::{HighlightColor, IntrinsicPadding, OutlineColor,
 use crate::utils::ModifiersExt;
 use crate::value::{Destination, Dynamic, Generation, IntoDynamic, IntoValue, Source, Value};
 use crate::widget::{Callback, EventHandling, Widget, HANDLED, IGNORED};
+use crate::widgets::scroll::ScrollIntoView;
 use crate::window::KeyEvent;
 use crate::{ConstraintLimit, FitMeasuredSize, Lazy};
 
@@ -42,6 +43,7 @@ pub struct Input<Storage = String> {
     mask_symbol: Value<CowString>,
     mask: CowString,
     on_key: Option<Callback<KeyEvent, EventHandling>>,
+    on_blur: Option<Callback>,
     cache: Option<CachedLayout>,
     selection: SelectionState,
     blink_state: BlinkState,
@@ -49,6 +51,10 @@ pub struct Input<Storage = String> {
     mouse_buttons_down: usize,
     line_navigation_x_target: Option<Px>,
     window_focused: bool,
+    multiline: bool,
+    visible_height: Px,
+    needs_cursor_visible: bool,
+    history: EditHistory,
 }
 
 #[derive(Eq, PartialEq, Clone, Copy)]
@@ -118,13 +124,31 @@ where
             blink_state: BlinkState::default(),
             selection: SelectionState::default(),
             on_key: None,
+            on_blur: None,
             mouse_buttons_down: 0,
             needs_to_select_all: false,
             line_navigation_x_target: None,
             window_focused: false,
+            multiline: false,
+            visible_height: Px::ZERO,
+            needs_cursor_visible: false,
+            history: EditHistory::default(),
         }
     }
 
+    /// Enables editing multiple lines of text.
+    ///
+    /// In multi-line mode, pressing Enter inserts a new line rather than
+    /// activating the window's default widget, and Page Up/Page Down move the
+    /// cursor a page at a time. Text is soft-wrapped at word boundaries to fit
+    /// the available width. When the input is placed inside of a
+    /// [`Scroll`](crate::widgets::Scroll), the scroll will follow the cursor as
+    /// it moves.
+    pub fn multiline(mut self) -> Self {
+        self.multiline = true;
+        self
+    }
+
     /// Sets the `placeholder` text, which is displayed when the field has an
     /// empty value.
     pub fn placeholder(mut self, placeholder: impl IntoValue<String>) -> Self {
@@ -157,6 +181,17 @@ where
         self
     }
 
+    /// Sets the `on_blur` callback.
+    ///
+    /// This function is called each time this widget loses focus.
+    pub fn on_blur<F>(mut self, mut on_blur: F) -> Self
+    where
+        F: FnMut() + Send + 'static,
+    {
+        self.on_blur = Some(Callback::new(move |()| on_blur()));
+        self
+    }
+
     fn select_all(&mut self) {
         self.value.map_ref(|value| {
             let text = value.as_str();
@@ -234,9 +269,13 @@ where
         mode: CursorNavigationMode,
         context: &mut EventContext<'_>,
     ) {
-        if !matches!(mode, CursorNavigationMode::Line) {
+        if !matches!(
+            mode,
+            CursorNavigationMode::Line | CursorNavigationMode::Page
+        ) {
             self.line_navigation_x_target = None;
         }
+        self.history.last_edit = None;
 
         // @ecton: After a lot of thought, it seems like the only way for
         // affinity to be switched to After is via dragging the mouse.
@@ -244,7 +283,11 @@ where
         match mode {
             CursorNavigationMode::Grapheme => self.move_cursor_by_grapheme(direction),
             CursorNavigationMode::Word => self.move_cursor_by_word(direction),
-            CursorNavigationMode::Line => self.move_cursor_by_line(direction, context),
+            CursorNavigationMode::Line => self.move_cursor_by_line(direction, 1, context),
+            CursorNavigationMode::Page => {
+                let lines = self.lines_per_page(context);
+                self.move_cursor_by_line(direction, lines, context);
+            }
             CursorNavigationMode::LineExtent => self.move_cursor_by_line_extent(direction, context),
         }
     }
@@ -336,10 +379,16 @@ where
         self.selection.cursor = self.cursor_from_point(position, context);
     }
 
-    fn move_cursor_by_line(&mut self, affinity: Affinity, context: &mut EventContext<'_>) {
+    fn move_cursor_by_line(
+        &mut self,
+        affinity: Affinity,
+        lines: i32,
+        context: &mut EventContext<'_>,
+    ) {
         let Some(cache) = self.cache.as_ref() else {
             return;
         };
+        let distance = cache.measured.line_height.saturating_mul(Px::new(lines));
 
         let (mut position, _) = self.point_from_cursor(cache, self.selection.cursor, cache.bytes);
         position += Point::squared(
@@ -354,15 +403,67 @@ where
             self.line_navigation_x_target = Some(position.x);
         }
         match affinity {
-            Affinity::Before => position.y -= cache.measured.line_height,
+            Affinity::Before => position.y -= distance,
             Affinity::After => {
-                position.y += cache.measured.line_height;
+                position.y += distance;
             }
         };
 
         self.selection.cursor = self.cursor_from_point(position, context);
     }
 
+    fn lines_per_page(&self, context: &EventContext<'_>) -> i32 {
+        let Some(cache) = self.cache.as_ref() else {
+            return 1;
+        };
+
+        let visible_height = if self.visible_height > Px::ZERO {
+            self.visible_height
+        } else {
+            context
+                .last_layout()
+                .map_or(Px::ZERO, |layout| layout.size.height)
+        };
+        (visible_height.get() / cache.measured.line_height.get().max(1)).max(1)
+    }
+
+    /// Asks any scrolling ancestors to show the cursor.
+    ///
+    /// Returns false if the cached layout is out of date, in which case the
+    /// cursor can only be located after the next layout.
+    fn scroll_cursor_into_view(&self, context: &mut EventContext<'_>) -> bool {
+        let Some(cache) = &self.cache else {
+            return false;
+        };
+        if cache.key.generation != self.value.generation() {
+            return false;
+        }
+
+        let info = self.cache_info();
+        let cursor = if info.masked {
+            if cache.key.cursor != self.selection.cursor {
+                return false;
+            }
+            info.cursor
+        } else {
+            self.selection.cursor
+        };
+        let (location, _) = self.point_from_cursor(info.cache, cursor, info.cache.bytes);
+        let scale = context.kludgine.scale();
+        let padding = context.get(&IntrinsicPadding).into_px(scale).round();
+        context.scroll_region_into_view(
+            Rect::new(
+                location + Point::squared(padding),
+                Size::new(
+                    Lp::points(2).into_px(scale),
+                    info.cache.measured.line_height,
+                ),
+            ),
+            ScrollIntoView::new(),
+        );
+        true
+    }
+
     fn constrain_selection(&mut self) {
         let length = self.value.map_ref(|s| s.as_str().len());
         self.selection.cursor.offset = self.selection.cursor.offset.min(length);
@@ -441,6 +542,89 @@ where
         };
     }
 
+    /// Invokes `edit`, recording the state of this input before the edit in
+    /// its undo history.
+    ///
+    /// Consecutive edits of the same kind are grouped into a single step,
+    /// unless the selection or value was changed in between them.
+    fn edit_with_history(
+        &mut self,
+        kind: EditKind,
+        context: &mut EventContext<'_>,
+        edit: impl FnOnce(&mut Self, &mut EventContext<'_>),
+    ) {
+        let generation = self.value.generation();
+        let grouped = kind != EditKind::Other
+            && self.history.last_edit == Some(kind)
+            && self.history.generation == Some(generation)
+            && self
+                .selection
+                .start
+                .map_or(true, |start| start == self.selection.cursor);
+        let before = (!grouped).then(|| self.snapshot());
+
+        edit(self, context);
+
+        let generation_after = self.value.generation();
+        if generation_after == generation {
+            return;
+        }
+        if let Some(before) = before {
+            if self.history.undo.len() == MAX_UNDO_STEPS {
+                self.history.undo.remove(0);
+            }
+            self.history.undo.push(before);
+        }
+        self.history.redo.clear();
+        self.history.last_edit = Some(kind);
+        self.history.generation = Some(generation_after);
+    }
+
+    fn snapshot(&self) -> EditSnapshot {
+        EditSnapshot {
+            text: self
+                .value
+                .map_ref(|value| Zeroizing::new(value.as_str().to_string())),
+            selection: self.selection,
+        }
+    }
+
+    fn restore(&mut self, snapshot: EditSnapshot) {
+        self.value.map_mut(|mut value| {
+            let value = value.as_string_mut();
+            value.clear();
+            value.push_str(&snapshot.text);
+        });
+        self.selection = snapshot.selection;
+        self.constrain_selection();
+        self.history.last_edit = None;
+        self.history.generation = Some(self.value.generation());
+    }
+
+    fn undo(&mut self, context: &EventContext<'_>) {
+        if !context.enabled() {
+            return;
+        }
+
+        if let Some(snapshot) = self.history.undo.pop() {
+            let current = self.snapshot();
+            self.restore(snapshot);
+            self.history.redo.push(current);
+        }
+    }
+
+    fn redo(&mut self, context: &EventContext<'_>) {
+        if !context.enabled() {
+            return;
+        }
+
+        if let Some(snapshot) = self.history.redo.pop() {
+            let current = self.snapshot();
+            self.restore(snapshot);
+            self.history.undo.push(current);
+        }
+    }
+
     fn paste_from_clipboard(&mut self, context: &mut EventContext<'_>) -> bool {
         if !context.enabled() {
             return false;
@@ -467,16 +651,18 @@ where
         match (input.state, input.logical_key, input.text.as_deref()) {
             (ElementState::Pressed,  Key::Named(key @ (NamedKey::Backspace| NamedKey::Delete)), _) => {
                 match key {
-                    NamedKey::Backspace => self.delete(context),
-                    NamedKey::Delete => self.forward_delete(context),
+                    NamedKey::Backspace => self.edit_with_history(EditKind::Deleting, context, Self::delete),
+                    NamedKey::Delete => self.edit_with_history(EditKind::Deleting, context, Self::forward_delete),
                     _ => unreachable!("previously matched"),
                 }
 
                 HANDLED
             }
-            (ElementState::Pressed, Key::Named(key @ (NamedKey::ArrowLeft | NamedKey::ArrowDown | NamedKey::ArrowUp | NamedKey::ArrowRight | NamedKey::Home | NamedKey::End)), _) => {
+            (ElementState::Pressed, Key::Named(key @ (NamedKey::ArrowLeft | NamedKey::ArrowDown | NamedKey::ArrowUp | NamedKey::ArrowRight | NamedKey::Home | NamedKey::End | NamedKey::PageUp | NamedKey::PageDown)), _)
+                if self.multiline || !matches!(key, NamedKey::PageUp | NamedKey::PageDown) =>
+            {
                 let modifiers = context.modifiers();
-                let affinity = if matches!(key, NamedKey::ArrowLeft | NamedKey::ArrowUp | NamedKey::Home) {
+                let affinity = if matches!(key, NamedKey::ArrowLeft | NamedKey::ArrowUp | NamedKey::Home | NamedKey::PageUp) {
                     Affinity::Before
                 } else {
                     Affinity::After
@@ -504,6 +690,7 @@ where
                     NamedKey::ArrowLeft | NamedKey::ArrowRight if modifiers.word_select() => self.move_cursor(affinity, CursorNavigationMode::Word, context),
                     NamedKey::ArrowLeft | NamedKey::ArrowRight => self.move_cursor(affinity, CursorNavigationMode::Grapheme, context),
                     NamedKey::ArrowDown | NamedKey::ArrowUp => self.move_cursor(affinity, CursorNavigationMode::Line, context),
+                    NamedKey::PageDown | NamedKey::PageUp => self.move_cursor(affinity, CursorNavigationMode::Page, context),
                     _ => tracing::warn!("unhandled key: {key:?}"),
                 }
 
@@ -524,11 +711,40 @@ where
             }
             (state, _, Some("v")) if context.modifiers().primary() => {
                 if state.is_pressed() {
-                    self.paste_from_clipboard(context);
+                    self.edit_with_history(EditKind::Other, context, |this, context| {
+                        this.paste_from_clipboard(context);
+                    });
                 }
 
                 HANDLED
             }
+            (state, _, Some(key @ ("z" | "Z"))) if context.modifiers().primary() => {
+                if state.is_pressed() {
+                    if key == "Z" || context.modifiers().state().shift_key() {
+                        self.redo(context);
+                    } else {
+                        self.undo(context);
+                    }
+                }
+                HANDLED
+            }
+            #[cfg(not(any(target_os = "ios", target_os = "macos")))]
+            (state, _, Some("y")) if context.modifiers().primary() => {
+                if state.is_pressed() {
+                    self.redo(context);
+                }
+                HANDLED
+            }
+            (state, Key::Named(NamedKey::Enter), _)
+                if self.multiline && !context.modifiers().primary() =>
+            {
+                if state.is_pressed() {
+                    self.edit_with_history(EditKind::Other, context, |this, context| {
+                        this.replace_selection("\n", context);
+                    });
+                }
+                HANDLED
+            }
             (state, _, Some(text))
                 if !context.modifiers().primary()
                     && text != "\t" // tab
@@ -537,7 +753,9 @@ where
                     =>
             {
                 if state.is_pressed() {
-                    self.replace_selection(text, context);
+                    self.edit_with_history(EditKind::Typing, context, |this, context| {
+                        this.replace_selection(text, context);
+                    });
                 }
                 HANDLED
             }
@@ -659,6 +877,19 @@ where
         cursor: Cursor,
         total_bytes: usize,
     ) -> (Point<Px>, Px) {
+        if let Some(line) = self.line_after_newline(cache, cursor) {
+            return (
+                Point::new(
+                    Px::ZERO,
+                    cache
+                        .measured
+                        .line_height
+                        .saturating_mul(Px::new(i32::try_from(line).unwrap_or(i32::MAX))),
+                ),
+                Px::ZERO,
+            );
+        }
+
         if cache.measured.glyphs.is_empty()
             || (cursor.offset == 0 && cursor.affinity == Affinity::Before)
         {
@@ -802,6 +1033,65 @@ where
         }
     }
 
+    /// Returns the visual line `cursor` is on if it is placed immediately after
+    /// a hard line break.
+    ///
+    /// Line breaks have no glyphs, which prevents the glyph-based
+    /// approximations from placing the cursor at the start of the next line.
+    fn line_after_newline(&self, cache: &CachedLayout, cursor: Cursor) -> Option<usize> {
+        if !self.multiline || cache.key.mask_bytes > 0 {
+            return None;
+        }
+
+        self.value.map_ref(|value| {
+            let text = value.as_str();
+            if !text.get(..cursor.offset)?.ends_with('\n') {
+                return None;
+            }
+
+            let previous = cache
+                .measured
+                .glyphs
+                .iter()
+                .filter(|glyph| glyph.info.end <= cursor.offset)
+                .max_by_key(|glyph| (glyph.info.line, glyph.info.end));
+            let (line, search_from) =
+                previous.map_or((0, 0), |glyph| (glyph.info.line, glyph.info.end));
+            Some(line + text[search_from..cursor.offset].matches('\n').count())
+        })
+    }
+
+    /// Returns the cursor at the start of `line` if the line contains no
+    /// glyphs because it is an empty line between hard line breaks.
+    fn cursor_on_empty_line(&self, cache: &CachedLayout, line: usize) -> Option<Cursor> {
+        if !self.multiline || cache.key.mask_bytes > 0 {
+            return None;
+        }
+
+        let previous = cache
+            .measured
+            .glyphs
+            .iter()
+            .filter(|glyph| glyph.info.line < line)
+            .max_by_key(|glyph| (glyph.info.line, glyph.info.end));
+        let (newlines, search_from) =
+            previous.map_or((line, 0), |glyph| (line - glyph.info.line, glyph.info.end));
+        let Some(skip) = newlines.checked_sub(1) else {
+            return Some(Cursor::default());
+        };
+
+        self.value.map_ref(|value| {
+            let text = value.as_str();
+            text.get(search_from..)?
+                .match_indices('\n')
+                .nth(skip)
+                .map(|(index, _)| Cursor {
+                    offset: search_from + index + 1,
+                    affinity: Affinity::Before,
+                })
+        })
+    }
+
     fn cursor_from_point(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> Cursor {
         let mut cursor = self.cached_cursor_from_point(location, context);
         if let Some(symbol) = self.mask.graphemes(true).next() {
@@ -917,10 +1207,17 @@ where
             // so that it's easier to inspect and detect when there's
             // whitespace. For now, this is just a hack that helps get *some*
             // selection at the end of the input for trailing whitespace.
-            if relative.x.abs() < cache.measured.line_height && index < cache.measured.glyphs.len()
+            if (self.multiline || relative.x.abs() < cache.measured.line_height)
+                && index < cache.measured.glyphs.len()
             {
                 return closest;
             }
+        } else if let Some(cursor) =
+            usize::try_from(location.y.get() / cache.measured.line_height.get().max(1))
+                .ok()
+                .and_then(|line| self.cursor_on_empty_line(cache, line))
+        {
+            return cursor;
         }
 
         Cursor {
@@ -930,6 +1227,29 @@ where
     }
 }
 
+/// The maximum number of steps kept in an [`Input`]'s undo history.
+const MAX_UNDO_STEPS: usize = 1_000;
+
+#[derive(Default)]
+struct EditHistory {
+    undo: Vec<EditSnapshot>,
+    redo: Vec<EditSnapshot>,
+    last_edit: Option<EditKind>,
+    generation: Option<Generation>,
+}
+
+struct EditSnapshot {
+    text: Zeroizing<String>,
+    selection: SelectionState,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum EditKind {
+    Typing,
+    Deleting,
+    Other,
+}
+
 struct CacheInfo<'a> {
     cache: &'a CachedLayout,
     masked: bool,
@@ -943,6 +1263,7 @@ enum CursorNavigationMode {
     Word,
     LineExtent,
     Line,
+    Page,
     // Document,
 }
 
@@ -981,6 +1302,7 @@ where
         self.mouse_buttons_down += 1;
         context.focus();
         self.needs_to_select_all = false;
+        self.history.last_edit = None;
         self.selection.cursor = self.cursor_from_point(location, context);
         self.selection.start = Some(self.selection.cursor);
         context.set_needs_redraw();
@@ -1045,6 +1367,10 @@ where
             .into_px(context.gfx.scale())
             .round();
         let padding = Point::squared(padding);
+        self.visible_height = context
+            .gfx
+            .visible_rect()
+            .map_or(Px::ZERO, |visible| visible.size.height.into_signed());
 
         self.layout_text(Some(size.width.into_signed()), context);
         let info = self.cache_info();
@@ -1175,6 +1501,11 @@ where
         let width = available_space.width.max().saturating_sub(padding * 2);
 
         self.layout_text(Some(width.into_signed()), &mut context.graphics);
+        if self.needs_cursor_visible
+            && self.scroll_cursor_into_view(&mut context.as_event_context())
+        {
+            self.needs_cursor_visible = false;
+        }
         let info = self.cache_info();
 
         let measured_size = info
@@ -1201,6 +1532,7 @@ where
         let handled = self.handle_key(input, context);
 
         if handled.is_break() {
+            self.needs_cursor_visible = self.multiline && !self.scroll_cursor_into_view(context);
             context.set_needs_redraw();
         }
 
@@ -1216,7 +1548,11 @@ where
                 tracing::warn!("TODO: preview IME input {text}, cursor: {cursor:?}");
             }
             Ime::Commit(text) => {
-                self.replace_selection(&text, context);
+                self.edit_with_history(EditKind::Typing, context, |this, context| {
+                    this.replace_selection(&text, context);
+                });
+                self.needs_cursor_visible =
+                    self.multiline && !self.scroll_cursor_into_view(context);
                 context.set_needs_redraw();
             }
         }
@@ -1241,6 +1577,33 @@ where
     fn blur(&mut self, context: &mut EventContext<'_>) {
         context.set_ime_allowed(false);
         context.set_needs_redraw();
+        if let Some(on_blur) = &mut self.on_blur {
+            on_blur.invoke(());
+        }
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(
+        &mut self,
+        _context: &mut crate::context::WidgetContext<'_>,
+    ) -> Option<accesskit::Node> {
+        let mut node = if self.is_masked() {
+            accesskit::Node::new(accesskit::Role::PasswordInput)
+        } else {
+            let mut node = accesskit::Node::new(if self.multiline {
+                accesskit::Role::MultilineTextInput
+            } else {
+                accesskit::Role::TextInput
+            });
+            node.set_value(self.value.map_ref(|value| value.as_str().to_string()));
+            node
+        };
+        let placeholder = self.placeholder.get();
+        if !placeholder.is_empty() {
+            node.set_placeholder(placeholder);
+        }
+        node.add_action(accesskit::Action::Focus);
+        Some(node)
     }
 }
 
@@ -1550,3 +1913,188 @@ macro_rules! impl_cow_string {
 
 impl_cow_string!(CowString, false);
 impl_cow_string!(MaskedString, true);
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+    use figures::Size;
+    use kludgine::app::winit::event::Modifiers;
+    use kludgine::app::winit::keyboard::{Key, ModifiersState, NamedKey};
+
+    use super::Input;
+    use crate::utils::ModifiersStateExt;
+    use crate::value::{Dynamic, Source};
+    use crate::widget::{MakeWidget, MountedWidget};
+    use crate::window::VirtualRecorder;
+
+    fn press(recorder: &mut VirtualRecorder, key: NamedKey) {
+        let _ = recorder.window.press_key(key);
+        recorder.refresh().expect("error refreshing");
+    }
+
+    fn shortcut(recorder: &mut VirtualRecorder, key: &str, modifiers: ModifiersState) {
+        recorder.window.set_modifiers(Modifiers::from(modifiers));
+        let _ = recorder.window.press_key(Key::Character(key.into()));
+        recorder.window.set_modifiers(Modifiers::default());
+        recorder.refresh().expect("error refreshing");
+    }
+
+    fn cursor_offset(input: &MountedWidget) -> usize {
+        input
+            .lock()
+            .downcast_ref::<Input<String>>()
+            .expect("input widget")
+            .selection
+            .cursor
+            .offset
+    }
+
+    fn cursor_line(input: &MountedWidget, text: &Dynamic<String>) -> usize {
+        let offset = input
+            .lock()
+            .downcast_ref::<Input<String>>()
+            .expect("input widget")
+            .selection
+            .cursor
+            .offset;
+        text.map_ref(|text| text[..offset].matches('\n').count())
+    }
+
+    fn focused_recorder(contents: impl MakeWidget) -> (VirtualRecorder, MountedWidget) {
+        let mut recorder = contents
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        let input = recorder
+            .window
+            .find_widget::<Input<String>>()
+            .expect("input widget");
+        recorder.window.focus(&input);
+        recorder.refresh().expect("error refreshing");
+        (recorder, input)
+    }
+
+    #[test]
+    fn multiline_editing() {
+        let text = Dynamic::new(String::new());
+        let (mut recorder, input) = focused_recorder(text.clone().into_input().multiline());
+
+        recorder.window.type_text(&input, "Hello");
+        press(&mut recorder, NamedKey::Enter);
+        recorder.window.type_text(&input, "World");
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(text.get(), "Hello\nWorld");
+        assert_eq!(cursor_line(&input, &text), 1);
+
+        // Moving up a line places the cursor at the end of the shorter line.
+        press(&mut recorder, NamedKey::ArrowUp);
+        assert_eq!(cursor_line(&input, &text), 0);
+        recorder.window.type_text(&input, "!");
+        assert_eq!(text.get(), "Hello!\nWorld");
+    }
+
+    #[test]
+    fn single_line_ignores_enter() {
+        let text = Dynamic::new(String::new());
+        let (mut recorder, input) = focused_recorder(text.clone().into_input());
+
+        recorder.window.type_text(&input, "Hello");
+        press(&mut recorder, NamedKey::Enter);
+        press(&mut recorder, NamedKey::PageUp);
+        assert_eq!(text.get(), "Hello");
+    }
+
+    #[test]
+    fn page_navigation() {
+        let text = Dynamic::new(
+            (0..50)
+                .map(|line| format!("Line {line}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+        let scroll = text.clone().into_input().multiline().vertical_scroll();
+        let offset = scroll.scroll.clone();
+        let (mut recorder, input) = focused_recorder(scroll);
+
+        // Focusing selects all of the text. Page Up collapses the selection
+        // to its start.
+        press(&mut recorder, NamedKey::PageUp);
+        assert_eq!(cursor_line(&input, &text), 0);
+        assert_eq!(offset.get().y, UPx::ZERO);
+
+        press(&mut recorder, NamedKey::PageDown);
+        let page = cursor_line(&input, &text);
+        assert!(page > 1 && page < 49, "page down moved to line {page}");
+
+        // Paging through the text scrolls the cursor into view.
+        for _ in 0..50 / page {
+            press(&mut recorder, NamedKey::PageDown);
+        }
+        assert_eq!(cursor_line(&input, &text), 49);
+        assert!(offset.get().y > UPx::ZERO);
+
+        press(&mut recorder, NamedKey::PageUp);
+        assert_eq!(cursor_line(&input, &text), 49 - page);
+        for _ in 0..50 / page {
+            press(&mut recorder, NamedKey::PageUp);
+        }
+        assert_eq!(cursor_line(&input, &text), 0);
+        assert_eq!(offset.get().y, UPx::ZERO);
+    }
+
+    #[test]
+    fn undo_redo() {
+        let text = Dynamic::new(String::new());
+        let (mut recorder, input) = focused_recorder(text.clone().into_input());
+
+        // Consecutive typing is grouped into a single step, and switching to
+        // deleting begins a new one.
+        recorder.window.type_text(&input, "Hello");
+        press(&mut recorder, NamedKey::Backspace);
+        press(&mut recorder, NamedKey::Backspace);
+        recorder.window.type_text(&input, "p!");
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(text.get(), "Help!");
+
+        shortcut(&mut recorder, "z", ModifiersState::PRIMARY);
+        assert_eq!(text.get(), "Hel");
+        assert_eq!(cursor_offset(&input), 3);
+        shortcut(&mut recorder, "z", ModifiersState::PRIMARY);
+        assert_eq!(text.get(), "Hello");
+        assert_eq!(cursor_offset(&input), 5);
+        shortcut(&mut recorder, "z", ModifiersState::PRIMARY);
+        assert_eq!(text.get(), "");
+        // Undoing with an empty history does nothing.
+        shortcut(&mut recorder, "z", ModifiersState::PRIMARY);
+        assert_eq!(text.get(), "");
+
+        shortcut(
+            &mut recorder,
+            "Z",
+            ModifiersState::PRIMARY | ModifiersState::SHIFT,
+        );
+        assert_eq!(text.get(), "Hello");
+        #[cfg(not(any(target_os = "ios", target_os = "macos")))]
+        shortcut(&mut recorder, "y", ModifiersState::PRIMARY);
+        #[cfg(any(target_os = "ios", target_os = "macos"))]
+        shortcut(
+            &mut recorder,
+            "Z",
+            ModifiersState::PRIMARY | ModifiersState::SHIFT,
+        );
+        assert_eq!(text.get(), "Hel");
+
+        // A new edit discards the steps that could be redone.
+        recorder.window.type_text(&input, "m");
+        recorder.refresh().expect("error refreshing");
+        shortcut(
+            &mut recorder,
+            "Z",
+            ModifiersState::PRIMARY | ModifiersState::SHIFT,
+        );
+        assert_eq!(text.get(), "Helm");
+        shortcut(&mut recorder, "z", ModifiersState::PRIMARY);
+        assert_eq!(text.get(), "Hel");
+    }
+}
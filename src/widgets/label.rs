@@ -2,24 +2,40 @@
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Write};
+use std::time::Instant;
 
 use figures::units::{Px, UPx};
-use figures::{IntoUnsigned, Point, Round, Size, Zero};
+use figures::{IntoUnsigned, Point, Rect, Round, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::shapes::Shape;
 use kludgine::text::{MeasuredText, Text, TextOrigin};
 use kludgine::{cosmic_text, CanRenderTo, Color, DrawableExt};
+use unicode_segmentation::UnicodeSegmentation;
 
-use super::input::CowString;
-use crate::context::{FontSettings, GraphicsContext, LayoutContext, Trackable, WidgetContext};
-use crate::styles::components::{HorizontalAlignment, TextColor, VerticalAlignment};
+use super::input::{Affinity, CowString, Cursor, SelectionState};
+use crate::context::{
+    EventContext, FontSettings, GraphicsContext, LayoutContext, Trackable, WidgetContext,
+};
+use crate::styles::components::{
+    HighlightColor, HorizontalAlignment, TextColor, VerticalAlignment,
+};
 use crate::styles::{HorizontalAlign, VerticalAlign};
+use crate::utils::ModifiersExt;
 use crate::value::{
     Dynamic, DynamicReader, Generation, IntoDynamic, IntoReadOnly, IntoValue, ReadOnly, Value,
 };
-use crate::widget::{MakeWidgetWithTag, Widget, WidgetInstance, WidgetTag};
-use crate::window::WindowLocal;
+use crate::widget::{
+    EventHandling, MakeWidgetWithTag, Widget, WidgetInstance, WidgetTag, HANDLED, IGNORED,
+};
+use crate::window::{DeviceId, KeyEvent, WindowLocal};
 use crate::{ConstraintLimit, FitMeasuredSize};
 
 /// A read-only text widget.
+///
+/// By default, the text of a label cannot be selected. When
+/// [selectable](Self::selectable), the text can be selected using the mouse
+/// and copied to the clipboard.
 #[derive(Debug)]
 pub struct Label<T> {
     /// The contents of the label.
@@ -29,6 +45,11 @@ pub struct Label<T> {
     pub overflow: Value<LabelOverflow>,
     displayed: String,
     prepared_text: WindowLocal<LabelCache>,
+    selectable: bool,
+    selection: SelectionState,
+    drag: Option<SelectionDrag>,
+    last_click: Option<Instant>,
+    click_count: usize,
 }
 
 impl<T> Label<T>
@@ -43,9 +64,30 @@ where
             overflow: Value::Constant(LabelOverflow::WordWrap),
             displayed: String::new(),
             prepared_text: WindowLocal::default(),
+            selectable: false,
+            selection: SelectionState::default(),
+            drag: None,
+            last_click: None,
+            click_count: 0,
         }
     }
 
+    /// Allows the text of this label to be selected and copied.
+    ///
+    /// Text is selected by dragging the mouse. Double-clicking selects a word,
+    /// and triple-clicking selects a line, with the time allowed between
+    /// clicks controlled by [`Cushy::multi_click_threshold`]. Once focused,
+    /// the primary shortcut modifier (Ctrl or Command) with A selects all of
+    /// the text, and with C copies the selected text to the clipboard. The
+    /// selection is drawn using [`HighlightColor`].
+    ///
+    /// [`Cushy::multi_click_threshold`]: crate::Cushy::multi_click_threshold
+    #[must_use]
+    pub fn selectable(mut self) -> Self {
+        self.selectable = true;
+        self
+    }
+
     /// Sets the behavior when more text than can fit on a single line is
     /// displayed.
     #[must_use]
@@ -103,8 +145,10 @@ where
                     LabelCache {
                         text: measured,
                         key: cache_key,
+                        y_offset: Px::ZERO,
                     },
                 );
+                self.clamp_selection();
             }
         }
 
@@ -113,6 +157,171 @@ where
             .map(|cache| &cache.text)
             .expect("always initialized")
     }
+
+    /// Clears the selection if it is no longer valid for the displayed text.
+    fn clamp_selection(&mut self) {
+        let valid = |cursor: Cursor| self.displayed.is_char_boundary(cursor.offset);
+        if !valid(self.selection.cursor) || !self.selection.start.map_or(true, valid) {
+            self.selection = SelectionState::default();
+            self.drag = None;
+        }
+    }
+
+    fn selected_range(&self) -> Option<(usize, usize)> {
+        let start = self.selection.start?.offset;
+        let end = self.selection.cursor.offset;
+        match start.cmp(&end) {
+            std::cmp::Ordering::Less => Some((start, end)),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some((end, start)),
+        }
+    }
+
+    fn select(&mut self, start: usize, end: usize) {
+        self.selection.start = Some(Cursor {
+            offset: start,
+            affinity: Affinity::Before,
+        });
+        self.selection.cursor = Cursor {
+            offset: end,
+            affinity: Affinity::Before,
+        };
+    }
+
+    fn select_all(&mut self) {
+        self.select(0, self.displayed.len());
+    }
+
+    /// Updates the selection to include the anchor of the current drag and
+    /// the text at `location`.
+    fn drag_selection_to(&mut self, location: Point<Px>, context: &EventContext<'_>) {
+        let Some(drag) = self.drag else {
+            return;
+        };
+        let offset = self.offset_from_point(location, context);
+        let (start, end) = drag.granularity.range_at(&self.displayed, offset);
+        if start < drag.anchor.0 {
+            self.select(drag.anchor.1, start);
+        } else {
+            self.select(drag.anchor.0, end.max(drag.anchor.1));
+        }
+    }
+
+    fn selected_text(&self) -> Option<&str> {
+        self.selected_range()
+            .map(|(start, end)| &self.displayed[start..end])
+    }
+
+    fn copy_selection_to_clipboard(&self, context: &mut EventContext<'_>) {
+        let Some(selected) = self.selected_text() else {
+            return;
+        };
+        if let Some(mut clipboard) = context.cushy().clipboard_guard() {
+            match clipboard.set_text(selected) {
+                Ok(()) => {}
+                Err(err) => tracing::error!("error copying to clipboard: {err}"),
+            }
+        }
+    }
+
+    /// Returns the byte offset of the text closest to `location`.
+    fn offset_from_point(&self, location: Point<Px>, context: &WidgetContext<'_>) -> usize {
+        let Some(cache) = self.prepared_text.get(context) else {
+            return 0;
+        };
+        let y = location.y - cache.y_offset;
+        if y < 0 {
+            return 0;
+        }
+        let line =
+            usize::try_from(y.get() / cache.text.line_height.get().max(1)).unwrap_or_default();
+        let last_line = cache
+            .text
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.info.line)
+            .max()
+            .unwrap_or_default();
+        if line > last_line {
+            return self.displayed.len();
+        }
+
+        // Lines that contain no glyphs use the glyphs from the closest line.
+        let Some(closest_line) = cache
+            .text
+            .glyphs
+            .iter()
+            .map(|glyph| glyph.info.line)
+            .min_by_key(|glyph_line| glyph_line.abs_diff(line))
+        else {
+            return 0;
+        };
+
+        let mut offset = None;
+        for glyph in cache
+            .text
+            .glyphs
+            .iter()
+            .filter(|glyph| glyph.info.line == closest_line)
+        {
+            let rect = glyph.rect();
+            if location.x < rect.origin.x {
+                return offset.unwrap_or(glyph.info.start);
+            } else if location.x < rect.origin.x + rect.size.width {
+                return if location.x - rect.origin.x > rect.size.width / 2 {
+                    glyph.info.end
+                } else {
+                    glyph.info.start
+                };
+            }
+            offset = Some(glyph.info.end);
+        }
+        offset.unwrap_or_default()
+    }
+}
+
+/// An in-progress mouse selection in a [`Label`].
+#[derive(Debug, Clone, Copy)]
+struct SelectionDrag {
+    granularity: SelectionGranularity,
+    /// The range of text initially selected when the drag began.
+    anchor: (usize, usize),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum SelectionGranularity {
+    Character,
+    Word,
+    Line,
+}
+
+impl SelectionGranularity {
+    fn from_click_count(count: usize) -> Self {
+        match count {
+            0 | 1 => Self::Character,
+            2 => Self::Word,
+            _ => Self::Line,
+        }
+    }
+
+    /// Returns the range of `text` selected by this granularity at `offset`.
+    fn range_at(self, text: &str, offset: usize) -> (usize, usize) {
+        match self {
+            Self::Character => (offset, offset),
+            Self::Word => text
+                .split_word_bound_indices()
+                .map(|(start, word)| (start, start + word.len()))
+                .find(|(start, end)| (*start..*end).contains(&offset))
+                .unwrap_or((offset, offset)),
+            Self::Line => {
+                let start = text[..offset].rfind('\n').map_or(0, |index| index + 1);
+                let end = text[offset..]
+                    .find('\n')
+                    .map_or(text.len(), |index| offset + index);
+                (start, end)
+            }
+        }
+    }
 }
 
 impl<T> Widget for Label<T>
@@ -138,8 +347,31 @@ where
             VerticalAlign::Bottom => context.gfx.region().size.height - prepared_text.size.height,
         };
 
+        let selection = self.selected_range();
+        let cache = self
+            .prepared_text
+            .get_mut(context)
+            .expect("always initialized");
+        cache.y_offset = y_offset;
+
+        if let Some((start, end)) = selection {
+            let highlight = context.get(&HighlightColor);
+            let line_height = cache.text.line_height;
+            for (line, left, right) in selected_line_extents(&cache.text, start, end) {
+                let top =
+                    line_height.saturating_mul(Px::new(i32::try_from(line).unwrap_or(i32::MAX)));
+                context.gfx.draw_shape(&Shape::filled_rect(
+                    Rect::new(
+                        Point::new(left, top + y_offset),
+                        Size::new(right - left, line_height),
+                    ),
+                    highlight,
+                ));
+            }
+        }
+
         context.gfx.draw_measured_text(
-            prepared_text.translate_by(Point::new(Px::ZERO, y_offset)),
+            cache.text.translate_by(Point::new(Px::ZERO, y_offset)),
             TextOrigin::TopLeft,
         );
     }
@@ -157,13 +389,134 @@ where
         available_space.fit_measured(prepared.size.into_unsigned().ceil())
     }
 
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        self.selectable
+    }
+
+    fn accept_focus(&mut self, _context: &mut EventContext<'_>) -> bool {
+        self.selectable
+    }
+
+    fn hover(
+        &mut self,
+        _location: Point<Px>,
+        _context: &mut EventContext<'_>,
+    ) -> Option<CursorIcon> {
+        self.selectable.then_some(CursorIcon::Text)
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if !self.selectable || button != MouseButton::Left {
+            return IGNORED;
+        }
+        context.focus();
+
+        let now = Instant::now();
+        let threshold = context.cushy().multi_click_threshold();
+        self.click_count = match self.last_click {
+            Some(last_click) if now.saturating_duration_since(last_click) < threshold => {
+                self.click_count % 3 + 1
+            }
+            _ => 1,
+        };
+        self.last_click = Some(now);
+
+        let granularity = SelectionGranularity::from_click_count(self.click_count);
+        let offset = self.offset_from_point(location, context);
+        let anchor = granularity.range_at(&self.displayed, offset);
+        self.drag = Some(SelectionDrag {
+            granularity,
+            anchor,
+        });
+        self.select(anchor.0, anchor.1);
+        context.set_needs_redraw();
+        HANDLED
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        let selection = self.selection;
+        self.drag_selection_to(location, context);
+        if selection != self.selection {
+            context.set_needs_redraw();
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        _location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        self.drag = None;
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if !self.selectable || !context.modifiers().primary() {
+            return IGNORED;
+        }
+
+        match input.text.as_deref() {
+            Some("a") => {
+                if input.state.is_pressed() {
+                    self.select_all();
+                    context.set_needs_redraw();
+                }
+                HANDLED
+            }
+            Some("c") => {
+                if input.state.is_pressed() {
+                    self.copy_selection_to_clipboard(context);
+                }
+                HANDLED
+            }
+            _ => IGNORED,
+        }
+    }
+
+    fn blur(&mut self, context: &mut EventContext<'_>) {
+        if self.selection.start.is_some() {
+            self.selection = SelectionState::default();
+            context.set_needs_redraw();
+        }
+    }
+
     fn summarize(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fmt.debug_tuple("Label").field(&self.display).finish()
     }
 
+    fn displayed_text(&self) -> Option<&str> {
+        Some(&self.displayed)
+    }
+
     fn unmounted(&mut self, context: &mut crate::context::EventContext<'_>) {
         self.prepared_text.clear_for(context);
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&mut self, _context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        let mut node = accesskit::Node::new(accesskit::Role::Label);
+        node.set_value(self.displayed.as_str());
+        Some(node)
+    }
 }
 
 macro_rules! impl_make_widget {
@@ -214,6 +567,33 @@ pub enum LabelOverflow {
 struct LabelCache {
     text: MeasuredText<Px>,
     key: LabelCacheKey,
+    y_offset: Px,
+}
+
+/// Returns the line index, left edge, and right edge of the selected glyphs on
+/// each line of `text` between the byte offsets `start` and `end`.
+fn selected_line_extents(
+    text: &MeasuredText<Px>,
+    start: usize,
+    end: usize,
+) -> Vec<(usize, Px, Px)> {
+    let mut extents: Vec<(usize, Px, Px)> = Vec::new();
+    for glyph in text
+        .glyphs
+        .iter()
+        .filter(|glyph| glyph.info.start < end && glyph.info.end > start)
+    {
+        let rect = glyph.rect();
+        let right = rect.origin.x + rect.size.width;
+        match extents.last_mut() {
+            Some((line, left_edge, right_edge)) if *line == glyph.info.line => {
+                *left_edge = (*left_edge).min(rect.origin.x);
+                *right_edge = (*right_edge).max(right);
+            }
+            _ => extents.push((glyph.info.line, rect.origin.x, right)),
+        }
+    }
+    extents
 }
 
 #[derive(Debug)]
@@ -374,3 +754,153 @@ where
         self.into_dynamic().into_reader()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Point, Size};
+    use kludgine::app::winit::event::{ElementState, Modifiers, MouseButton};
+    use kludgine::app::winit::keyboard::{Key, ModifiersState};
+
+    use super::Label;
+    use crate::utils::ModifiersStateExt;
+    use crate::widget::{MakeWidget, MountedWidget, HANDLED, IGNORED};
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    const TEXT: &str = "first line\nthe second line is long enough to wrap";
+
+    struct TestLabel {
+        recorder: VirtualRecorder,
+        label: MountedWidget,
+    }
+
+    impl TestLabel {
+        fn new(label: Label<String>) -> Self {
+            let mut recorder = label
+                .width(Px::new(120))
+                .align_left()
+                .align_top()
+                .build_recorder()
+                .size(Size::new(UPx::new(300), UPx::new(300)))
+                .finish()
+                .expect("error creating recorder");
+            recorder.refresh().expect("error refreshing");
+            let label = recorder
+                .window
+                .find_widget::<Label<String>>()
+                .expect("label");
+            Self { recorder, label }
+        }
+
+        fn selectable() -> Self {
+            Self::new(Label::new(String::from(TEXT)).selectable())
+        }
+
+        fn selected(&self) -> Option<String> {
+            self.label
+                .lock()
+                .downcast_ref::<Label<String>>()
+                .expect("label")
+                .selected_text()
+                .map(String::from)
+        }
+
+        /// Returns a location within the first character of the label.
+        fn start(&self) -> Point<Px> {
+            self.label.last_layout().expect("laid out").origin + Point::new(Px::new(1), Px::new(2))
+        }
+
+        /// Returns a location past the end of the label's last line.
+        fn end(&self) -> Point<Px> {
+            let layout = self.label.last_layout().expect("laid out");
+            layout.origin + Point::new(layout.size.width - 1, layout.size.height - 2)
+        }
+
+        fn mouse_button(&mut self, state: ElementState) {
+            let _ =
+                self.recorder
+                    .window
+                    .mouse_input(DeviceId::Virtual(0), state, MouseButton::Left);
+        }
+
+        fn click(&mut self, location: Point<Px>, count: usize) {
+            self.recorder
+                .window
+                .cursor_moved(DeviceId::Virtual(0), location);
+            for _ in 0..count {
+                self.mouse_button(ElementState::Pressed);
+                self.mouse_button(ElementState::Released);
+            }
+        }
+
+        fn shortcut(&mut self, key: &str) -> crate::widget::EventHandling {
+            let window = &mut self.recorder.window;
+            window.set_modifiers(Modifiers::from(ModifiersState::PRIMARY));
+            let handled = window.press_key(Key::Character(key.into()));
+            window.set_modifiers(Modifiers::default());
+            handled
+        }
+    }
+
+    #[test]
+    fn drag_across_wrapped_lines() {
+        let mut label = TestLabel::selectable();
+        // The text must wrap for this test to cover multiple visual lines.
+        assert!(label.end().y - label.start().y > Px::new(40));
+
+        let (start, end) = (label.start(), label.end());
+        label
+            .recorder
+            .window
+            .cursor_moved(DeviceId::Virtual(0), start);
+        label.mouse_button(ElementState::Pressed);
+        assert_eq!(label.selected(), None);
+        label
+            .recorder
+            .window
+            .cursor_moved(DeviceId::Virtual(0), end);
+        label.mouse_button(ElementState::Released);
+
+        assert_eq!(label.selected().as_deref(), Some(TEXT));
+        assert!(label.label.focused());
+    }
+
+    #[test]
+    fn multiple_clicks() {
+        let mut label = TestLabel::selectable();
+        let start = label.start();
+
+        label.click(start, 2);
+        assert_eq!(label.selected().as_deref(), Some("first"));
+
+        // Triple-clicking selects the line, up to the line break.
+        label.click(start, 1);
+        assert_eq!(label.selected().as_deref(), Some("first line"));
+
+        // A fourth click starts over with a single click.
+        label.click(start, 1);
+        assert_eq!(label.selected(), None);
+    }
+
+    #[test]
+    fn select_all_and_copy() {
+        let mut label = TestLabel::selectable();
+        label.click(label.start(), 1);
+        assert_eq!(label.selected(), None);
+
+        assert_eq!(label.shortcut("a"), HANDLED);
+        assert_eq!(label.selected().as_deref(), Some(TEXT));
+        assert_eq!(label.shortcut("c"), HANDLED);
+        assert_eq!(label.selected().as_deref(), Some(TEXT));
+    }
+
+    #[test]
+    fn not_selectable_by_default() {
+        let mut label = TestLabel::new(Label::new(String::from(TEXT)));
+        label.click(label.start(), 2);
+        assert_eq!(label.selected(), None);
+        assert!(!label.label.focused());
+        assert_eq!(label.shortcut("a"), IGNORED);
+        assert_eq!(label.selected(), None);
+    }
+}
@@ -463,7 +463,13 @@ impl OverlayState {
                     ),
                     true,
                 ),
-                Position::At(pt) => (pt, false),
+                Position::At(pt) => (
+                    Point::new(
+                        flip_within(pt.x, size.width, available_space.width.into_signed()),
+                        flip_within(pt.y, size.height, available_space.height.into_signed()),
+                    ),
+                    false,
+                ),
             };
 
             layout = Rect::new(origin.max(Point::ZERO), size);
@@ -574,6 +580,17 @@ impl OverlayState {
     }
 }
 
+/// Returns the starting coordinate of a `length`-sized span positioned at
+/// `position`, flipping the span to end at `position` if it would otherwise
+/// extend past `available`.
+fn flip_within(position: Px, length: Px, available: Px) -> Px {
+    if position + length > available && position >= length {
+        position - length
+    } else {
+        position
+    }
+}
+
 /// A type that is being prepared to be shown in an [`OverlayLayer`].
 pub trait Overlayable: Sized {
     /// The resulting handle type when this overlay is shown.
@@ -609,6 +626,10 @@ pub trait Overlayable: Sized {
     fn near(self, id: WidgetId, direction: Direction) -> Self;
 
     /// Shows this overlay at a specified window `location`.
+    ///
+    /// When combined with [`parent()`](Self::parent), the overlay is flipped
+    /// to the opposite side of `location` on any axis where it would otherwise
+    /// extend past the edge of the layer.
     #[must_use]
     fn at(self, location: Point<Px>) -> Self;
 
@@ -796,6 +817,17 @@ impl OverlayHandle {
         self.dismiss_on_drop = false;
         drop(self);
     }
+
+    /// Moves this overlay to be shown at the window `location`.
+    pub(crate) fn move_to(&self, location: Point<Px>) {
+        let mut state = self.state.lock();
+        let Some(index) = state.overlays.index_of_id(self.id) else {
+            return;
+        };
+        let overlay = state.overlays.get_mut_by_index(index).assert_expected();
+        overlay.positioning = Position::At(location);
+        overlay.layout = None;
+    }
 }
 
 impl Drop for OverlayHandle {
@@ -1290,3 +1322,81 @@ impl ModalTarget for ModalHandle {
         &self.layer
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Point, Px2D, Size};
+
+    use super::{flip_within, OverlayLayer, Overlayable};
+    use crate::widget::{MakeWidget, MakeWidgetWithTag, WidgetTag};
+    use crate::widgets::Space;
+
+    #[test]
+    fn flipping() {
+        // Fits without flipping.
+        assert_eq!(
+            flip_within(Px::new(10), Px::new(50), Px::new(100)),
+            Px::new(10)
+        );
+        assert_eq!(
+            flip_within(Px::new(50), Px::new(50), Px::new(100)),
+            Px::new(50)
+        );
+        // Flips to end at the position.
+        assert_eq!(
+            flip_within(Px::new(60), Px::new(50), Px::new(100)),
+            Px::new(10)
+        );
+        // Too close to the start to flip.
+        assert_eq!(
+            flip_within(Px::new(30), Px::new(50), Px::new(60)),
+            Px::new(30)
+        );
+    }
+
+    /// Returns the origin of a 50px square overlay shown at `location` in a
+    /// 200px square layer.
+    fn overlay_origin(location: Point<Px>) -> Point<Px> {
+        let overlay = OverlayLayer::default();
+        let (anchor_tag, anchor) = WidgetTag::new();
+        let (square_tag, square) = WidgetTag::new();
+        let mut recorder = Space::clear()
+            .make_with_tag(anchor_tag)
+            .and(overlay.clone())
+            .into_layers()
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+
+        let _handle = overlay
+            .build_overlay(
+                Space::clear()
+                    .size(Size::squared(Px::new(50)))
+                    .make_with_tag(square_tag),
+            )
+            .parent(anchor)
+            .at(location)
+            .show();
+        recorder.refresh().expect("error refreshing");
+
+        recorder
+            .window
+            .widget(square)
+            .and_then(|square| square.last_layout())
+            .expect("overlay should be laid out")
+            .origin
+    }
+
+    #[test]
+    fn at_flips_near_edges() {
+        assert_eq!(overlay_origin(Point::px(20, 20)), Point::px(20, 20));
+        assert_eq!(overlay_origin(Point::px(180, 20)), Point::px(130, 20));
+        assert_eq!(overlay_origin(Point::px(20, 180)), Point::px(20, 130));
+        assert_eq!(overlay_origin(Point::px(180, 180)), Point::px(130, 130));
+        // Exactly fitting against the edge does not flip.
+        assert_eq!(overlay_origin(Point::px(150, 150)), Point::px(150, 150));
+    }
+}
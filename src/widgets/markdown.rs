@@ -0,0 +1,713 @@
+//! A widget that displays Markdown-formatted text.
+
+use std::mem;
+use std::sync::Arc;
+
+use figures::units::Lp;
+use figures::Zero;
+use nominals::{Decimal, NominalSystem};
+use pulldown_cmark::{Alignment, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+use super::grid::GridWidgets;
+use super::label::LabelOverflow;
+use super::list::{Indicator, List, ListIndicator, ListStyle};
+use super::rich_text::{RichText, Span};
+use super::{Delimiter, Grid, Label, RichLabel, Space, Switcher};
+use crate::styles::components::{BorderWidth, FontFamily, FontWeight, HorizontalAlignment};
+use crate::styles::{
+    Dimension, Edges, FamilyOwned, FontFamilyList, HorizontalAlign, Styles, Weight,
+};
+use crate::value::{IntoValue, Value};
+use crate::widget::{
+    MakeWidget, MakeWidgetWithTag, SharedCallback, WidgetInstance, WidgetList, WidgetTag,
+};
+
+/// A widget that displays Markdown-formatted text.
+///
+/// The Markdown source is parsed using [CommonMark][commonmark] rules, with
+/// support for tables and task lists. Each time the source changes, the
+/// widgets displaying it are rebuilt.
+///
+/// - Headings are styled using [`MakeWidget::h1`] through [`MakeWidget::h6`].
+/// - Paragraphs, emphasis, and inline code are displayed using [`RichLabel`].
+/// - Inline code and code blocks use a monospace font family.
+/// - Lists are displayed using [`List`].
+/// - Tables are displayed using [`Grid`].
+/// - Links are only interactive when a callback is provided via
+///   [`Markdown::on_link`].
+/// - Images are only displayed when an [`ImageLoader`] is provided via
+///   [`Markdown::image_loader`], and are placed on their own line. Otherwise,
+///   the image's alternate text is displayed.
+///
+/// [commonmark]: https://commonmark.org/
+pub struct Markdown {
+    source: Value<String>,
+    handlers: Handlers,
+}
+
+impl Markdown {
+    /// Returns a widget that displays `source` as Markdown.
+    pub fn new(source: impl IntoValue<String>) -> Self {
+        Self {
+            source: source.into_value(),
+            handlers: Handlers::default(),
+        }
+    }
+
+    /// Invokes `on_link` with the link's destination when a link is clicked,
+    /// and returns self.
+    #[must_use]
+    pub fn on_link<F>(mut self, on_link: F) -> Self
+    where
+        F: FnMut(String) + Send + 'static,
+    {
+        self.handlers.on_link = Some(SharedCallback::new(on_link));
+        self
+    }
+
+    /// Uses `loader` to create the widgets that display images, and returns
+    /// self.
+    #[must_use]
+    pub fn image_loader(mut self, loader: impl ImageLoader) -> Self {
+        self.handlers.image_loader = Some(Arc::new(loader));
+        self
+    }
+}
+
+impl MakeWidgetWithTag for Markdown {
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let handlers = self.handlers;
+        match self.source {
+            Value::Constant(source) => handlers.render(&source).make_with_tag(tag),
+            Value::Dynamic(source) => {
+                Switcher::mapping(source, move |source: &String, _| handlers.render(source))
+                    .make_with_tag(tag)
+            }
+        }
+    }
+}
+
+/// A type that creates widgets for images embedded in [`Markdown`].
+pub trait ImageLoader: Send + Sync + 'static {
+    /// Returns a widget that displays `image`, or None if the image cannot be
+    /// displayed.
+    ///
+    /// When None is returned, the image's alternate text is displayed instead.
+    fn load_image(&self, image: &MarkdownImage<'_>) -> Option<WidgetInstance>;
+}
+
+impl<F> ImageLoader for F
+where
+    F: Fn(&MarkdownImage<'_>) -> Option<WidgetInstance> + Send + Sync + 'static,
+{
+    fn load_image(&self, image: &MarkdownImage<'_>) -> Option<WidgetInstance> {
+        self(image)
+    }
+}
+
+/// An image referenced in [`Markdown`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MarkdownImage<'a> {
+    /// The location of the image.
+    pub url: &'a str,
+    /// The title of the image.
+    pub title: &'a str,
+    /// The alternate text describing the image.
+    pub alt: &'a str,
+}
+
+#[derive(Clone, Default)]
+struct Handlers {
+    on_link: Option<SharedCallback<String>>,
+    image_loader: Option<Arc<dyn ImageLoader>>,
+}
+
+impl Handlers {
+    fn render(&self, source: &str) -> WidgetInstance {
+        let mut renderer = Renderer::new(self);
+        for event in Parser::new_ext(source, Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS) {
+            renderer.handle(event);
+        }
+        renderer.finish()
+    }
+}
+
+struct Renderer<'a> {
+    handlers: &'a Handlers,
+    blocks: Vec<Block>,
+    text: RichText,
+    emphasis: usize,
+    strong: usize,
+    link: Option<String>,
+    image: Option<PendingImage>,
+    code_block: Option<String>,
+    table: Option<Table>,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(handlers: &'a Handlers) -> Self {
+        Self {
+            handlers,
+            blocks: vec![Block::new(BlockKind::Root)],
+            text: RichText::new(),
+            emphasis: 0,
+            strong: 0,
+            link: None,
+            image: None,
+            code_block: None,
+            table: None,
+        }
+    }
+
+    fn handle(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some(code) = &mut self.code_block {
+                    code.push_str(&text);
+                } else if let Some(image) = &mut self.image {
+                    image.alt.push_str(&text);
+                } else {
+                    self.push_text(text.into_string(), false);
+                }
+            }
+            Event::Code(code) => {
+                if let Some(image) = &mut self.image {
+                    image.alt.push_str(&code);
+                } else {
+                    self.push_text(code.into_string(), true);
+                }
+            }
+            Event::SoftBreak => self.push_text(String::from(" "), false),
+            Event::HardBreak => self.push_text(String::from("\n"), false),
+            Event::Rule => {
+                self.finish_text();
+                self.push_block(Delimiter::horizontal().make_widget());
+            }
+            Event::TaskListMarker(checked) => {
+                self.push_text(
+                    String::from(if checked { "\u{2611} " } else { "\u{2610} " }),
+                    false,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph | Tag::Heading { .. } => self.finish_text(),
+            Tag::BlockQuote(_) => {
+                self.finish_text();
+                self.blocks.push(Block::new(BlockKind::Quote));
+            }
+            Tag::CodeBlock(_) => {
+                self.finish_text();
+                self.code_block = Some(String::new());
+            }
+            Tag::List(start) => {
+                self.finish_text();
+                let style = match start {
+                    Some(1) => ListStyle::Decimal,
+                    Some(start) => ListStyle::Custom(Arc::new(StartingAt(start))),
+                    None => {
+                        // Nested unordered lists use a different indicator
+                        // for each level of nesting.
+                        let depth = self
+                            .blocks
+                            .iter()
+                            .filter(|block| {
+                                matches!(block.kind, BlockKind::List { ordered: false, .. })
+                            })
+                            .count();
+                        match depth {
+                            0 => ListStyle::Disc,
+                            1 => ListStyle::Circle,
+                            _ => ListStyle::Square,
+                        }
+                    }
+                };
+                self.blocks.push(Block::new(BlockKind::List {
+                    style,
+                    ordered: start.is_some(),
+                }));
+            }
+            Tag::Item => {
+                self.finish_text();
+                self.blocks.push(Block::new(BlockKind::Item));
+            }
+            Tag::Table(alignments) => {
+                self.finish_text();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    in_head: false,
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.in_head = matches!(tag, Tag::TableHead);
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::Emphasis => self.emphasis += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Link { dest_url, .. } => self.link = Some(dest_url.into_string()),
+            Tag::Image {
+                dest_url, title, ..
+            } => {
+                self.image = Some(PendingImage {
+                    url: dest_url.into_string(),
+                    title: title.into_string(),
+                    alt: String::new(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.finish_text(),
+            TagEnd::Heading(level) => {
+                if let Some(text) = self.take_text() {
+                    let heading = match level {
+                        HeadingLevel::H1 => text.h1(),
+                        HeadingLevel::H2 => text.h2(),
+                        HeadingLevel::H3 => text.h3(),
+                        HeadingLevel::H4 => text.h4(),
+                        HeadingLevel::H5 => text.h5(),
+                        HeadingLevel::H6 => text.h6(),
+                    };
+                    self.push_block(heading.make_widget());
+                }
+            }
+            TagEnd::BlockQuote(_) => {
+                self.finish_text();
+                let quote =
+                    self.pop_block()
+                        .into_rows()
+                        .contain()
+                        .with_styles(Styles::new().with_local(
+                            &BorderWidth,
+                            Edges::from(Dimension::ZERO).with_left(Lp::points(3)),
+                        ));
+                self.push_block(quote.make_widget());
+            }
+            TagEnd::CodeBlock => {
+                let mut code = self.code_block.take().unwrap_or_default();
+                if code.ends_with('\n') {
+                    code.pop();
+                }
+                let code = Label::new(code)
+                    .overflow(LabelOverflow::Clip)
+                    .horizontal_scroll()
+                    .with(&FontFamily, FontFamilyList::from(FamilyOwned::Monospace))
+                    .contain();
+                self.push_block(code.make_widget());
+            }
+            TagEnd::List(_) => {
+                self.finish_text();
+                let block = self.blocks.pop().expect("list pushed");
+                let BlockKind::List { style, .. } = block.kind else {
+                    unreachable!("list pushed")
+                };
+                self.push_block(List::new(block.children).style(style).make_widget());
+            }
+            TagEnd::Item => {
+                self.finish_text();
+                let mut item = self.pop_block();
+                let item = if item.len() == 1 {
+                    item.remove(0)
+                } else {
+                    item.into_rows().make_widget()
+                };
+                self.push_block(item);
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.push_block(table.into_widget());
+                }
+            }
+            TagEnd::TableCell => {
+                let text = self.take_text();
+                if let Some(table) = &mut self.table {
+                    let column = table.rows.last().map_or(0, Vec::len);
+                    let align = match table.alignments.get(column) {
+                        Some(Alignment::Center) => HorizontalAlign::Center,
+                        Some(Alignment::Right) => HorizontalAlign::Right,
+                        _ => HorizontalAlign::Left,
+                    };
+                    let mut cell = text
+                        .map_or_else(|| Space::clear().make_widget(), MakeWidget::make_widget)
+                        .with(&HorizontalAlignment, align)
+                        .make_widget();
+                    if table.in_head {
+                        cell = cell.with(&FontWeight, Weight::BOLD).make_widget();
+                    }
+                    if let Some(row) = table.rows.last_mut() {
+                        row.push(cell);
+                    }
+                }
+            }
+            TagEnd::Emphasis => self.emphasis = self.emphasis.saturating_sub(1),
+            TagEnd::Strong => self.strong = self.strong.saturating_sub(1),
+            TagEnd::Link => self.link = None,
+            TagEnd::Image => {
+                if let Some(image) = self.image.take() {
+                    self.push_image(&image);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn push_text(&mut self, text: String, code: bool) {
+        let mut span = Span::new(text);
+        if code {
+            span = span.family(FamilyOwned::Monospace);
+        }
+        if self.strong > 0 {
+            span = span.bold();
+        }
+        if self.emphasis > 0 {
+            span = span.italic();
+        }
+        if let (Some(url), Some(on_link)) = (&self.link, &self.handlers.on_link) {
+            let url = url.clone();
+            let on_link = on_link.clone();
+            span = span.on_click(move |()| on_link.invoke(url.clone()));
+        }
+        self.text.push(span);
+    }
+
+    fn push_image(&mut self, image: &PendingImage) {
+        let widget = self.handlers.image_loader.as_ref().and_then(|loader| {
+            loader.load_image(&MarkdownImage {
+                url: &image.url,
+                title: &image.title,
+                alt: &image.alt,
+            })
+        });
+        if let Some(widget) = widget {
+            // Images are displayed between the text before and after them.
+            self.finish_text();
+            self.push_block(widget);
+        } else if !image.alt.is_empty() {
+            self.text.push(Span::new(image.alt.clone()).italic());
+        }
+    }
+
+    fn take_text(&mut self) -> Option<RichLabel> {
+        (!self.text.is_empty()).then(|| RichLabel::new(mem::take(&mut self.text)))
+    }
+
+    fn finish_text(&mut self) {
+        if let Some(text) = self.take_text() {
+            self.push_block(text.make_widget());
+        }
+    }
+
+    fn push_block(&mut self, widget: WidgetInstance) {
+        self.blocks
+            .last_mut()
+            .expect("root block")
+            .children
+            .push(widget);
+    }
+
+    fn pop_block(&mut self) -> WidgetList {
+        self.blocks.pop().expect("block pushed").children
+    }
+
+    fn finish(mut self) -> WidgetInstance {
+        self.finish_text();
+        let mut blocks = mem::take(&mut self.blocks);
+        // Unbalanced events should not occur, but if they do, the contents of
+        // any unclosed blocks are added to the root.
+        let mut root = blocks.remove(0).children;
+        for block in blocks {
+            root.extend(block.children);
+        }
+        root.into_rows().make_widget()
+    }
+}
+
+struct Block {
+    kind: BlockKind,
+    children: WidgetList,
+}
+
+impl Block {
+    fn new(kind: BlockKind) -> Self {
+        Self {
+            kind,
+            children: WidgetList::new(),
+        }
+    }
+}
+
+enum BlockKind {
+    Root,
+    Quote,
+    List { style: ListStyle, ordered: bool },
+    Item,
+}
+
+struct PendingImage {
+    url: String,
+    title: String,
+    alt: String,
+}
+
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<WidgetInstance>>,
+    in_head: bool,
+}
+
+impl Table {
+    fn into_widget(self) -> WidgetInstance {
+        let columns = self.rows.iter().map(Vec::len).max().unwrap_or_default();
+        let mut rows = self.rows;
+        for row in &mut rows {
+            row.resize_with(columns, || Space::clear().make_widget());
+        }
+
+        // Grid requires the number of columns to be known at compile time.
+        // Tables with more columns than are supported are displayed as rows
+        // of columns, which do not align their cells.
+        macro_rules! grid {
+            ($($columns:literal)+) => {
+                match columns {
+                    $($columns => Grid::from_rows(
+                        rows.into_iter()
+                            .map(|row| {
+                                <[WidgetInstance; $columns]>::try_from(row)
+                                    .expect("rows are padded")
+                            })
+                            .collect::<GridWidgets<$columns>>(),
+                    )
+                    .make_widget(),)+
+                    _ => rows
+                        .into_iter()
+                        .map(|row| row.into_iter().collect::<WidgetList>().into_columns())
+                        .collect::<WidgetList>()
+                        .into_rows()
+                        .make_widget(),
+                }
+            };
+        }
+
+        grid!(1 2 3 4 5 6 7 8 9 10 11 12)
+    }
+}
+
+/// A decimal list indicator for ordered lists that do not start at 1.
+#[derive(Debug)]
+struct StartingAt(u64);
+
+impl ListIndicator for StartingAt {
+    fn list_indicator(&self, index: usize) -> Option<Indicator> {
+        // List indices start at 1.
+        let index = usize::try_from(self.0)
+            .unwrap_or(usize::MAX)
+            .saturating_add(index.saturating_sub(1));
+        Some(Indicator::delimited(String::from(
+            Decimal.format_nominal(index),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Point, Rect, Size};
+    use kludgine::app::winit::event::{ElementState, MouseButton};
+
+    use super::{Grid, Label, List, Markdown, MarkdownImage, RichLabel, RichText};
+    use crate::value::{Destination, Dynamic, Source};
+    use crate::widget::{MakeWidget, MountedWidget};
+    use crate::window::{DeviceId, VirtualRecorder, VirtualWindow};
+
+    fn render(markdown: Markdown) -> VirtualRecorder {
+        let mut recorder = markdown
+            .build_recorder()
+            .size(Size::new(UPx::new(600), UPx::new(600)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        recorder
+    }
+
+    /// Returns the rich labels in the window from top to bottom, left to
+    /// right.
+    fn rich_labels(window: &VirtualWindow) -> Vec<(MountedWidget, RichText)> {
+        let mut labels = window
+            .find_widgets::<RichLabel>()
+            .into_iter()
+            .map(|widget| {
+                let text = widget
+                    .lock()
+                    .downcast_ref::<RichLabel>()
+                    .expect("rich label")
+                    .text
+                    .get();
+                (widget, text)
+            })
+            .collect::<Vec<_>>();
+        labels.sort_by_key(|(widget, _)| {
+            widget
+                .last_layout()
+                .map(|layout| (layout.origin.y, layout.origin.x))
+        });
+        labels
+    }
+
+    fn texts(window: &VirtualWindow) -> Vec<String> {
+        rich_labels(window)
+            .into_iter()
+            .map(|(_, text)| text.to_plain_text())
+            .collect()
+    }
+
+    fn layout_of(window: &VirtualWindow, text: &str) -> Rect<Px> {
+        rich_labels(window)
+            .into_iter()
+            .find(|(_, label)| label.to_plain_text() == text)
+            .and_then(|(widget, _)| widget.last_layout())
+            .expect("label not found")
+    }
+
+    #[test]
+    fn headings() {
+        let recorder = render(Markdown::new("# Title\n\n### Section\n\nBody *text*"));
+        let window = &recorder.window;
+        assert_eq!(texts(window), ["Title", "Section", "Body text"]);
+
+        let body = layout_of(window, "Body text").size.height;
+        let section = layout_of(window, "Section").size.height;
+        assert!(section > body, "{section:?} <= {body:?}");
+        assert!(layout_of(window, "Title").size.height > section);
+
+        let (_, body) = rich_labels(window).pop().expect("body");
+        assert_eq!(body.spans()[1].text(), "text");
+        assert!(body.spans()[1].style.is_some());
+    }
+
+    #[test]
+    fn nested_lists() {
+        let recorder = render(Markdown::new(
+            "3. Three\n4. Four\n   - Nested\n     - Deeper\n",
+        ));
+        let window = &recorder.window;
+        assert_eq!(window.find_widgets::<List>().len(), 3);
+        assert_eq!(texts(window), ["Three", "Four", "Nested", "Deeper"]);
+
+        // Ordered lists count from their start number, and each level of
+        // nested unordered lists uses a different indicator.
+        for indicator in ["3.", "4.", "\u{2022}", "\u{25E6}"] {
+            assert!(
+                window.find_text(indicator).is_some(),
+                "indicator {indicator:?} not found"
+            );
+        }
+        assert!(window.find_text("1.").is_none());
+    }
+
+    #[test]
+    fn tables() {
+        let recorder = render(Markdown::new(
+            "| Name | Value |\n| :--- | ---: |\n| a | 1 |\n| b |\n",
+        ));
+        let window = &recorder.window;
+        assert!(window.find_widget::<Grid<2>>().is_some());
+        assert_eq!(texts(window), ["Name", "Value", "a", "1", "b"]);
+
+        // Cells in the same row are laid out side by side.
+        let name = layout_of(window, "Name");
+        let value = layout_of(window, "Value");
+        assert_eq!(name.origin.y, value.origin.y);
+        assert!(layout_of(window, "1").origin.x > layout_of(window, "a").origin.x);
+        assert!(layout_of(window, "b").origin.y > layout_of(window, "a").origin.y);
+    }
+
+    #[test]
+    fn code_blocks() {
+        let recorder = render(Markdown::new(
+            "Before\n\n```rust\nfn main() {\n    *not emphasis*\n}\n```\n\nAfter",
+        ));
+        let window = &recorder.window;
+        assert_eq!(texts(window), ["Before", "After"]);
+
+        let code = window
+            .find_text("fn main() {\n    *not emphasis*\n}")
+            .expect("code block label");
+        assert!(code.lock().downcast_ref::<Label<String>>().is_some());
+    }
+
+    #[test]
+    fn links() {
+        let clicked = Dynamic::new(Vec::<String>::new());
+        let mut recorder = render(Markdown::new("[Cushy](https://cushy.rs) rocks").on_link({
+            let clicked = clicked.clone();
+            move |url| clicked.lock().push(url)
+        }));
+        let (label, text) = rich_labels(&recorder.window).pop().expect("paragraph");
+        assert!(text.spans()[0].is_link());
+        assert!(!text.spans()[1].is_link());
+
+        let layout = label.last_layout().expect("laid out");
+        let window = &mut recorder.window;
+        window.cursor_moved(
+            DeviceId::Virtual(0),
+            layout.origin + Point::new(Px::new(3), layout.size.height / 2),
+        );
+        let _ = window.mouse_input(
+            DeviceId::Virtual(0),
+            ElementState::Pressed,
+            MouseButton::Left,
+        );
+        let _ = window.mouse_input(
+            DeviceId::Virtual(0),
+            ElementState::Released,
+            MouseButton::Left,
+        );
+        assert_eq!(clicked.get(), ["https://cushy.rs"]);
+
+        // Without a callback, links are displayed as regular text.
+        let recorder = render(Markdown::new("[Cushy](https://cushy.rs)"));
+        let (_, text) = rich_labels(&recorder.window).pop().expect("paragraph");
+        assert!(!text.spans()[0].is_link());
+    }
+
+    #[test]
+    fn images() {
+        let source = "![Missing image](missing.png)\n\n![Loaded image](loaded.png)";
+        let recorder = render(Markdown::new(source));
+        assert_eq!(texts(&recorder.window), ["Missing image", "Loaded image"]);
+
+        let recorder = render(
+            Markdown::new(source).image_loader(|image: &MarkdownImage<'_>| {
+                (image.url == "loaded.png")
+                    .then(|| Label::new(format!("{} loaded", image.alt)).make_widget())
+            }),
+        );
+        let window = &recorder.window;
+        assert_eq!(texts(window), ["Missing image"]);
+        assert!(window.find_text("Loaded image loaded").is_some());
+    }
+
+    #[test]
+    fn rerenders_when_source_changes() {
+        let source = Dynamic::new(String::from("# First"));
+        let mut recorder = render(Markdown::new(source.clone()));
+        assert_eq!(texts(&recorder.window), ["First"]);
+
+        source.set(String::from("Second\n\n- Third"));
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(texts(&recorder.window), ["Second", "Third"]);
+        assert!(recorder.window.find_widget::<List>().is_some());
+    }
+}
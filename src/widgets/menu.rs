@@ -7,6 +7,8 @@ use std::time::Duration;
 use alot::LotId;
 use figures::units::{Px, UPx};
 use figures::{Angle, IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::keyboard::{Key, NamedKey};
 use kludgine::shapes::{PathBuilder, Shape, StrokeOptions};
 use kludgine::DrawableExt;
 
@@ -15,7 +17,7 @@ use super::button::{ButtonColors, ButtonKind, VisualState};
 use super::container::{self, ContainerShadow};
 use super::disclose::IndicatorSize;
 use super::layers::{OverlayBuilder, OverlayHandle, OverlayLayer, Overlayable};
-use super::Button;
+use super::{Button, Label};
 use crate::animation::{AnimationHandle, AnimationTarget, Spawn};
 use crate::context::{AsEventContext, EventContext, GraphicsContext, LayoutContext};
 use crate::styles::components::{
@@ -25,8 +27,9 @@ use crate::styles::Styles;
 use crate::value::{Dynamic, IntoValue, Source, Value};
 use crate::widget::{
     Callback, EventHandling, MakeWidget, MakeWidgetWithTag, SharedNotify, Widget, WidgetId,
-    WidgetInstance, WidgetRef, WidgetTag, HANDLED,
+    WidgetInstance, WidgetRef, WidgetTag, WrapperWidget, HANDLED, IGNORED,
 };
+use crate::window::{DeviceId, KeyEvent};
 use crate::ConstraintLimit;
 
 #[derive(Debug, Clone)]
@@ -40,6 +43,18 @@ enum ItemKind<T> {
 /// This widget is designed to implement Cushy's contextual menu system. When
 /// used with an [`OverlayLayer`], this widget can be shown above other widgets
 /// or at a specific location.
+///
+/// Open menus can be navigated using the keyboard:
+///
+/// - Up and Down move the highlighted item, skipping separators and disabled
+///   items. Home and End highlight the first and last items.
+/// - Right opens the highlighted item's submenu, and Left closes the current
+///   submenu.
+/// - Enter and Space select the highlighted item, or open its submenu.
+/// - Escape dismisses the menu and restores focus to the widget that was
+///   focused when the menu was opened.
+/// - Typing a letter highlights the next item whose text begins with that
+///   letter.
 #[derive(Debug, Clone)]
 pub struct Menu<T, Handler = MenuHandler<T>> {
     items: Vec<ItemKind<MenuItem<T>>>,
@@ -116,13 +131,24 @@ where
     /// be positioned relative or absolutely within `overlay`.
     #[must_use]
     pub fn overlay_in<'overlay>(&self, overlay: &'overlay OverlayLayer) -> MenuOverlay<'overlay> {
-        self.overlay_in_shared(overlay, Dynamic::default())
+        self.overlay_in_shared(overlay, Dynamic::default(), None, false)
+    }
+
+    /// Presents this menu in `overlay` with its first selectable item
+    /// highlighted, as is expected when a menu is opened using the keyboard.
+    pub(crate) fn overlay_in_from_keyboard<'overlay>(
+        &self,
+        overlay: &'overlay OverlayLayer,
+    ) -> MenuOverlay<'overlay> {
+        self.overlay_in_shared(overlay, Dynamic::default(), None, true)
     }
 
     fn overlay_in_shared<'overlay>(
         &self,
         overlay: &'overlay OverlayLayer,
         shared: Dynamic<SharedMenuState>,
+        parent_menu: Option<WidgetId>,
+        highlight_first: bool,
     ) -> MenuOverlay<'overlay> {
         let Self { items, on_click } = self;
         let handle = OpenMenuHandle(Dynamic::new(None));
@@ -137,17 +163,26 @@ where
                         widget,
                         submenu,
                         enabled,
-                    }) => ItemKind::Item(OpenItem {
-                        value: value.clone(),
-                        contents: WidgetRef::new(
-                            widget.clone().align_left().with_enabled(enabled.clone()),
-                        ),
-                        submenu: submenu.clone(),
-                        colors: None,
-                        color_animation: AnimationHandle::default(),
-                        state: VisualState::Normal,
-                        enabled: enabled.clone(),
-                    }),
+                    }) => {
+                        let contents = widget.clone();
+                        #[cfg(feature = "accessibility")]
+                        let contents = AccessibleMenuItem {
+                            child: WidgetRef::new(contents),
+                            has_submenu: submenu.is_some(),
+                        };
+                        ItemKind::Item(OpenItem {
+                            value: value.clone(),
+                            widget: widget.clone(),
+                            contents: WidgetRef::new(
+                                contents.align_left().with_enabled(enabled.clone()),
+                            ),
+                            submenu: submenu.clone(),
+                            colors: None,
+                            color_animation: AnimationHandle::default(),
+                            state: VisualState::Normal,
+                            enabled: enabled.clone(),
+                        })
+                    }
                     ItemKind::Separator => ItemKind::Separator,
                 },
             })
@@ -164,6 +199,9 @@ where
                     open_id: root_menu,
                     padding: UPx::ZERO,
                     selecting: None,
+                    keyboard_selection: None,
+                    highlight_first,
+                    parent_menu,
                     hover_location: None,
                     mouse_down: false,
                     layer: overlay.clone(),
@@ -240,6 +278,123 @@ impl OpenMenuHandle {
     }
 }
 
+/// A widget that shows a contextual [`Menu`] for its child.
+///
+/// The menu is created by invoking a factory function each time it is opened,
+/// allowing the menu to reflect the current state of the application. The
+/// menu is shown in an [`OverlayLayer`]:
+///
+/// - At the mouse cursor when the right mouse button is pressed and the child
+///   does not handle the click.
+/// - At the bottom-left corner of this widget when the Menu key or Shift+F10
+///   is pressed while this widget or one of its children is focused.
+///
+/// When the menu does not fit within the layer at its requested location, it is
+/// flipped to the opposite side of the location.
+///
+/// Use [`MakeWidget::with_context_menu`] to create this widget.
+pub struct ContextMenu<T> {
+    child: WidgetRef,
+    layer: OverlayLayer,
+    factory: Box<dyn FnMut() -> Menu<T> + Send>,
+    open_menu: Option<OpenMenuHandle>,
+}
+
+impl<T> ContextMenu<T>
+where
+    T: Unpin + Debug + Send + Clone + 'static,
+{
+    /// Returns a widget that shows the menu returned from `menu_factory` in
+    /// `layer` when `child` requests a context menu.
+    pub fn new<F>(layer: &OverlayLayer, menu_factory: F, child: impl MakeWidget) -> Self
+    where
+        F: FnMut() -> Menu<T> + Send + 'static,
+    {
+        Self {
+            child: WidgetRef::new(child),
+            layer: layer.clone(),
+            factory: Box::new(menu_factory),
+            open_menu: None,
+        }
+    }
+
+    fn open(&mut self, location: Point<Px>, from_keyboard: bool, context: &mut EventContext<'_>) {
+        if let Some(open) = self.open_menu.take() {
+            open.dismiss();
+        }
+
+        let menu = (self.factory)();
+        let overlay = if from_keyboard {
+            menu.overlay_in_from_keyboard(&self.layer)
+        } else {
+            menu.overlay_in(&self.layer)
+        };
+        self.open_menu = Some(overlay.parent(context.widget().id()).at(location).show());
+    }
+}
+
+impl<T> Debug for ContextMenu<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextMenu")
+            .field("child", &self.child)
+            .field("layer", &self.layer)
+            .field("open_menu", &self.open_menu)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> WrapperWidget for ContextMenu<T>
+where
+    T: Unpin + Debug + Send + Clone + 'static,
+{
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Right {
+            return IGNORED;
+        }
+        let Some(layout) = context.last_layout() else {
+            return IGNORED;
+        };
+        self.open(layout.origin + location, false, context);
+        HANDLED
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let requested = match &input.logical_key {
+            Key::Named(NamedKey::ContextMenu) => true,
+            Key::Named(NamedKey::F10) => context.modifiers().state().shift_key(),
+            _ => false,
+        };
+        if !requested {
+            return IGNORED;
+        }
+
+        if input.state.is_pressed() {
+            let Some(layout) = context.last_layout() else {
+                return IGNORED;
+            };
+            let location = Point::new(layout.origin.x, layout.origin.y + layout.size.height);
+            self.open(location, true, context);
+        }
+        HANDLED
+    }
+}
+
 /// A builder of a [`MenuItem<T>`].
 pub struct MenuItemBuilder<T, Contents = ()> {
     value: T,
@@ -304,6 +459,8 @@ mod sealed {
             &self,
             overlay: &'overlay OverlayLayer,
             shared_state: Dynamic<SharedMenuState>,
+            parent_menu: WidgetId,
+            highlight_first: bool,
         ) -> MenuOverlay<'overlay>;
     }
 
@@ -320,6 +477,8 @@ mod sealed {
     pub struct SharedMenuState {
         pub open_menus: OrderedLots<OpenMenuHandle>,
         pub hovering: Set<WidgetId>,
+        /// The widget that was focused when the root menu was opened.
+        pub opener: Option<WidgetId>,
     }
 }
 
@@ -365,8 +524,10 @@ where
         &self,
         overlay: &'overlay OverlayLayer,
         shared_state: Dynamic<SharedMenuState>,
+        parent_menu: WidgetId,
+        highlight_first: bool,
     ) -> MenuOverlay<'overlay> {
-        self.overlay_in_shared(overlay, shared_state)
+        self.overlay_in_shared(overlay, shared_state, Some(parent_menu), highlight_first)
     }
 }
 
@@ -465,6 +626,9 @@ struct OpenMenu<T> {
     open_id: LotId,
     padding: UPx,
     selecting: Option<usize>,
+    keyboard_selection: Option<usize>,
+    highlight_first: bool,
+    parent_menu: Option<WidgetId>,
     hover_location: Option<Point<Px>>,
     mouse_down: bool,
     layer: OverlayLayer,
@@ -475,11 +639,15 @@ struct OpenMenu<T> {
 }
 impl<T> OpenMenu<T> {
     fn update_visual_state(&mut self, context: &mut EventContext<'_>) {
-        let location = self.hover_location.unwrap_or(Point::squared(Px::new(-1)));
         self.selecting = None;
+        let mut submenu_to_open = None;
         for (index, rendered) in self.items.iter_mut().enumerate() {
-            let hovered = location.y >= rendered.y - self.padding
-                && location.y < rendered.y + rendered.height + self.padding;
+            let hovered = if let Some(location) = self.hover_location {
+                location.y >= rendered.y - self.padding
+                    && location.y < rendered.y + rendered.height + self.padding
+            } else {
+                self.keyboard_selection == Some(index)
+            };
             if let ItemKind::Item(item) = &mut rendered.item {
                 let enabled = item.enabled.get_tracking_redraw(context);
                 let new_state = if enabled {
@@ -491,21 +659,10 @@ impl<T> OpenMenu<T> {
                                 handle.dismiss();
                                 self.open_submenu = None;
                             }
-                        } else if let Some(factory) = &item.submenu {
-                            let last_layout = context.last_layout().expect("must have rendered");
-                            let menu_location = Point::new(
-                                last_layout.origin.x + last_layout.size.width
-                                    - self.padding.into_signed() * 2,
-                                last_layout.origin.y + (rendered.y - self.padding).into_signed(),
-                            );
-                            self.open_submenu = Some((
-                                index,
-                                factory
-                                    .overlay_submenu_in(&self.layer, self.shared.clone())
-                                    .parent(self.menu_id)
-                                    .at(menu_location)
-                                    .show(),
-                            ));
+                        } else if item.submenu.is_some() && self.hover_location.is_some() {
+                            // Submenus are only opened automatically when
+                            // hovered by the mouse.
+                            submenu_to_open = Some(index);
                         }
                         if self.mouse_down {
                             VisualState::Active
@@ -538,6 +695,139 @@ impl<T> OpenMenu<T> {
                 }
             }
         }
+
+        if let Some(index) = submenu_to_open {
+            self.show_submenu(index, false, context);
+        }
+    }
+
+    fn show_submenu(
+        &mut self,
+        index: usize,
+        highlight_first: bool,
+        context: &mut EventContext<'_>,
+    ) {
+        let rendered = &self.items[index];
+        let Some(factory) = rendered.submenu() else {
+            return;
+        };
+        let last_layout = context.last_layout().expect("must have rendered");
+        let menu_location = Point::new(
+            last_layout.origin.x + last_layout.size.width - self.padding.into_signed() * 2,
+            last_layout.origin.y + (rendered.y - self.padding).into_signed(),
+        );
+        self.open_submenu = Some((
+            index,
+            factory
+                .overlay_submenu_in(
+                    &self.layer,
+                    self.shared.clone(),
+                    context.widget().id(),
+                    highlight_first,
+                )
+                .parent(self.menu_id)
+                .at(menu_location)
+                .show(),
+        ));
+    }
+
+    fn is_selectable(&self, index: usize, context: &mut EventContext<'_>) -> bool {
+        matches!(&self.items[index].item, ItemKind::Item(item) if item.enabled.get_tracking_redraw(context))
+    }
+
+    fn highlight(&mut self, index: usize, context: &mut EventContext<'_>) {
+        self.hover_location = None;
+        self.keyboard_selection = Some(index);
+        self.update_visual_state(context);
+    }
+
+    /// Highlights the next selectable item after `start` in the direction
+    /// indicated by `forwards`. When `start` is `None`, the search begins at
+    /// the first or last item.
+    fn highlight_next(
+        &mut self,
+        start: Option<usize>,
+        forwards: bool,
+        context: &mut EventContext<'_>,
+    ) {
+        let count = self.items.len();
+        let mut index = start;
+        for _ in 0..count {
+            let next = match index {
+                Some(index) if forwards => (index + 1) % count,
+                Some(index) => (index + count - 1) % count,
+                None if forwards => 0,
+                None => count - 1,
+            };
+            if self.is_selectable(next, context) {
+                self.highlight(next, context);
+                return;
+            }
+            index = Some(next);
+        }
+    }
+
+    /// Highlights the next selectable item whose text begins with `letter`.
+    fn type_ahead(&mut self, letter: char, context: &mut EventContext<'_>) {
+        let count = self.items.len();
+        let start = self.selecting.map_or(0, |index| index + 1);
+        for offset in 0..count {
+            let index = (start + offset) % count;
+            let ItemKind::Item(item) = &self.items[index].item else {
+                continue;
+            };
+            if item.starts_with(letter) && self.is_selectable(index, context) {
+                self.highlight(index, context);
+                return;
+            }
+        }
+    }
+
+    fn activate_highlighted(&mut self, context: &mut EventContext<'_>) {
+        let Some(index) = self.selecting else {
+            return;
+        };
+        let ItemKind::Item(item) = &self.items[index].item else {
+            return;
+        };
+        if item.submenu.is_some() {
+            self.open_highlighted_submenu(context);
+        } else {
+            self.on_click.0.notify(item.value.clone());
+            self.dismiss_all(context);
+        }
+    }
+
+    fn open_highlighted_submenu(&mut self, context: &mut EventContext<'_>) {
+        let Some(index) = self.selecting else {
+            return;
+        };
+        match &self.open_submenu {
+            Some((open_index, _)) if *open_index == index => {}
+            _ => {
+                if let Some((_, handle)) = self.open_submenu.take() {
+                    handle.dismiss();
+                }
+                self.show_submenu(index, true, context);
+            }
+        }
+    }
+
+    /// Dismisses every menu in this menu's hierarchy, restoring focus to the
+    /// widget that was focused when the menu was opened.
+    fn dismiss_all(&mut self, context: &mut EventContext<'_>) {
+        let mut shared = self.shared.lock();
+        let opener = shared.opener.take();
+        for handle in shared.open_menus.drain() {
+            handle.dismiss();
+        }
+        drop(shared);
+
+        if let Some(opener) = opener {
+            if let Some(mut opener) = context.for_other(&opener) {
+                opener.focus();
+            }
+        }
     }
 }
 
@@ -718,6 +1008,7 @@ where
         context: &mut crate::context::EventContext<'_>,
     ) -> Option<kludgine::app::winit::window::CursorIcon> {
         self.hover_location = Some(location);
+        self.keyboard_selection = None;
         self.update_visual_state(context);
         self.shared.lock().hovering.insert(context.widget().id());
         None
@@ -763,17 +1054,14 @@ where
         _location: Option<Point<Px>>,
         _device_id: crate::window::DeviceId,
         _button: kludgine::app::winit::event::MouseButton,
-        _context: &mut crate::context::EventContext<'_>,
+        context: &mut crate::context::EventContext<'_>,
     ) {
         if let Some(index) = self.selecting {
             let ItemKind::Item(item) = &self.items[index].item else {
                 return;
             };
             self.on_click.0.notify(item.value.clone());
-            let mut shared = self.shared.lock();
-            for handle in shared.open_menus.drain() {
-                handle.dismiss();
-            }
+            self.dismiss_all(context);
         }
         self.hover_location = None;
         self.mouse_down = false;
@@ -784,6 +1072,13 @@ where
     }
 
     fn mounted(&mut self, context: &mut crate::context::EventContext<'_>) {
+        if self.parent_menu.is_none() {
+            self.shared.lock().opener = context
+                .tree
+                .focused_widget()
+                .and_then(|node| context.tree.widget_from_node(node))
+                .map(|opener| opener.id());
+        }
         context.focus();
 
         let colors = Button::colors_for_transparent(VisualState::Normal, context);
@@ -793,6 +1088,81 @@ where
             };
             item.colors = Some(Dynamic::new(colors));
         }
+
+        if self.highlight_first {
+            self.highlight_next(None, true, context);
+        }
+    }
+
+    fn focus(&mut self, _context: &mut crate::context::EventContext<'_>) {
+        // Focus returns to this menu when a submenu is closed using the
+        // keyboard. The item that opened the submenu remains highlighted.
+        if let Some((index, handle)) = self.open_submenu.take() {
+            handle.dismiss();
+            self.hover_location = None;
+            self.keyboard_selection = Some(index);
+        }
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let pressed = input.state.is_pressed();
+        match &input.logical_key {
+            Key::Named(NamedKey::ArrowDown) if pressed => {
+                self.highlight_next(self.selecting, true, context);
+            }
+            Key::Named(NamedKey::ArrowUp) if pressed => {
+                self.highlight_next(self.selecting, false, context);
+            }
+            Key::Named(NamedKey::Home) if pressed => self.highlight_next(None, true, context),
+            Key::Named(NamedKey::End) if pressed => self.highlight_next(None, false, context),
+            Key::Named(NamedKey::ArrowRight) if pressed => self.open_highlighted_submenu(context),
+            Key::Named(NamedKey::ArrowLeft) if pressed => {
+                if let Some(parent) = self.parent_menu {
+                    if let Some(mut parent) = context.for_other(&parent) {
+                        parent.focus();
+                    }
+                }
+            }
+            Key::Named(NamedKey::Enter | NamedKey::Space) if pressed => {
+                self.activate_highlighted(context);
+            }
+            Key::Named(NamedKey::Escape) if pressed => self.dismiss_all(context),
+            Key::Named(
+                NamedKey::ArrowDown
+                | NamedKey::ArrowUp
+                | NamedKey::Home
+                | NamedKey::End
+                | NamedKey::ArrowRight
+                | NamedKey::ArrowLeft
+                | NamedKey::Enter
+                | NamedKey::Space
+                | NamedKey::Escape,
+            ) => {}
+            _ => {
+                let modifiers = context.modifiers().state();
+                if modifiers.control_key() || modifiers.alt_key() || modifiers.super_key() {
+                    return IGNORED;
+                }
+                let mut chars = input.text.as_deref().unwrap_or_default().chars();
+                let (Some(letter), None) = (chars.next(), chars.next()) else {
+                    return IGNORED;
+                };
+                if !letter.is_alphanumeric() {
+                    return IGNORED;
+                }
+                if pressed {
+                    self.type_ahead(letter, context);
+                }
+            }
+        }
+
+        HANDLED
     }
 
     fn blur(&mut self, _context: &mut crate::context::EventContext<'_>) {
@@ -808,6 +1178,70 @@ where
             }
         }
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(
+        &mut self,
+        _context: &mut crate::context::WidgetContext<'_>,
+    ) -> Option<accesskit::Node> {
+        Some(accesskit::Node::new(accesskit::Role::Menu))
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_action(
+        &mut self,
+        request: &accesskit::ActionRequest,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let target = WidgetId::from(request.target);
+        let Some(index) = self.items.iter().position(|rendered| {
+            matches!(&rendered.item, ItemKind::Item(item) if item.contents.widget().id() == target)
+        }) else {
+            return IGNORED;
+        };
+        if !self.is_selectable(index, context) {
+            return IGNORED;
+        }
+        match request.action {
+            accesskit::Action::Click => {
+                self.highlight(index, context);
+                self.activate_highlighted(context);
+            }
+            accesskit::Action::Expand if self.items[index].submenu().is_some() => {
+                self.highlight(index, context);
+                self.open_highlighted_submenu(context);
+            }
+            _ => return IGNORED,
+        }
+        HANDLED
+    }
+}
+
+/// Describes an item in an [`OpenMenu`] to assistive technologies.
+#[cfg(feature = "accessibility")]
+#[derive(Debug)]
+struct AccessibleMenuItem {
+    child: WidgetRef,
+    has_submenu: bool,
+}
+
+#[cfg(feature = "accessibility")]
+impl crate::widget::WrapperWidget for AccessibleMenuItem {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn accessibility_node(
+        &mut self,
+        _context: &mut crate::context::WidgetContext<'_>,
+    ) -> Option<accesskit::Node> {
+        let mut node = accesskit::Node::new(accesskit::Role::MenuItem);
+        node.add_action(accesskit::Action::Click);
+        if self.has_submenu {
+            node.add_action(accesskit::Action::Expand);
+        }
+        Some(node)
+    }
 }
 
 #[derive(Debug)]
@@ -828,6 +1262,7 @@ impl<T> RenderedItem<T> {
 
 struct OpenItem<T> {
     value: T,
+    widget: WidgetInstance,
     enabled: Value<bool>,
     contents: WidgetRef,
     submenu: Option<Arc<dyn SubmenuFactory>>,
@@ -836,6 +1271,20 @@ struct OpenItem<T> {
     state: VisualState,
 }
 
+impl<T> OpenItem<T> {
+    /// Returns true if this item's text begins with `letter`, ignoring case.
+    ///
+    /// Only items whose contents are a text label can be matched.
+    fn starts_with(&self, letter: char) -> bool {
+        let first = self
+            .widget
+            .lock()
+            .downcast_ref::<Label<String>>()
+            .and_then(|label| label.display.map(|text| text.chars().next()));
+        first.is_some_and(|first| first.to_lowercase().eq(letter.to_lowercase()))
+    }
+}
+
 impl<T> Debug for OpenItem<T>
 where
     T: Debug,
@@ -0,0 +1,780 @@
+//! A widget for entering numbers, with buttons to step the value.
+
+use std::fmt::{self, Debug, Display};
+use std::str::FromStr;
+
+use figures::{FloatConversion, Ranged, ScreenScale, Size};
+#[cfg(feature = "localization")]
+use fixed_decimal::FixedDecimal;
+#[cfg(feature = "localization")]
+use icu_decimal::FixedDecimalFormatter;
+#[cfg(feature = "localization")]
+use icu_provider::DataLocale;
+use intentional::Cast;
+use kludgine::app::winit::event::{MouseScrollDelta, TouchPhase};
+use kludgine::app::winit::keyboard::{Key, NamedKey};
+#[cfg(feature = "localization")]
+use unic_langid::LanguageIdentifier;
+
+use super::slider::SliderValue;
+use super::Input;
+use crate::context::{EventContext, LayoutContext};
+use crate::styles::components::LineHeight;
+use crate::value::{Destination, Dynamic, IntoDynamic, IntoValue, Source, Value};
+use crate::widget::{
+    EventHandling, MakeWidget, MakeWidgetWithTag, WidgetInstance, WidgetRef, WidgetTag,
+    WrapperWidget, HANDLED, IGNORED,
+};
+use crate::window::DeviceId;
+use crate::ConstraintLimit;
+
+/// A text input for entering a number, with buttons to increment and
+/// decrement the value.
+///
+/// The value can also be stepped using the up and down arrow keys while the
+/// input is focused, or by using the mouse wheel while the widget is hovered.
+///
+/// When the `localization` feature is enabled, the number is formatted and
+/// parsed using the decimal and grouping separators of the widget's locale.
+/// Otherwise, `.` is used as the decimal separator and `,` as the grouping
+/// separator. [`NumberInput::separators`] overrides either behavior.
+///
+/// Text that cannot be parsed, or that is outside of the allowed range, does
+/// not update the value. Instead, the error is reported through
+/// [`NumberInput::result`], which can be used with
+/// [`Validations`](crate::value::Validations) and
+/// [`Validated`](super::Validated):
+///
+/// ```rust
+/// use cushy::value::{Dynamic, Validations};
+/// use cushy::widget::MakeWidget;
+/// use cushy::widgets::NumberInput;
+///
+/// let validations = Validations::default();
+/// let quantity = NumberInput::new(Dynamic::new(1_u32)).minimum(1).maximum(99);
+/// let validation = validations.validate_result(quantity.result());
+/// let widget = quantity.validation(validation);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NumberInput<T>
+where
+    T: NumberValue,
+{
+    /// The current value.
+    pub value: Dynamic<T>,
+    /// The minimum value allowed.
+    pub minimum: Value<T>,
+    /// The maximum value allowed.
+    pub maximum: Value<T>,
+    /// The amount to increment or decrement the value by when stepping.
+    ///
+    /// This defaults to [`NumberValue::DEFAULT_STEP`].
+    pub step: Value<T>,
+    grouped: bool,
+    separators: Option<NumberFormat>,
+    result: Dynamic<Result<T, NumberInputError<T>>>,
+}
+
+impl<T> NumberInput<T>
+where
+    T: NumberValue,
+{
+    /// Returns a new number input that edits `value`, allowing the full
+    /// [range](Ranged) of the type.
+    #[must_use]
+    pub fn new(value: impl IntoDynamic<T>) -> Self {
+        let value = value.into_dynamic();
+        let result = Dynamic::new(Ok(value.get()));
+        Self {
+            value,
+            minimum: Value::Constant(T::MIN),
+            maximum: Value::Constant(T::MAX),
+            step: Value::Constant(T::DEFAULT_STEP),
+            grouped: false,
+            separators: None,
+            result,
+        }
+    }
+
+    /// Sets the minimum value of this input to `min` and returns self.
+    #[must_use]
+    pub fn minimum(mut self, min: impl IntoValue<T>) -> Self {
+        self.minimum = min.into_value();
+        self
+    }
+
+    /// Sets the maximum value of this input to `max` and returns self.
+    #[must_use]
+    pub fn maximum(mut self, max: impl IntoValue<T>) -> Self {
+        self.maximum = max.into_value();
+        self
+    }
+
+    /// Sets the amount to increment or decrement the value by when stepping
+    /// and returns self.
+    #[must_use]
+    pub fn step(mut self, step: impl IntoValue<T>) -> Self {
+        self.step = step.into_value();
+        self
+    }
+
+    /// Displays the number using grouping separators (e.g., `1,000,000`) and
+    /// returns self.
+    ///
+    /// Grouping separators are always accepted when parsing.
+    #[must_use]
+    pub fn grouped(mut self) -> Self {
+        self.grouped = true;
+        self
+    }
+
+    /// Formats and parses the number using `decimal` to separate the whole
+    /// and fractional parts and `grouping` to separate groups of digits, and
+    /// returns self.
+    ///
+    /// These separators are used instead of the separators of the widget's
+    /// locale.
+    ///
+    /// When `grouping` is whitespace, any whitespace is accepted as a
+    /// grouping separator when parsing.
+    #[must_use]
+    pub fn separators(mut self, decimal: char, grouping: char) -> Self {
+        self.separators = Some(NumberFormat { decimal, grouping });
+        self
+    }
+
+    /// Returns a dynamic containing the result of parsing the entered text.
+    ///
+    /// The returned dynamic implements
+    /// [`IntoDynamic<Validation>`](crate::value::Validation), allowing it to
+    /// be used directly with [`Validated`](super::Validated), or with
+    /// [`Validations::validate_result`](crate::value::Validations::validate_result).
+    #[must_use]
+    pub fn result(&self) -> Dynamic<Result<T, NumberInputError<T>>> {
+        self.result.clone()
+    }
+}
+
+impl<T> MakeWidgetWithTag for NumberInput<T>
+where
+    T: NumberValue,
+{
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let follow_locale = self.separators.is_none();
+        let format = Dynamic::new(self.separators.unwrap_or_default());
+        let text = Dynamic::new(format.get().format(&self.value.get(), self.grouped));
+
+        // When the value or format changes, update the text unless it already
+        // represents the value. This prevents the text from being reformatted
+        // while it is being edited.
+        let weak_text = text.downgrade();
+        let grouped = self.grouped;
+        text.set_source(
+            (&self.value, &format).for_each_cloned(move |(value, format)| {
+                let Some(text) = weak_text.upgrade() else {
+                    return;
+                };
+                if text.map_ref(|text| format.parse::<T>(text)) != Some(value) {
+                    text.set(format.format(&value, grouped));
+                }
+            }),
+        );
+
+        text.set_source(text.for_each_cloned({
+            let value = self.value.clone();
+            let format = format.clone();
+            let minimum = self.minimum.clone();
+            let maximum = self.maximum.clone();
+            let result = self.result.clone();
+            move |text| {
+                let parsed = match format.get().parse::<T>(&text) {
+                    None => Err(NumberInputError::Invalid),
+                    Some(parsed) => {
+                        let minimum = minimum.get();
+                        let maximum = maximum.get();
+                        if parsed < minimum {
+                            Err(NumberInputError::BelowMinimum(minimum))
+                        } else if parsed > maximum {
+                            Err(NumberInputError::AboveMaximum(maximum))
+                        } else {
+                            Ok(parsed)
+                        }
+                    }
+                };
+                if let Ok(parsed) = parsed {
+                    value.set(parsed);
+                }
+                result.set(parsed);
+            }
+        }));
+
+        let stepper = Stepper {
+            value: self.value,
+            text: text.clone(),
+            format: format.clone(),
+            minimum: self.minimum,
+            maximum: self.maximum,
+            step: self.step,
+            grouped,
+        };
+
+        let input = Input::new(text)
+            .on_key({
+                let stepper = stepper.clone();
+                move |input| {
+                    let forwards = match input.logical_key {
+                        Key::Named(NamedKey::ArrowUp) => true,
+                        Key::Named(NamedKey::ArrowDown) => false,
+                        _ => return IGNORED,
+                    };
+                    if input.state.is_pressed() {
+                        stepper.step(forwards);
+                    }
+                    HANDLED
+                }
+            })
+            .expand_horizontally();
+        let decrement = "-".into_button().on_click({
+            let stepper = stepper.clone();
+            move |_| stepper.step(false)
+        });
+        let increment = "+".into_button().on_click({
+            let stepper = stepper.clone();
+            move |_| stepper.step(true)
+        });
+
+        NumberInputWidget {
+            child: WidgetRef::new(input.and(decrement).and(increment).into_columns()),
+            stepper,
+            format,
+            follow_locale,
+            #[cfg(feature = "localization")]
+            locale: None,
+            scrolled: 0.,
+        }
+        .make_with_tag(tag)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Stepper<T>
+where
+    T: NumberValue,
+{
+    value: Dynamic<T>,
+    text: Dynamic<String>,
+    format: Dynamic<NumberFormat>,
+    minimum: Value<T>,
+    maximum: Value<T>,
+    step: Value<T>,
+    grouped: bool,
+}
+
+impl<T> Stepper<T>
+where
+    T: NumberValue,
+{
+    fn step(&self, forwards: bool) {
+        let format = self.format.get();
+        let current = self
+            .text
+            .map_ref(|text| format.parse::<T>(text))
+            .unwrap_or_else(|| self.value.get());
+        let minimum = self.minimum.get();
+        let maximum = self.maximum.get();
+        let mut next = current.step_by(self.step.get(), forwards);
+        if next < minimum {
+            next = minimum;
+        } else if next > maximum {
+            next = maximum;
+        }
+
+        self.value.set(next);
+        // The text may have contained an invalid or out of range value, in
+        // which case the value above may not have changed.
+        self.text.set(format.format(&next, self.grouped));
+    }
+}
+
+#[derive(Debug)]
+struct NumberInputWidget<T>
+where
+    T: NumberValue,
+{
+    child: WidgetRef,
+    stepper: Stepper<T>,
+    format: Dynamic<NumberFormat>,
+    follow_locale: bool,
+    #[cfg(feature = "localization")]
+    locale: Option<LanguageIdentifier>,
+    scrolled: f32,
+}
+
+impl<T> WrapperWidget for NumberInputWidget<T>
+where
+    T: NumberValue,
+{
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn adjust_child_constraints(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<ConstraintLimit> {
+        #[cfg(feature = "localization")]
+        if self.follow_locale {
+            let locale = context.locale().get_tracking_invalidate(context);
+            if self.locale.as_ref() != Some(&locale) {
+                self.format.set(NumberFormat::for_locale(&locale));
+                self.locale = Some(locale);
+            }
+        }
+        #[cfg(not(feature = "localization"))]
+        let _ = (context, &self.format, self.follow_locale);
+
+        available_space
+    }
+
+    fn mouse_wheel(
+        &mut self,
+        _device_id: DeviceId,
+        delta: MouseScrollDelta,
+        _phase: TouchPhase,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if !context.enabled() {
+            return IGNORED;
+        }
+
+        self.scrolled += match delta {
+            MouseScrollDelta::LineDelta(_, y) => y,
+            MouseScrollDelta::PixelDelta(pt) => {
+                let line_height = context
+                    .get(&LineHeight)
+                    .into_px(context.kludgine.scale())
+                    .into_float();
+                pt.y.cast::<f32>() / line_height
+            }
+        };
+
+        while self.scrolled.abs() >= 1. {
+            let forwards = self.scrolled > 0.;
+            self.stepper.step(forwards);
+            self.scrolled -= self.scrolled.signum();
+        }
+
+        HANDLED
+    }
+
+    fn summarize(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("NumberInput")
+            .field("value", &self.stepper.value)
+            .field("min", &self.stepper.minimum)
+            .field("max", &self.stepper.maximum)
+            .finish()
+    }
+}
+
+/// A number that can be edited using a [`NumberInput`].
+pub trait NumberValue:
+    SliderValue<Value = Self> + Ranged + Copy + PartialOrd + Display + FromStr
+{
+    /// The amount to step by when no step has been provided.
+    const DEFAULT_STEP: Self;
+
+    /// Returns the result of adding `step` to `self` when `forwards` is true,
+    /// or subtracting `step` from `self` when `forwards` is false.
+    ///
+    /// The result should saturate at the bounds of the type.
+    #[must_use]
+    fn step_by(self, step: Self, forwards: bool) -> Self;
+}
+
+macro_rules! impl_number_value_for_int {
+    ($($type:ident),+) => {
+        $(
+            impl NumberValue for $type {
+                const DEFAULT_STEP: Self = 1;
+
+                fn step_by(self, step: Self, forwards: bool) -> Self {
+                    if forwards {
+                        self.saturating_add(step)
+                    } else {
+                        self.saturating_sub(step)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_number_value_for_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_number_value_for_float {
+    ($($type:ident),+) => {
+        $(
+            impl NumberValue for $type {
+                const DEFAULT_STEP: Self = 1.;
+
+                fn step_by(self, step: Self, forwards: bool) -> Self {
+                    let stepped = if forwards { self + step } else { self - step };
+                    // Round to the precision of the step to avoid accumulating
+                    // floating point error (e.g., 0.1 + 0.2).
+                    let step = step.to_string();
+                    let precision = step.split_once('.').map_or(0, |(_, fraction)| fraction.len());
+                    format!("{stepped:.precision$}").parse().unwrap_or(stepped)
+                }
+            }
+        )+
+    };
+}
+
+impl_number_value_for_float!(f32, f64);
+
+/// An error from parsing the text of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumberInputError<T> {
+    /// The text is not a valid number.
+    Invalid,
+    /// The number is less than the minimum allowed value.
+    BelowMinimum(T),
+    /// The number is greater than the maximum allowed value.
+    AboveMaximum(T),
+}
+
+impl<T> Display for NumberInputError<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NumberInputError::Invalid => f.write_str("must be a number"),
+            NumberInputError::BelowMinimum(min) => write!(f, "must be at least {min}"),
+            NumberInputError::AboveMaximum(max) => write!(f, "must be at most {max}"),
+        }
+    }
+}
+
+impl<T> std::error::Error for NumberInputError<T> where T: Debug + Display {}
+
+/// The separators used to format and parse numbers.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct NumberFormat {
+    decimal: char,
+    grouping: char,
+}
+
+impl NumberFormat {
+    /// Returns the separators used by `locale`, according to the Unicode
+    /// CLDR data included with `icu_decimal`.
+    #[cfg(feature = "localization")]
+    fn for_locale(locale: &LanguageIdentifier) -> Self {
+        let Some(formatter) = locale
+            .to_string()
+            .parse::<DataLocale>()
+            .ok()
+            .and_then(|locale| FixedDecimalFormatter::try_new(&locale, Default::default()).ok())
+        else {
+            return Self::default();
+        };
+
+        // 1,234,567.8 is large enough to be grouped in every locale, so the
+        // first separator is the grouping separator and the last is the
+        // decimal separator.
+        let sample = FixedDecimal::from(12_345_678).multiplied_pow10(-1);
+        let formatted = formatter.format_to_string(&sample);
+        let mut separators = formatted.chars().filter(|ch| !ch.is_numeric());
+        match (separators.next(), separators.last()) {
+            (Some(grouping), Some(decimal)) if grouping != decimal => Self { decimal, grouping },
+            _ => Self::default(),
+        }
+    }
+
+    fn format<T>(&self, value: &T, grouped: bool) -> String
+    where
+        T: Display,
+    {
+        let text = value.to_string();
+        let (sign, unsigned) = text
+            .strip_prefix('-')
+            .map_or(("", text.as_str()), |unsigned| ("-", unsigned));
+        let (whole, fraction) = unsigned
+            .split_once('.')
+            .map_or((unsigned, None), |(whole, fraction)| {
+                (whole, Some(fraction))
+            });
+        let grouped = grouped && whole.bytes().all(|b| b.is_ascii_digit());
+
+        let mut formatted = String::with_capacity(text.len() + whole.len() / 3);
+        formatted.push_str(sign);
+        for (index, digit) in whole.chars().enumerate() {
+            if grouped && index > 0 && (whole.len() - index) % 3 == 0 {
+                formatted.push(self.grouping);
+            }
+            formatted.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+
+    fn parse<T>(&self, text: &str) -> Option<T>
+    where
+        T: FromStr,
+    {
+        let normalized = text
+            .trim()
+            .chars()
+            .filter(|&ch| {
+                ch != self.grouping && !(self.grouping.is_whitespace() && ch.is_whitespace())
+            })
+            .map(|ch| match ch {
+                ch if ch == self.decimal => '.',
+                // U+2212 MINUS SIGN
+                '\u{2212}' => '-',
+                ch => ch,
+            })
+            .collect::<String>();
+        normalized.parse().ok()
+    }
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal: '.',
+            grouping: ',',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+    use figures::{Point, Size};
+    use kludgine::app::winit::event::{MouseScrollDelta, TouchPhase};
+    use kludgine::app::winit::keyboard::NamedKey;
+
+    use super::{NumberFormat, NumberInput, NumberInputError, NumberValue};
+    use crate::value::{Destination, Dynamic, Source};
+    use crate::widget::MakeWidget;
+    use crate::widgets::Input;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    #[test]
+    fn format_round_trip() {
+        let english = NumberFormat::default();
+        assert_eq!(english.format(&-1_234_567, true), "-1,234,567");
+        assert_eq!(english.format(&1234.5, false), "1234.5");
+        assert_eq!(english.parse::<i32>("-1,234,567"), Some(-1_234_567));
+        assert_eq!(english.parse::<f32>(" 1234.5 "), Some(1234.5));
+        assert_eq!(english.parse::<u8>("256"), None);
+
+        let german = NumberFormat {
+            decimal: ',',
+            grouping: '.',
+        };
+        assert_eq!(german.format(&1234.5, true), "1.234,5");
+        assert_eq!(german.parse::<f64>("1.234,5"), Some(1234.5));
+
+        let french = NumberFormat {
+            decimal: ',',
+            grouping: '\u{a0}',
+        };
+        assert_eq!(french.format(&12345, true), "12\u{a0}345");
+        assert_eq!(french.parse::<u32>("12 345"), Some(12345));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn stepping() {
+        assert_eq!(u8::MAX.step_by(1, true), u8::MAX);
+        assert_eq!(0_u8.step_by(5, false), 0);
+        assert_eq!(0.1_f64.step_by(0.2, true), 0.3);
+        assert_eq!(1_f32.step_by(0.25, false), 0.75);
+    }
+
+    fn scroll(recorder: &mut VirtualRecorder, lines: f32) {
+        let _ = recorder.window.mouse_wheel(
+            DeviceId::Virtual(0),
+            MouseScrollDelta::LineDelta(0., lines),
+            TouchPhase::Moved,
+        );
+    }
+
+    #[test]
+    fn widget_input() {
+        let value = Dynamic::new(5_i32);
+        let number = NumberInput::new(value.clone()).minimum(0).maximum(10);
+        let result = number.result();
+        let mut recorder = number
+            .build_recorder()
+            .size(Size::new(UPx::new(300), UPx::new(100)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let input = recorder.window.find_widget::<Input>().expect("input");
+        let text = input
+            .lock()
+            .downcast_ref::<Input>()
+            .expect("input")
+            .value
+            .clone();
+        assert_eq!(text.get(), "5");
+        recorder.window.focus(&input);
+
+        // The arrow keys step the value.
+        let _ = recorder.window.press_key(NamedKey::ArrowUp);
+        assert_eq!(value.get(), 6);
+        assert_eq!(text.get(), "6");
+        let _ = recorder.window.press_key(NamedKey::ArrowDown);
+        let _ = recorder.window.press_key(NamedKey::ArrowDown);
+        assert_eq!(value.get(), 4);
+        assert_eq!(result.get(), Ok(4));
+
+        // The mouse wheel steps the value once per line scrolled, and the value
+        // stays within the allowed range.
+        let layout = input.last_layout().expect("laid out");
+        recorder.window.cursor_moved(
+            DeviceId::Virtual(0),
+            layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2),
+        );
+        scroll(&mut recorder, 2.);
+        assert_eq!(value.get(), 6);
+        scroll(&mut recorder, -0.5);
+        assert_eq!(value.get(), 6);
+        scroll(&mut recorder, -0.5);
+        assert_eq!(value.get(), 5);
+        scroll(&mut recorder, -10.);
+        assert_eq!(value.get(), 0);
+        assert_eq!(text.get(), "0");
+
+        // Out of range text reports an error without changing the value.
+        text.set(String::new());
+        recorder.refresh().expect("error refreshing");
+        recorder.window.type_text(&input, "12");
+        assert_eq!(text.get(), "12");
+        assert_eq!(value.get(), 0);
+        assert_eq!(result.get(), Err(NumberInputError::AboveMaximum(10)));
+
+        // Stepping from out of range text clamps to the allowed range.
+        let _ = recorder.window.press_key(NamedKey::ArrowUp);
+        assert_eq!(value.get(), 10);
+        assert_eq!(text.get(), "10");
+        assert_eq!(result.get(), Ok(10));
+
+        // Invalid text reports an error, and stepping continues from the last
+        // valid value.
+        recorder.window.type_text(&input, "x");
+        assert_eq!(text.get(), "10x");
+        assert_eq!(value.get(), 10);
+        assert_eq!(result.get(), Err(NumberInputError::Invalid));
+        let _ = recorder.window.press_key(NamedKey::ArrowDown);
+        assert_eq!(value.get(), 9);
+        assert_eq!(text.get(), "9");
+        assert_eq!(result.get(), Ok(9));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn custom_separators() {
+        let value = Dynamic::new(1234.5_f64);
+        let number = NumberInput::new(value.clone())
+            .grouped()
+            .separators(',', '.');
+        let mut recorder = number
+            .build_recorder()
+            .size(Size::new(UPx::new(300), UPx::new(100)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let input = recorder.window.find_widget::<Input>().expect("input");
+        let text = input
+            .lock()
+            .downcast_ref::<Input>()
+            .expect("input")
+            .value
+            .clone();
+        assert_eq!(text.get(), "1.234,5");
+
+        text.set(String::from("2.000,25"));
+        assert_eq!(value.get(), 2000.25);
+    }
+
+    #[test]
+    #[cfg(feature = "localization")]
+    fn locale_separators() {
+        use unic_langid::langid;
+
+        assert_eq!(
+            NumberFormat::for_locale(&langid!("en-US")),
+            NumberFormat::default()
+        );
+        assert_eq!(
+            NumberFormat::for_locale(&langid!("de-DE")),
+            NumberFormat {
+                decimal: ',',
+                grouping: '.',
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "localization")]
+    #[allow(clippy::float_cmp)]
+    fn localized_round_trip() {
+        use unic_langid::langid;
+
+        let value = Dynamic::new(1234.5_f64);
+        let locale = Dynamic::new(langid!("de-DE"));
+        let mut recorder = NumberInput::new(value.clone())
+            .grouped()
+            .localized_in(locale.clone())
+            .build_recorder()
+            .size(Size::new(UPx::new(300), UPx::new(100)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let input = recorder.window.find_widget::<Input>().expect("input");
+        let text = input
+            .lock()
+            .downcast_ref::<Input>()
+            .expect("input")
+            .value
+            .clone();
+        assert_eq!(text.get(), "1.234,5");
+
+        text.set(String::from("2.000,25"));
+        assert_eq!(value.get(), 2000.25);
+        value.set(1234.5);
+        assert_eq!(text.get(), "1.234,5");
+
+        // Changing the locale reformats the text.
+        locale.set(langid!("en-US"));
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(text.get(), "1,234.5");
+
+        // Explicit separators take precedence over the locale.
+        let mut recorder = NumberInput::new(value.clone())
+            .grouped()
+            .separators('.', '\'')
+            .localized_in(langid!("de-DE"))
+            .build_recorder()
+            .size(Size::new(UPx::new(300), UPx::new(100)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let input = recorder.window.find_widget::<Input>().expect("input");
+        let text = input
+            .lock()
+            .downcast_ref::<Input>()
+            .expect("input")
+            .value
+            .clone();
+        assert_eq!(text.get(), "1'234.5");
+    }
+}
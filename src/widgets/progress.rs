@@ -19,6 +19,8 @@ use crate::value::{
     TryLockError, Watcher,
 };
 use crate::widget::{MakeWidget, MakeWidgetWithTag, Widget, WidgetInstance};
+#[cfg(feature = "accessibility")]
+use crate::widget::{WidgetRef, WrapperWidget};
 use crate::widgets::slider::{InactiveTrackColor, Slidable, TrackColor, TrackSize};
 use crate::widgets::Data;
 
@@ -100,6 +102,8 @@ impl MakeWidgetWithTag for ProgressBar {
         let ease_in = ease_in_probe.value().clone();
         let ease_out_probe = EasingOut.probe_wrapping(ease_in_probe);
         let ease_out = ease_out_probe.value().clone();
+        #[cfg(feature = "accessibility")]
+        let ease_out_probe = AccessibleProgress::new(&self.progress, ease_out_probe);
         update_progress_bar(
             self.progress.get(),
             &mut indeterminant_animation,
@@ -136,6 +140,48 @@ impl MakeWidgetWithTag for ProgressBar {
     }
 }
 
+/// Describes a [`ProgressBar`] to assistive technologies.
+#[cfg(feature = "accessibility")]
+#[derive(Debug)]
+struct AccessibleProgress {
+    progress: ReadOnly<Progress>,
+    child: WidgetRef,
+}
+
+#[cfg(feature = "accessibility")]
+impl AccessibleProgress {
+    fn new(progress: &ReadOnly<Progress>, child: impl MakeWidget) -> Self {
+        let progress = match progress {
+            ReadOnly::Constant(progress) => ReadOnly::Constant(*progress),
+            ReadOnly::Reader(progress) => ReadOnly::Reader(progress.clone()),
+        };
+        Self {
+            progress,
+            child: WidgetRef::new(child),
+        }
+    }
+}
+
+#[cfg(feature = "accessibility")]
+impl WrapperWidget for AccessibleProgress {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn accessibility_node(
+        &mut self,
+        _context: &mut crate::context::WidgetContext<'_>,
+    ) -> Option<accesskit::Node> {
+        let mut node = accesskit::Node::new(accesskit::Role::ProgressIndicator);
+        if let Progress::Percent(percent) = self.progress.get() {
+            node.set_numeric_value(f64::from(*percent) * 100.);
+            node.set_min_numeric_value(0.);
+            node.set_max_numeric_value(100.);
+        }
+        Some(node)
+    }
+}
+
 #[derive(Debug)]
 struct IndeterminantAnimations {
     _primary: AnimationHandle,
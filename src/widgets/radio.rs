@@ -198,6 +198,22 @@ where
         let state = is_active || state;
         draw_radio(state, *colors, selected_color, region, context);
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&self) -> Option<accesskit::Node> {
+        Some(radio_node(self.is_selected()))
+    }
+}
+
+#[cfg(feature = "accessibility")]
+fn radio_node(selected: bool) -> accesskit::Node {
+    let mut node = accesskit::Node::new(accesskit::Role::RadioButton);
+    node.set_toggled(if selected {
+        accesskit::Toggled::True
+    } else {
+        accesskit::Toggled::False
+    });
+    node
 }
 
 fn draw_radio(
@@ -276,6 +292,11 @@ where
         let radio_size = context.get(&RadioSize).into_upx(context.gfx.scale());
         Size::squared(radio_size)
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(&mut self, _context: &mut WidgetContext<'_>) -> Option<accesskit::Node> {
+        Some(radio_node(self.state.map_ref(|state| state == &self.value)))
+    }
 }
 
 define_components! {
@@ -0,0 +1,792 @@
+//! A read-only text widget that displays a mix of styled text and links.
+
+use std::sync::Arc;
+
+use figures::units::{Px, UPx};
+use figures::{
+    FloatConversion, IntoSigned, IntoUnsigned, Point, Rect, Round, ScreenScale, Size, Zero,
+};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::cosmic_text::{self, Attrs, Buffer, FamilyOwned, Metrics, Shaping};
+use kludgine::shapes::Shape;
+use kludgine::text::TextOrigin;
+use kludgine::{Color, DrawableExt};
+
+use super::input::CowString;
+use super::label::LabelOverflow;
+use crate::context::{EventContext, FontSettings, GraphicsContext, LayoutContext, Trackable};
+use crate::styles::components::{HorizontalAlignment, TextColor, VerticalAlignment};
+use crate::styles::{Dimension, FontFamilyList, HorizontalAlign, Style, VerticalAlign, Weight};
+use crate::value::{Generation, IntoValue, Value};
+use crate::widget::{
+    EventHandling, MakeWidgetWithTag, SharedCallback, Widget, WidgetInstance, WidgetTag, HANDLED,
+    IGNORED,
+};
+use crate::window::{DeviceId, WindowLocal};
+use crate::{ConstraintLimit, FitMeasuredSize};
+
+/// Text made up of [`Span`]s that each have their own style.
+///
+/// ```rust
+/// use cushy::styles::Color;
+/// use cushy::widgets::rich_text::{RichText, Span};
+///
+/// let text = RichText::new()
+///     .with("Cushy supports ")
+///     .with(Span::new("bold").bold())
+///     .with(", ")
+///     .with(Span::new("italic").italic())
+///     .with(", and ")
+///     .with(Span::new("colored").color(Color::RED))
+///     .with(" text, as well as ")
+///     .with(Span::new("links").on_click(|()| println!("Clicked!")))
+///     .with(".");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RichText(Arc<Vec<Span>>);
+
+impl RichText {
+    /// Returns an empty rich text.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `span` to the end of this text.
+    pub fn push(&mut self, span: impl Into<Span>) {
+        Arc::make_mut(&mut self.0).push(span.into());
+    }
+
+    /// Adds `span` to the end of this text and returns self.
+    #[must_use]
+    pub fn with(mut self, span: impl Into<Span>) -> Self {
+        self.push(span);
+        self
+    }
+
+    /// Returns the spans that make up this text.
+    #[must_use]
+    pub fn spans(&self) -> &[Span] {
+        &self.0
+    }
+
+    /// Returns true if this text contains no spans.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the text of all spans combined.
+    #[must_use]
+    pub fn to_plain_text(&self) -> String {
+        self.0.iter().map(Span::text).collect()
+    }
+}
+
+impl<T> From<T> for RichText
+where
+    T: Into<Span>,
+{
+    fn from(span: T) -> Self {
+        Self::new().with(span)
+    }
+}
+
+impl From<Vec<Span>> for RichText {
+    fn from(spans: Vec<Span>) -> Self {
+        Self(Arc::new(spans))
+    }
+}
+
+impl FromIterator<Span> for RichText {
+    fn from_iter<T: IntoIterator<Item = Span>>(iter: T) -> Self {
+        Self(Arc::new(iter.into_iter().collect()))
+    }
+}
+
+impl MakeWidgetWithTag for RichText {
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        RichLabel::new(self).make_with_tag(tag)
+    }
+}
+
+/// A section of [`RichText`].
+///
+/// Each style setting that is not specified is inherited from the style
+/// components of the [`RichLabel`] displaying the text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Span {
+    text: CowString,
+    /// The font weight (boldness) of this span.
+    pub weight: Option<Weight>,
+    /// The font style of this span.
+    pub style: Option<Style>,
+    /// The font family of this span.
+    pub family: Option<FontFamilyList>,
+    /// The color of this span.
+    pub color: Option<Color>,
+    /// The font size of this span.
+    pub size: Option<Dimension>,
+    /// The callback invoked when this span is clicked.
+    ///
+    /// Spans with a callback are drawn as links.
+    pub on_click: Option<SharedCallback>,
+}
+
+impl Span {
+    /// Returns a new span containing `text`.
+    pub fn new(text: impl Into<CowString>) -> Self {
+        Self {
+            text: text.into(),
+            weight: None,
+            style: None,
+            family: None,
+            color: None,
+            size: None,
+            on_click: None,
+        }
+    }
+
+    /// Returns the text of this span.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Sets the font weight of this span and returns self.
+    #[must_use]
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    /// Sets this span's font weight to bold and returns self.
+    #[must_use]
+    pub fn bold(self) -> Self {
+        self.weight(Weight::BOLD)
+    }
+
+    /// Sets the font style of this span and returns self.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Sets this span's font style to italic and returns self.
+    #[must_use]
+    pub fn italic(self) -> Self {
+        self.style(Style::Italic)
+    }
+
+    /// Sets the font family of this span and returns self.
+    #[must_use]
+    pub fn family(mut self, family: impl Into<FontFamilyList>) -> Self {
+        self.family = Some(family.into());
+        self
+    }
+
+    /// Sets the color of this span and returns self.
+    #[must_use]
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the font size of this span and returns self.
+    #[must_use]
+    pub fn size(mut self, size: impl Into<Dimension>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Makes this span a link that invokes `on_click` when clicked, and
+    /// returns self.
+    #[must_use]
+    pub fn on_click<F>(mut self, on_click: F) -> Self
+    where
+        F: FnMut(()) + Send + 'static,
+    {
+        self.on_click = Some(SharedCallback::new(on_click));
+        self
+    }
+
+    /// Returns true if this span is a link.
+    #[must_use]
+    pub const fn is_link(&self) -> bool {
+        self.on_click.is_some()
+    }
+
+    /// Returns the attributes to shape this span with.
+    ///
+    /// `index` is stored in the attributes' metadata, offset by one to allow 0
+    /// to represent no span.
+    fn attrs<'a>(
+        &self,
+        index: usize,
+        defaults: Attrs<'a>,
+        family: Option<&'a FamilyOwned>,
+        key: &RichTextCacheKey,
+    ) -> Attrs<'a> {
+        let mut attrs = defaults.metadata(index + 1);
+        if let Some(weight) = self.weight {
+            attrs = attrs.weight(weight);
+        }
+        if let Some(style) = self.style {
+            attrs = attrs.style(style);
+        }
+        if let Some(family) = family {
+            attrs = attrs.family(family.as_family());
+        }
+        if let Some(color) = self.color {
+            attrs = attrs.color(cosmic_color(color));
+        } else if self.is_link() {
+            attrs = attrs.color(cosmic_color(key.link_color));
+        }
+        if let Some(size) = self.size {
+            let font_size = key.settings.size.into_px(key.scale).into_float();
+            let line_height = key.settings.line_height.into_px(key.scale).into_float();
+            let size = size.into_px(key.scale).into_float();
+            attrs = attrs.metrics(Metrics::new(size, line_height / font_size * size));
+        }
+        attrs
+    }
+}
+
+impl From<&'_ str> for Span {
+    fn from(text: &'_ str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for Span {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<CowString> for Span {
+    fn from(text: CowString) -> Self {
+        Self::new(text)
+    }
+}
+
+/// A read-only widget that displays [`RichText`].
+///
+/// Text is wrapped in the same way as a [`Label`](super::Label), and the
+/// [`HorizontalAlignment`] and [`VerticalAlignment`] components are honored.
+/// Links are drawn using [`LinkColor`] and are underlined.
+#[derive(Debug)]
+pub struct RichLabel {
+    /// The contents of the label.
+    pub text: Value<RichText>,
+    /// The behavior to use when too much text is able to be displayed on a
+    /// single line.
+    pub overflow: Value<LabelOverflow>,
+    prepared: WindowLocal<RichTextCache>,
+    pressed_link: Option<usize>,
+}
+
+impl RichLabel {
+    /// Returns a new label that displays `text`, wrapping if necessary to fit
+    /// the content in the provided space.
+    pub fn new(text: impl IntoValue<RichText>) -> Self {
+        Self {
+            text: text.into_value(),
+            overflow: Value::Constant(LabelOverflow::WordWrap),
+            prepared: WindowLocal::default(),
+            pressed_link: None,
+        }
+    }
+
+    /// Sets the behavior when more text than can fit on a single line is
+    /// displayed.
+    #[must_use]
+    pub fn overflow(mut self, overflow: impl IntoValue<LabelOverflow>) -> Self {
+        self.overflow = overflow.into_value();
+        self
+    }
+
+    fn prepare(
+        &mut self,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+        width: Px,
+        align: HorizontalAlign,
+    ) -> &RichTextCache {
+        let align = match align {
+            HorizontalAlign::Left => cosmic_text::Align::Left,
+            HorizontalAlign::Center => cosmic_text::Align::Center,
+            HorizontalAlign::Right => cosmic_text::Align::Right,
+        };
+        let width = (self.overflow.get_tracking_invalidate(context) == LabelOverflow::WordWrap)
+            .then_some(width);
+        context.apply_current_font_settings();
+        let key = RichTextCacheKey {
+            generation: self.text.generation(),
+            width,
+            settings: context.current_font_settings(),
+            link_color: context.get(&LinkColor),
+            align,
+            scale: context.gfx.scale(),
+        };
+
+        if !matches!(self.prepared.get(context), Some(cache) if cache.key == key) {
+            let text = self.text.get();
+            let buffer = build_buffer(&text, &key, context);
+            self.prepared.set(context, RichTextCache::new(buffer, key));
+        }
+
+        self.prepared.get(context).expect("always initialized")
+    }
+
+    fn link_at(&self, location: Point<Px>, context: &EventContext<'_>) -> Option<usize> {
+        let cache = self.prepared.get(context)?;
+        let location = location - cache.offset;
+        let index = cache.span_at(location.x.into_float(), location.y.into_float())?;
+        self.text
+            .map(|text| text.spans().get(index).is_some_and(Span::is_link))
+            .then_some(index)
+    }
+}
+
+impl Widget for RichLabel {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        self.text.invalidate_when_changed(context);
+
+        let align = context.get(&HorizontalAlignment);
+        let text_color = context.get(&TextColor);
+        let width = context.gfx.region().size.width;
+        self.prepare(context, width, align);
+        let cache = self.prepared.get(context).expect("always initialized");
+        let offset = cache.offset;
+
+        context.gfx.draw_text_buffer(
+            (&cache.buffer).translate_by(offset),
+            text_color,
+            TextOrigin::TopLeft,
+        );
+
+        let (link_color, underline) = (cache.key.link_color, cache.underline_thickness());
+        let spans = self.text.get();
+        for (origin, span_index, width) in cache.link_segments(&spans) {
+            let color = spans.spans()[span_index].color.unwrap_or(link_color);
+            let origin = Point::new(Px::from_float(origin.x), Px::from_float(origin.y));
+            context.gfx.draw_shape(&Shape::filled_rect(
+                Rect::new(origin + offset, Size::new(Px::from_float(width), underline)),
+                color,
+            ));
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        let align = context.get(&HorizontalAlignment);
+        let valign = context.get(&VerticalAlignment);
+        let width = available_space.width.max().try_into().unwrap_or(Px::MAX);
+        let measured = self.prepare(context, width, align).size;
+        let size = available_space.fit_measured(measured.into_unsigned().ceil());
+
+        let height = size.height.into_signed();
+        let offset = Point::new(
+            Px::ZERO,
+            match valign {
+                VerticalAlign::Top => Px::ZERO,
+                VerticalAlign::Center => (height - measured.height) / 2,
+                VerticalAlign::Bottom => height - measured.height,
+            },
+        );
+        let cache = self.prepared.get_mut(context).expect("always initialized");
+        cache.offset = offset;
+
+        size
+    }
+
+    fn hit_test(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> bool {
+        self.link_at(location, context).is_some()
+    }
+
+    fn hover(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> Option<CursorIcon> {
+        self.link_at(location, context).map(|_| CursorIcon::Pointer)
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left {
+            return IGNORED;
+        }
+        self.pressed_link = self.link_at(location, context);
+        if self.pressed_link.is_some() {
+            HANDLED
+        } else {
+            IGNORED
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        let Some(pressed) = self.pressed_link.take() else {
+            return;
+        };
+        if location.and_then(|location| self.link_at(location, context)) == Some(pressed) {
+            let on_click = self.text.map(|text| {
+                text.spans()
+                    .get(pressed)
+                    .and_then(|span| span.on_click.clone())
+            });
+            if let Some(on_click) = on_click {
+                on_click.invoke(());
+            }
+        }
+    }
+
+    fn summarize(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt.debug_tuple("RichLabel").field(&self.text).finish()
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        self.prepared.clear_for(context);
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(
+        &mut self,
+        _context: &mut crate::context::WidgetContext<'_>,
+    ) -> Option<accesskit::Node> {
+        let mut node = accesskit::Node::new(accesskit::Role::Label);
+        node.set_value(self.text.map(RichText::to_plain_text));
+        Some(node)
+    }
+}
+
+/// Creates a shaped text buffer containing `text`.
+fn build_buffer(
+    text: &RichText,
+    key: &RichTextCacheKey,
+    context: &mut GraphicsContext<'_, '_, '_, '_>,
+) -> Buffer {
+    let font_size = key.settings.size.into_px(key.scale).into_float();
+    let line_height = key.settings.line_height.into_px(key.scale).into_float();
+    let default_family = context.find_available_font_family(&key.settings.family);
+    let families = text
+        .spans()
+        .iter()
+        .map(|span| {
+            span.family
+                .as_ref()
+                .and_then(|family| context.find_available_font_family(family))
+        })
+        .collect::<Vec<_>>();
+
+    let mut default_attrs = Attrs::new()
+        .weight(key.settings.weight)
+        .style(key.settings.style);
+    if let Some(family) = &default_family {
+        default_attrs = default_attrs.family(family.as_family());
+    }
+
+    let font_system = context.gfx.font_system();
+    let mut buffer = Buffer::new(font_system, Metrics::new(font_size, line_height));
+    buffer.set_size(font_system, key.width.map(Px::into_float), None);
+
+    let spans = text.spans().iter().enumerate().map(|(index, span)| {
+        (
+            span.text(),
+            span.attrs(index, default_attrs, families[index].as_ref(), key),
+        )
+    });
+    buffer.set_rich_text(font_system, spans, default_attrs, Shaping::Advanced);
+    for line in &mut buffer.lines {
+        line.set_align(Some(key.align));
+    }
+    buffer.shape_until_scroll(font_system, false);
+    buffer
+}
+
+fn cosmic_color(color: Color) -> cosmic_text::Color {
+    cosmic_text::Color::rgba(color.red(), color.green(), color.blue(), color.alpha())
+}
+
+#[derive(Debug)]
+struct RichTextCache {
+    buffer: Buffer,
+    key: RichTextCacheKey,
+    size: Size<Px>,
+    offset: Point<Px>,
+}
+
+impl RichTextCache {
+    fn new(buffer: Buffer, key: RichTextCacheKey) -> Self {
+        let (width, height) = buffer.layout_runs().fold((0., 0.), |(width, height), run| {
+            (
+                f32::max(width, run.line_w),
+                f32::max(height, run.line_top + run.line_height),
+            )
+        });
+        Self {
+            buffer,
+            key,
+            size: Size::new(Px::from_float(width), Px::from_float(height)).ceil(),
+            offset: Point::default(),
+        }
+    }
+
+    /// Returns the index of the span at `x`,`y`.
+    fn span_at(&self, x: f32, y: f32) -> Option<usize> {
+        self.buffer
+            .layout_runs()
+            .filter(|run| y >= run.line_top && y < run.line_top + run.line_height)
+            .flat_map(|run| run.glyphs.iter())
+            .find(|glyph| x >= glyph.x && x < glyph.x + glyph.w)
+            .and_then(|glyph| glyph.metadata.checked_sub(1))
+    }
+
+    fn underline_thickness(&self) -> Px {
+        (self.key.settings.size.into_px(self.key.scale) / 16)
+            .round()
+            .max(Px::new(1))
+    }
+
+    /// Returns the location, span index, and width of each horizontal segment
+    /// of a link's underline.
+    fn link_segments(&self, text: &RichText) -> Vec<(Point<f32>, usize, f32)> {
+        let underline_offset = self.underline_thickness().into_float();
+        let mut segments = Vec::new();
+        for run in self.buffer.layout_runs() {
+            // While building a segment, the width holds the segment's right
+            // edge.
+            let mut current: Option<(Point<f32>, usize, f32)> = None;
+            for glyph in run.glyphs {
+                let span = glyph
+                    .metadata
+                    .checked_sub(1)
+                    .filter(|index| text.spans().get(*index).is_some_and(Span::is_link));
+                match (&mut current, span) {
+                    (Some((_, current_span, right)), Some(span)) if *current_span == span => {
+                        *right = right.max(glyph.x + glyph.w);
+                    }
+                    (_, span) => {
+                        segments.extend(current.take());
+                        current = span.map(|span| {
+                            (
+                                Point::new(glyph.x, run.line_y + underline_offset),
+                                span,
+                                glyph.x + glyph.w,
+                            )
+                        });
+                    }
+                }
+            }
+            segments.extend(current);
+        }
+        for (origin, _, right) in &mut segments {
+            *right -= origin.x;
+        }
+        segments
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct RichTextCacheKey {
+    generation: Option<Generation>,
+    width: Option<Px>,
+    settings: FontSettings,
+    link_color: Color,
+    align: cosmic_text::Align,
+    scale: figures::Fraction,
+}
+
+define_components! {
+    RichLabel {
+        /// The [`Color`] of links in a [`RichLabel`].
+        LinkColor(Color, "link_color", .primary.color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Fraction, Point, Size};
+    use kludgine::app::winit::event::{ElementState, MouseButton};
+    use kludgine::cosmic_text::{self, Attrs, Metrics};
+    use kludgine::Color;
+
+    use super::{cosmic_color, RichLabel, RichText, RichTextCacheKey, Span};
+    use crate::context::FontSettings;
+    use crate::styles::{Dimension, FontFamilyList, Style, Weight};
+    use crate::value::{Dynamic, Source};
+    use crate::widget::MakeWidget;
+    use crate::widgets::label::LabelOverflow;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    fn cache_key() -> RichTextCacheKey {
+        RichTextCacheKey {
+            generation: None,
+            width: None,
+            settings: FontSettings {
+                family: FontFamilyList::default(),
+                size: Dimension::Px(Px::new(10)),
+                line_height: Dimension::Px(Px::new(15)),
+                style: Style::Normal,
+                weight: Weight::NORMAL,
+            },
+            link_color: Color::BLUE,
+            align: cosmic_text::Align::Left,
+            scale: Fraction::ONE,
+        }
+    }
+
+    #[test]
+    fn span_attributes() {
+        let key = cache_key();
+
+        assert_eq!(
+            Span::new("plain").attrs(0, Attrs::new(), None, &key),
+            Attrs::new().metadata(1)
+        );
+        assert_eq!(
+            Span::new("styled").bold().italic().color(Color::RED).attrs(
+                1,
+                Attrs::new(),
+                None,
+                &key
+            ),
+            Attrs::new()
+                .metadata(2)
+                .weight(Weight::BOLD)
+                .style(Style::Italic)
+                .color(cosmic_color(Color::RED))
+        );
+        // Links are drawn using the link color unless a color is specified.
+        assert_eq!(
+            Span::new("link")
+                .on_click(|()| {})
+                .attrs(2, Attrs::new(), None, &key),
+            Attrs::new().metadata(3).color(cosmic_color(Color::BLUE))
+        );
+        assert_eq!(
+            Span::new("link").on_click(|()| {}).color(Color::RED).attrs(
+                2,
+                Attrs::new(),
+                None,
+                &key
+            ),
+            Attrs::new().metadata(3).color(cosmic_color(Color::RED))
+        );
+        // Sizes keep the line height proportional to the font size.
+        assert_eq!(
+            Span::new("large")
+                .size(Px::new(20))
+                .attrs(3, Attrs::new(), None, &key),
+            Attrs::new().metadata(4).metrics(Metrics::new(20., 30.))
+        );
+    }
+
+    fn recorder(label: impl MakeWidget) -> VirtualRecorder {
+        let mut recorder = label
+            .build_recorder()
+            .size(Size::new(UPx::new(400), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        recorder
+    }
+
+    fn click(recorder: &mut VirtualRecorder, location: Point<Px>) {
+        let window = &mut recorder.window;
+        window.cursor_moved(DeviceId::Virtual(0), location);
+        let _ = window.mouse_input(
+            DeviceId::Virtual(0),
+            ElementState::Pressed,
+            MouseButton::Left,
+        );
+        let _ = window.mouse_input(
+            DeviceId::Virtual(0),
+            ElementState::Released,
+            MouseButton::Left,
+        );
+    }
+
+    #[test]
+    fn link_hit_testing() {
+        let clicks = Dynamic::new(0_usize);
+        let text = RichText::new()
+            .with("plain text ")
+            .with(Span::new("link").on_click({
+                let clicks = clicks.clone();
+                move |()| *clicks.lock() += 1
+            }));
+        let mut recorder = recorder(RichLabel::new(text).align_left().align_top());
+        let layout = recorder
+            .window
+            .find_widget::<RichLabel>()
+            .and_then(|label| label.last_layout())
+            .expect("label should be laid out");
+        let middle = layout.origin.y + layout.size.height / 2;
+
+        // The link is the last span, at the end of the line.
+        click(
+            &mut recorder,
+            Point::new(layout.origin.x + layout.size.width - 2, middle),
+        );
+        assert_eq!(clicks.get(), 1);
+
+        // Clicking the plain text doesn't invoke the link.
+        click(&mut recorder, Point::new(layout.origin.x + 3, middle));
+        assert_eq!(clicks.get(), 1);
+
+        // Clicking outside of the text doesn't invoke the link.
+        click(
+            &mut recorder,
+            Point::new(
+                layout.origin.x + layout.size.width - 2,
+                layout.origin.y + layout.size.height + 20,
+            ),
+        );
+        assert_eq!(clicks.get(), 1);
+    }
+
+    fn label_size(overflow: LabelOverflow) -> Size<Px> {
+        let text = "This text is long enough that it needs to wrap across several lines.";
+        let recorder = recorder(
+            RichLabel::new(RichText::from(text))
+                .overflow(overflow)
+                .width(Px::new(100))
+                .align_left()
+                .align_top(),
+        );
+        recorder
+            .window
+            .find_widget::<RichLabel>()
+            .and_then(|label| label.last_layout())
+            .expect("label should be laid out")
+            .size
+    }
+
+    #[test]
+    fn word_wrap_width() {
+        let wrapped = label_size(LabelOverflow::WordWrap);
+        let clipped = label_size(LabelOverflow::Clip);
+        assert!(
+            wrapped.width <= Px::new(100),
+            "{wrapped:?} wider than 100px"
+        );
+        assert!(
+            wrapped.height > clipped.height,
+            "{wrapped:?} not taller than {clipped:?}"
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! A container that scrolls its contents on a virtual surface.
 
 use std::mem;
-use std::time::Duration;
+use std::ops::ControlFlow;
+use std::time::{Duration, Instant};
 
 use figures::units::{Lp, Px, UPx};
 use figures::{
@@ -14,7 +15,9 @@ use kludgine::app::winit::window::CursorIcon;
 use kludgine::shapes::{CornerRadii, Shape};
 use kludgine::Color;
 
-use crate::animation::{AnimationHandle, AnimationTarget, IntoAnimate, Spawn, ZeroToOne};
+use crate::animation::{
+    AnimationHandle, AnimationTarget, EasingFunction, IntoAnimate, Spawn, ZeroToOne,
+};
 use crate::context::{AsEventContext, EventContext, LayoutContext};
 use crate::styles::components::{EasingIn, EasingOut, LineHeight, PrimaryColor, SurfaceColor};
 use crate::styles::Dimension;
@@ -22,6 +25,7 @@ use crate::value::{
     Destination, Dynamic, DynamicReader, IntoDynamic, IntoValue, MapEachCloned, Source, Value,
 };
 use crate::widget::{EventHandling, MakeWidget, Widget, WidgetId, WidgetRef, HANDLED, IGNORED};
+use crate::widgets::gestures::{Gesture, GesturePhase, GestureRecognizer};
 use crate::window::DeviceId;
 use crate::ConstraintLimit;
 
@@ -116,6 +120,8 @@ pub struct Scroll {
     max_scroll: DynamicReader<Point<UPx>>,
     vertical_widget: OwnedWidget<ScrollBar>,
     horizontal_widget: OwnedWidget<ScrollBar>,
+    scroll_animation: AnimationHandle,
+    gestures: GestureRecognizer,
 }
 
 #[derive(Debug)]
@@ -173,6 +179,8 @@ impl Scroll {
             max_scroll,
             horizontal_widget: OwnedWidget::new(horizontal),
             vertical_widget: OwnedWidget::new(vertical),
+            scroll_animation: AnimationHandle::default(),
+            gestures: GestureRecognizer::new(),
         }
     }
 
@@ -236,6 +244,85 @@ impl Scroll {
             .expect("a ScrollBar")
             .show(context);
     }
+
+    /// Returns true if the contents can be scrolled on any enabled axis.
+    fn can_scroll(&self) -> bool {
+        let max_scroll = self.max_scroll.get();
+        (self.enabled.x && max_scroll.x > UPx::ZERO) || (self.enabled.y && max_scroll.y > UPx::ZERO)
+    }
+
+    fn pan_by(&mut self, delta: Point<Px>, context: &mut EventContext<'_>) {
+        if pan_scroll(&self.scroll, self.max_scroll.get(), self.enabled, delta) {
+            self.show_scrollbars(context);
+            context.set_needs_redraw();
+        }
+    }
+
+    /// Continues scrolling after a pan ends, starting at `velocity` and
+    /// slowing down until the contents come to rest.
+    fn fling(&mut self, velocity: Point<f32>) {
+        // The fraction of the velocity that remains after one second.
+        const FRICTION: f32 = 0.05;
+        // Velocities below this many pixels per second stop the animation.
+        const MINIMUM_VELOCITY: f32 = 10.;
+
+        let scroll = self.scroll.clone();
+        let max_scroll = self.max_scroll.get();
+        let enabled = self.enabled;
+        let mut velocity = velocity;
+        let mut remainder = Point::<f32>::default();
+        self.scroll_animation = (move |elapsed: Duration| {
+            let seconds = elapsed.as_secs_f32();
+            remainder.x += velocity.x * seconds;
+            remainder.y += velocity.y * seconds;
+            let delta = Point::new(Px::from(remainder.x.trunc()), Px::from(remainder.y.trunc()));
+            remainder.x -= delta.x.into_float();
+            remainder.y -= delta.y.into_float();
+
+            let decay = FRICTION.powf(seconds);
+            velocity.x *= decay;
+            velocity.y *= decay;
+
+            let stopped =
+                delta != Point::default() && !pan_scroll(&scroll, max_scroll, enabled, delta);
+            if stopped || velocity.x.hypot(velocity.y) < MINIMUM_VELOCITY {
+                ControlFlow::Break(Duration::ZERO)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .spawn();
+    }
+}
+
+/// Scrolls `scroll` as if the contents were dragged by `delta`, returning true
+/// if the scroll position changed.
+fn pan_scroll(
+    scroll: &Dynamic<Point<UPx>>,
+    max_scroll: Point<UPx>,
+    enabled: Point<bool>,
+    delta: Point<Px>,
+) -> bool {
+    let current = scroll.get();
+    let mut target = current;
+    if enabled.x {
+        target.x = (current.x.into_signed() - delta.x)
+            .max(Px::ZERO)
+            .into_unsigned()
+            .min(max_scroll.x);
+    }
+    if enabled.y {
+        target.y = (current.y.into_signed() - delta.y)
+            .max(Px::ZERO)
+            .into_unsigned()
+            .min(max_scroll.y);
+    }
+    if target == current {
+        false
+    } else {
+        scroll.set(target);
+        true
+    }
 }
 
 impl Widget for Scroll {
@@ -383,6 +470,7 @@ impl Widget for Scroll {
         context: &mut EventContext<'_>,
     ) -> EventHandling {
         let mut handled = false;
+        self.scroll_animation.clear();
         {
             let mut vertical = self.vertical_widget.expect_made().widget().lock();
             handled |= vertical
@@ -407,12 +495,113 @@ impl Widget for Scroll {
         }
     }
 
+    fn touch_down(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if !self.can_scroll() {
+            return IGNORED;
+        }
+        self.scroll_animation.clear();
+        self.gestures.set_scale(context.kludgine.scale());
+        self.gestures
+            .touch_down(device_id, location, Instant::now());
+        HANDLED
+    }
+
+    fn touch_moved(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) {
+        if let Some(Gesture::Pan(pan)) =
+            self.gestures
+                .touch_moved(device_id, location, Instant::now())
+        {
+            self.pan_by(pan.delta, context);
+        }
+    }
+
+    fn touch_up(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        cancelled: bool,
+        context: &mut EventContext<'_>,
+    ) {
+        match self
+            .gestures
+            .touch_up(device_id, location, cancelled, Instant::now())
+        {
+            Some(Gesture::Pan(pan)) => {
+                self.pan_by(pan.delta, context);
+                if pan.phase == GesturePhase::Ended {
+                    self.fling(pan.velocity);
+                }
+            }
+            // Scrolling claims all touches, so taps are forwarded to the
+            // contents as clicks.
+            Some(Gesture::Tap(location) | Gesture::DoubleTap(location)) => {
+                context.click_child_at(location, device_id);
+            }
+            _ => {}
+        }
+    }
+
+    fn scroll_into_view(
+        &mut self,
+        region: Rect<Px>,
+        options: &ScrollIntoView,
+        context: &mut EventContext<'_>,
+    ) -> Rect<Px> {
+        let Some(viewport) = context.last_layout() else {
+            return region;
+        };
+        let scrolled = scroll_region_into_view(
+            &self.scroll,
+            &mut self.scroll_animation,
+            self.max_scroll.get(),
+            self.enabled,
+            viewport,
+            region,
+            options,
+        );
+        if scrolled != region {
+            self.show_scrollbars(context);
+        }
+        scrolled
+    }
+
     fn summarize(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         fmt.debug_struct("Scroll")
             .field("enabled", &self.enabled)
             .field("contents", &self.contents)
             .finish()
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(
+        &mut self,
+        _context: &mut crate::context::WidgetContext<'_>,
+    ) -> Option<accesskit::Node> {
+        let mut node = accesskit::Node::new(accesskit::Role::ScrollView);
+        let scroll = self.scroll.get();
+        let max_scroll = self.max_scroll.get();
+        if self.enabled.x {
+            node.set_scroll_x(f64::from(scroll.x.into_float()));
+            node.set_scroll_x_min(0.);
+            node.set_scroll_x_max(f64::from(max_scroll.x.into_float()));
+        }
+        if self.enabled.y {
+            node.set_scroll_y(f64::from(scroll.y.into_float()));
+            node.set_scroll_y_min(0.);
+            node.set_scroll_y_max(f64::from(max_scroll.y.into_float()));
+        }
+        Some(node)
+    }
 }
 
 #[derive(Default, Debug)]
@@ -476,6 +665,145 @@ impl DragInfo {
     }
 }
 
+/// Describes where a region should be positioned when it is scrolled into
+/// view.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum ScrollAlign {
+    /// Scroll the least amount needed to show the region. Regions that are
+    /// already fully visible are not scrolled.
+    #[default]
+    Nearest,
+    /// Align the region with the top or left edge of the visible area.
+    Start,
+    /// Align the center of the region with the center of the visible area.
+    Center,
+    /// Align the region with the bottom or right edge of the visible area.
+    End,
+}
+
+/// Options for scrolling a widget into view.
+///
+/// See [`WidgetContext::scroll_into_view()`](crate::context::WidgetContext::scroll_into_view).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ScrollIntoView {
+    /// How the region is aligned within each scrolling ancestor.
+    pub align: ScrollAlign,
+    /// When present, scrolling is animated over the duration using the easing
+    /// function.
+    pub animation: Option<(Duration, EasingFunction)>,
+}
+
+impl ScrollIntoView {
+    /// Returns options that scroll the least amount needed without animating.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            align: ScrollAlign::Nearest,
+            animation: None,
+        }
+    }
+
+    /// Sets how the region is aligned and returns self.
+    #[must_use]
+    pub const fn align(mut self, align: ScrollAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Animates scrolling over `duration` using `easing`, and returns self.
+    #[must_use]
+    pub fn animated(mut self, duration: Duration, easing: impl Into<EasingFunction>) -> Self {
+        self.animation = Some((duration, easing.into()));
+        self
+    }
+}
+
+/// Updates `scroll` so that `region` is visible within `viewport`, returning
+/// the location of `region` once scrolling completes.
+///
+/// Both rectangles are in window coordinates.
+pub(crate) fn scroll_region_into_view(
+    scroll: &Dynamic<Point<UPx>>,
+    animation: &mut AnimationHandle,
+    max_scroll: Point<UPx>,
+    enabled: Point<bool>,
+    viewport: Rect<Px>,
+    region: Rect<Px>,
+    options: &ScrollIntoView,
+) -> Rect<Px> {
+    let current = scroll.get().into_signed();
+    let relative = region.origin - viewport.origin;
+    let mut target = current;
+    if enabled.x {
+        target.x = scroll_to_show(
+            current.x,
+            relative.x,
+            region.size.width,
+            viewport.size.width,
+            max_scroll.x.into_signed(),
+            options.align,
+        );
+    }
+    if enabled.y {
+        target.y = scroll_to_show(
+            current.y,
+            relative.y,
+            region.size.height,
+            viewport.size.height,
+            max_scroll.y.into_signed(),
+            options.align,
+        );
+    }
+
+    if target != current {
+        let new_scroll = target.into_unsigned();
+        if let Some((duration, easing)) = &options.animation {
+            *animation = scroll
+                .transition_to(new_scroll)
+                .over(*duration)
+                .with_easing(easing.clone())
+                .spawn();
+        } else {
+            animation.clear();
+            scroll.set(new_scroll);
+        }
+    }
+
+    Rect::new(region.origin - (target - current), region.size)
+}
+
+/// Returns the scroll offset along a single axis that shows the region
+/// starting at `start` with `length`, where `start` is relative to the visible
+/// area of size `visible`.
+pub(crate) fn scroll_to_show(
+    scroll: Px,
+    start: Px,
+    length: Px,
+    visible: Px,
+    max_scroll: Px,
+    align: ScrollAlign,
+) -> Px {
+    let end = start + length;
+    let content_start = scroll + start;
+    let target = match align {
+        ScrollAlign::Start => content_start,
+        ScrollAlign::Center => content_start + (length - visible) / 2,
+        ScrollAlign::End => content_start + length - visible,
+        ScrollAlign::Nearest => {
+            if (start >= Px::ZERO && end <= visible) || (start <= Px::ZERO && end >= visible) {
+                // The region is fully visible, or it is filling the visible
+                // area.
+                scroll
+            } else if start < Px::ZERO || length > visible {
+                content_start
+            } else {
+                content_start + length - visible
+            }
+        }
+    };
+    target.min(max_scroll).max(Px::ZERO)
+}
+
 fn constrain_child(constraint: ConstraintLimit, measured: UPx) -> UPx {
     match constraint {
         ConstraintLimit::Fill(size) => size.min(measured),
@@ -930,3 +1258,170 @@ define_components! {
         ScrollBarThumbCornerRadius(CornerRadii<Dimension>, "corner_radius", |context| CornerRadii::from(context.get(&ScrollBarThickness)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use figures::units::{Px, UPx};
+    use figures::{Point, Px2D, Size};
+    use kludgine::app::winit::event::{Modifiers, TouchPhase};
+    use kludgine::app::winit::keyboard::{ModifiersState, NamedKey};
+
+    use super::{scroll_to_show, Scroll, ScrollAlign};
+    use crate::value::{Dynamic, Source};
+    use crate::widget::MakeWidget;
+    use crate::widgets::input::InputValue;
+    use crate::widgets::Space;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    const FINGER: DeviceId = DeviceId::finger(None, 0);
+
+    /// Returns a recorder showing a 200px square scroll view of a 1,000px tall
+    /// space, and the scroll view's offset.
+    fn tall_scroll() -> (VirtualRecorder, Dynamic<Point<UPx>>) {
+        let scroll = Scroll::vertical(Space::clear().height(Px::new(1000)));
+        let offset = scroll.scroll.clone();
+        let mut recorder = scroll
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        (recorder, offset)
+    }
+
+    #[test]
+    fn nearest() {
+        let show = |start: i32, length: i32| {
+            scroll_to_show(
+                Px::new(100),
+                Px::new(start),
+                Px::new(length),
+                Px::new(50),
+                Px::new(500),
+                ScrollAlign::Nearest,
+            )
+        };
+        // Fully visible regions do not scroll.
+        assert_eq!(show(10, 20), Px::new(100));
+        // Regions above or below the visible area scroll the minimum amount.
+        assert_eq!(show(-30, 20), Px::new(70));
+        assert_eq!(show(40, 20), Px::new(110));
+        // Regions larger than the visible area are aligned to their start,
+        // unless they already fill the visible area.
+        assert_eq!(show(10, 100), Px::new(110));
+        assert_eq!(show(-10, 100), Px::new(100));
+    }
+
+    #[test]
+    fn aligned() {
+        let show = |start: i32, align: ScrollAlign| {
+            scroll_to_show(
+                Px::new(100),
+                Px::new(start),
+                Px::new(10),
+                Px::new(50),
+                Px::new(500),
+                align,
+            )
+        };
+        assert_eq!(show(20, ScrollAlign::Start), Px::new(120));
+        assert_eq!(show(20, ScrollAlign::Center), Px::new(100));
+        assert_eq!(show(20, ScrollAlign::End), Px::new(80));
+        // The scroll is clamped to the valid range.
+        assert_eq!(show(-200, ScrollAlign::Start), Px::ZERO);
+        assert_eq!(show(600, ScrollAlign::Start), Px::new(500));
+    }
+
+    #[test]
+    fn touch_panning() {
+        let (mut recorder, offset) = tall_scroll();
+        let window = &mut recorder.window;
+        window.touch(FINGER, TouchPhase::Started, Point::px(100, 150));
+        // Dragging the contents up scrolls down.
+        window.touch(FINGER, TouchPhase::Moved, Point::px(100, 100));
+        assert_eq!(offset.get(), Point::new(UPx::ZERO, UPx::new(50)));
+        window.touch(FINGER, TouchPhase::Moved, Point::px(120, 80));
+        assert_eq!(offset.get(), Point::new(UPx::ZERO, UPx::new(70)));
+        // Dragging the contents down scrolls up, but not past the start.
+        window.touch(FINGER, TouchPhase::Moved, Point::px(120, 190));
+        assert_eq!(offset.get(), Point::default());
+        window.touch(FINGER, TouchPhase::Moved, Point::px(120, 130));
+        assert_eq!(offset.get(), Point::new(UPx::ZERO, UPx::new(60)));
+
+        // Lifting a finger that has stopped moving does not fling.
+        std::thread::sleep(Duration::from_millis(150));
+        window.touch(FINGER, TouchPhase::Ended, Point::px(120, 130));
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(offset.get(), Point::new(UPx::ZERO, UPx::new(60)));
+    }
+
+    #[test]
+    fn touch_fling() {
+        let (mut recorder, offset) = tall_scroll();
+        let window = &mut recorder.window;
+        window.touch(FINGER, TouchPhase::Started, Point::px(100, 190));
+        for y in [160, 130, 100] {
+            std::thread::sleep(Duration::from_millis(10));
+            window.touch(FINGER, TouchPhase::Moved, Point::px(100, y));
+        }
+        window.touch(FINGER, TouchPhase::Ended, Point::px(100, 100));
+        let released = offset.get().y;
+        assert_eq!(released, UPx::new(90));
+
+        // The contents continue scrolling after the finger is lifted.
+        std::thread::sleep(Duration::from_millis(100));
+        let flung = offset.get().y;
+        assert!(flung > released, "{flung:?} <= {released:?}");
+
+        // Touching the contents again stops the fling.
+        window.touch(FINGER, TouchPhase::Started, Point::px(100, 100));
+        let stopped = offset.get();
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(offset.get(), stopped);
+        window.touch(FINGER, TouchPhase::Ended, Point::px(100, 100));
+    }
+
+    #[test]
+    fn focus_scrolls_into_view() {
+        let top = String::from("top").into_input().make_widget();
+        let bottom = String::from("bottom").into_input().make_widget();
+        let scroll = Scroll::vertical(
+            top.clone()
+                .and(Space::clear().height(Px::new(1000)))
+                .and(bottom.clone())
+                .into_rows(),
+        );
+        let offset = scroll.scroll.clone();
+        let mut recorder = scroll
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let top = recorder.window.widget(top.id()).expect("top input");
+        let bottom = recorder.window.widget(bottom.id()).expect("bottom input");
+        recorder.window.focus(&top);
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(offset.get(), Point::default());
+
+        // Tabbing to a widget outside of the viewport scrolls it into view.
+        let _ = recorder.window.press_key(NamedKey::Tab);
+        recorder.refresh().expect("error refreshing");
+        recorder.window.assert_focused(&bottom);
+        let layout = bottom.last_layout().expect("bottom laid out");
+        assert!(offset.get().y > UPx::ZERO);
+        assert!(layout.origin.y >= Px::ZERO);
+        assert!(layout.origin.y + layout.size.height <= Px::new(200));
+
+        recorder
+            .window
+            .set_modifiers(Modifiers::from(ModifiersState::SHIFT));
+        let _ = recorder.window.press_key(NamedKey::Tab);
+        recorder.window.set_modifiers(Modifiers::default());
+        recorder.refresh().expect("error refreshing");
+        recorder.window.assert_focused(&top);
+        assert_eq!(offset.get(), Point::default());
+    }
+}
@@ -733,6 +733,50 @@ where
             .field("max", &self.maximum)
             .finish()
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_node(
+        &mut self,
+        _context: &mut crate::context::WidgetContext<'_>,
+    ) -> Option<accesskit::Node> {
+        if !self.interactive {
+            return None;
+        }
+
+        let mut node = accesskit::Node::new(accesskit::Role::Slider);
+        let value = self.value.get();
+        let (start, _) = value.clone().into_parts();
+        let percent = start.percent_between(&self.minimum.get(), &self.maximum.get());
+        node.set_value(format!("{value:?}"));
+        node.set_numeric_value(f64::from(*percent) * 100.);
+        node.set_min_numeric_value(0.);
+        node.set_max_numeric_value(100.);
+        node.set_numeric_value_step(f64::from(*self.step.get()) * 100.);
+        node.add_action(accesskit::Action::Increment);
+        node.add_action(accesskit::Action::Decrement);
+        if self.knob_visible {
+            node.add_action(accesskit::Action::Focus);
+        }
+        Some(node)
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_action(
+        &mut self,
+        request: &accesskit::ActionRequest,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if !self.interactive || request.target != accesskit::NodeId::from(context.widget().id()) {
+            return IGNORED;
+        }
+        let forwards = match request.action {
+            accesskit::Action::Increment => true,
+            accesskit::Action::Decrement => false,
+            _ => return IGNORED,
+        };
+        self.step(forwards, 1.);
+        HANDLED
+    }
 }
 
 struct TrackSpec {
@@ -0,0 +1,947 @@
+//! A widget that divides its area between panes separated by draggable
+//! dividers.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use figures::units::{Lp, Px, UPx};
+use figures::{Fraction, IntoSigned, IntoUnsigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::keyboard::{Key, NamedKey};
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::shapes::Shape;
+use kludgine::Color;
+use parking_lot::Mutex;
+
+use crate::context::{EventContext, GraphicsContext, LayoutContext};
+use crate::styles::components::{
+    AutoFocusableControls, HighlightColor, IntrinsicPadding, LineHeight, OutlineColor, OutlineWidth,
+};
+use crate::styles::Dimension;
+use crate::value::{Destination, Dynamic, Generation, IntoDynamic, Source};
+use crate::widget::{
+    EventHandling, MakeWidget, MakeWidgetWithTag, Widget, WidgetInstance, WidgetRef, WidgetTag,
+    HANDLED, IGNORED,
+};
+use crate::widgets::grid::{GridDimension, GridLayout, Orientation, StackLayout};
+use crate::window::{DeviceId, KeyEvent};
+use crate::ConstraintLimit;
+
+/// A widget that divides its area between two or more panes separated by
+/// draggable dividers.
+///
+/// The positions of the dividers are stored in a `Dynamic<Vec<Lp>>`, where
+/// each entry is the distance from the start of the split to the start of a
+/// divider. The final pane is given all remaining space. If no positions are
+/// provided, the panes are given equal amounts of space.
+///
+/// Dividers can be focused and moved using the arrow keys. Double-clicking a
+/// divider, or pressing Enter or Space while it is focused, collapses the pane
+/// before it. Doing so again restores the pane to its previous size.
+pub struct Split {
+    orientation: Orientation,
+    panes: Vec<SplitPane>,
+    positions: Dynamic<Vec<Lp>>,
+}
+
+impl Split {
+    /// Returns a new split that arranges `panes` in `orientation`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if fewer than two panes are provided.
+    #[must_use]
+    pub fn new(
+        orientation: Orientation,
+        panes: impl IntoIterator<Item = impl Into<SplitPane>>,
+    ) -> Self {
+        let panes = panes.into_iter().map(Into::into).collect::<Vec<_>>();
+        assert!(panes.len() >= 2, "a Split requires at least two panes");
+        Self {
+            orientation,
+            panes,
+            positions: Dynamic::default(),
+        }
+    }
+
+    /// Returns a new split that displays `panes` side-by-side, separated by
+    /// vertical dividers.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if fewer than two panes are provided.
+    #[must_use]
+    pub fn columns(panes: impl IntoIterator<Item = impl Into<SplitPane>>) -> Self {
+        Self::new(Orientation::Column, panes)
+    }
+
+    /// Returns a new split that displays `panes` stacked vertically, separated
+    /// by horizontal dividers.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if fewer than two panes are provided.
+    #[must_use]
+    pub fn rows(panes: impl IntoIterator<Item = impl Into<SplitPane>>) -> Self {
+        Self::new(Orientation::Row, panes)
+    }
+
+    /// Sets the dynamic that stores the positions of this split's dividers and
+    /// returns self.
+    #[must_use]
+    pub fn positions(mut self, positions: impl IntoDynamic<Vec<Lp>>) -> Self {
+        self.positions = positions.into_dynamic();
+        self
+    }
+
+    /// Returns the dynamic containing the positions of this split's dividers.
+    #[must_use]
+    pub const fn divider_positions(&self) -> &Dynamic<Vec<Lp>> {
+        &self.positions
+    }
+}
+
+impl MakeWidgetWithTag for Split {
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let metrics = Arc::new(Mutex::new(SplitMetrics {
+            scale: Fraction::ONE,
+            layouts: Vec::new(),
+            limits: Vec::new(),
+            restore: vec![None; self.panes.len() - 1],
+        }));
+        let mut children = Vec::with_capacity(self.panes.len() * 2 - 1);
+        let mut limits = Vec::with_capacity(self.panes.len());
+        for (index, pane) in self.panes.into_iter().enumerate() {
+            if index > 0 {
+                children.push(WidgetRef::new(SplitDivider {
+                    index: index - 1,
+                    orientation: self.orientation,
+                    metrics: metrics.clone(),
+                    positions: self.positions.clone(),
+                    hovered: false,
+                    grab: None,
+                    last_click: None,
+                }));
+            }
+            children.push(WidgetRef::new(pane.widget));
+            limits.push((pane.minimum, pane.maximum));
+        }
+
+        SplitPanes {
+            children,
+            limits,
+            positions: self.positions,
+            layout: GridLayout::new(self.orientation),
+            synchronized: None,
+            metrics,
+        }
+        .make_with_tag(tag)
+    }
+}
+
+/// A pane in a [`Split`].
+#[derive(Debug)]
+pub struct SplitPane {
+    widget: WidgetInstance,
+    minimum: Dimension,
+    maximum: Option<Dimension>,
+}
+
+impl SplitPane {
+    /// Returns a new pane displaying `widget`.
+    #[must_use]
+    pub fn new(widget: impl MakeWidget) -> Self {
+        Self {
+            widget: widget.make_widget(),
+            minimum: Dimension::ZERO,
+            maximum: None,
+        }
+    }
+
+    /// Sets the smallest size this pane can be resized to and returns self.
+    ///
+    /// A pane can always be collapsed, regardless of its minimum size.
+    #[must_use]
+    pub fn minimum(mut self, minimum: impl Into<Dimension>) -> Self {
+        self.minimum = minimum.into();
+        self
+    }
+
+    /// Sets the largest size this pane can be resized to and returns self.
+    #[must_use]
+    pub fn maximum(mut self, maximum: impl Into<Dimension>) -> Self {
+        self.maximum = Some(maximum.into());
+        self
+    }
+}
+
+impl<T> From<T> for SplitPane
+where
+    T: MakeWidget,
+{
+    fn from(widget: T) -> Self {
+        Self::new(widget)
+    }
+}
+
+/// The measurements of a [`Split`] from its most recent layout, shared with
+/// its dividers.
+#[derive(Debug)]
+struct SplitMetrics {
+    scale: Fraction,
+    /// The layouts of the split's children, alternating between panes and
+    /// dividers.
+    layouts: Vec<StackLayout>,
+    limits: Vec<(UPx, Option<UPx>)>,
+    restore: Vec<Option<UPx>>,
+}
+
+impl SplitMetrics {
+    fn pane(&self, pane: usize) -> StackLayout {
+        self.layouts[pane * 2]
+    }
+
+    fn divider_position(&self, divider: usize) -> UPx {
+        self.layouts[divider * 2 + 1].offset
+    }
+
+    fn divider_size(&self) -> UPx {
+        self.layouts
+            .get(1)
+            .map_or(UPx::ZERO, |divider| divider.size)
+    }
+
+    fn resolved_positions(&self) -> Vec<Lp> {
+        (0..self.limits.len() - 1)
+            .map(|divider| self.divider_position(divider).into_lp(self.scale))
+            .collect()
+    }
+
+    /// Returns the sizes of each pane except the final pane for the divider
+    /// `positions`, clamped to the limits of the panes.
+    ///
+    /// The final pane receives the space that remains of `available`. If this
+    /// is outside of its limits, the other panes are adjusted, starting with
+    /// the closest.
+    fn requested_sizes(&self, positions: &[Lp], divider: UPx, available: UPx) -> Vec<UPx> {
+        let mut start = UPx::ZERO;
+        let mut sizes = positions
+            .iter()
+            .zip(&self.limits)
+            .map(|(position, (minimum, maximum))| {
+                let position = position.into_upx(self.scale).round();
+                let mut size = position.saturating_sub(start);
+                start = position + divider;
+                // A pane with no size is collapsed, which is allowed regardless
+                // of its limits.
+                if size > 0 {
+                    size = size.max(*minimum);
+                    if let Some(maximum) = maximum {
+                        size = size.min(*maximum);
+                    }
+                }
+                size
+            })
+            .collect::<Vec<_>>();
+
+        let used = sizes.iter().fold(UPx::ZERO, |total, size| total + *size);
+        let mut last = available.saturating_sub(used);
+        let (last_minimum, last_maximum) = self.limits[sizes.len()];
+        for (size, (minimum, _)) in sizes.iter_mut().zip(&self.limits).rev() {
+            if last >= last_minimum {
+                break;
+            } else if *size > 0 {
+                let amount = size.saturating_sub(*minimum).min(last_minimum - last);
+                *size -= amount;
+                last += amount;
+            }
+        }
+        if let Some(last_maximum) = last_maximum {
+            for (size, (_, maximum)) in sizes.iter_mut().zip(&self.limits).rev() {
+                if last <= last_maximum {
+                    break;
+                } else if *size > 0 {
+                    let growth = maximum.map_or(last - last_maximum, |maximum| {
+                        maximum.saturating_sub(*size).min(last - last_maximum)
+                    });
+                    *size += growth;
+                    last -= growth;
+                }
+            }
+        }
+        sizes
+    }
+
+    /// Returns the closest position to `requested` that keeps the panes on
+    /// either side of `divider` within their limits.
+    fn clamp_divider(&self, divider: usize, requested: Px) -> UPx {
+        let start = self.pane(divider).offset;
+        let next = self.pane(divider + 1);
+        let next_end = next.offset + next.size;
+        let divider_size = self.divider_size();
+        let (minimum, maximum) = self.limits[divider];
+        let (next_minimum, next_maximum) = self.limits[divider + 1];
+
+        let mut lowest = start + minimum;
+        if let Some(next_maximum) = next_maximum {
+            lowest = lowest.max(
+                next_end
+                    .saturating_sub(divider_size)
+                    .saturating_sub(next_maximum),
+            );
+        }
+        let mut highest = next_end
+            .saturating_sub(divider_size)
+            .saturating_sub(next_minimum);
+        if let Some(maximum) = maximum {
+            highest = highest.min(start + maximum);
+        }
+
+        requested.into_unsigned().min(highest).max(lowest)
+    }
+}
+
+/// Lays out the panes and dividers of a [`Split`].
+///
+/// The children are arranged by a [`GridLayout`], alternating between panes
+/// and dividers. Each time the divider positions change, every pane except the
+/// final pane is given a [`GridDimension::Measured`] size matching the
+/// positions, and the final pane fills the remaining space. Until positions
+/// are known, the panes share the space equally.
+#[derive(Debug)]
+struct SplitPanes {
+    children: Vec<WidgetRef>,
+    limits: Vec<(Dimension, Option<Dimension>)>,
+    positions: Dynamic<Vec<Lp>>,
+    layout: GridLayout,
+    synchronized: Option<(Generation, Fraction, UPx, UPx)>,
+    metrics: Arc<Mutex<SplitMetrics>>,
+}
+
+impl SplitPanes {
+    fn synchronize_dimensions(
+        &mut self,
+        requested: &[Lp],
+        length: UPx,
+        divider: UPx,
+        scale: Fraction,
+    ) {
+        let synchronized = Some((self.positions.generation(), scale, divider, length));
+        if synchronized == self.synchronized {
+            return;
+        }
+        self.synchronized = synchronized;
+
+        let dividers = self.limits.len() - 1;
+        let mut metrics = self.metrics.lock();
+        metrics.scale = scale;
+        metrics.limits = self
+            .limits
+            .iter()
+            .map(|(minimum, maximum)| {
+                (
+                    minimum.into_upx(scale).round(),
+                    maximum.map(|maximum| maximum.into_upx(scale).round()),
+                )
+            })
+            .collect();
+        let sizes = (requested.len() == dividers).then(|| {
+            metrics.requested_sizes(
+                requested,
+                divider,
+                length.saturating_sub(divider * u32::try_from(dividers).unwrap_or(u32::MAX)),
+            )
+        });
+        drop(metrics);
+
+        self.layout.truncate(0);
+        for pane in 0..=dividers {
+            if pane > 0 {
+                self.layout.insert(
+                    self.layout.len(),
+                    GridDimension::Measured {
+                        size: Dimension::Px(divider.into_signed()),
+                    },
+                    scale,
+                );
+            }
+            let dimension = match sizes.as_ref().and_then(|sizes| sizes.get(pane)) {
+                Some(size) => GridDimension::Measured {
+                    size: Dimension::Px(size.into_signed()),
+                },
+                None => GridDimension::Fractional { weight: 1 },
+            };
+            self.layout.insert(self.layout.len(), dimension, scale);
+        }
+    }
+}
+
+impl Widget for SplitPanes {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        for (index, child) in self.children.iter_mut().enumerate() {
+            // Collapsed panes are not drawn.
+            if index % 2 == 0 && self.layout.get(index).map_or(false, |pane| pane.size == 0) {
+                continue;
+            }
+            let child = child.mounted(context);
+            context.for_other(&child).redraw();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        let scale = context.gfx.scale();
+        let divider = context.get(&DividerSize).into_upx(scale).round();
+        let length = self.layout.orientation.split_size(available_space).0.max();
+        let requested = self.positions.get_tracking_invalidate(context);
+        self.synchronize_dimensions(&requested, length, divider, scale);
+
+        let content_size = self.layout.update(
+            available_space,
+            UPx::ZERO,
+            scale,
+            |child, _element, constraints, persist| {
+                let child = self.children[child].mounted(context);
+                let mut context = context.for_other(&child);
+                if !persist {
+                    context = context.as_temporary();
+                }
+                context.layout(constraints)
+            },
+        );
+
+        for (layout, child) in self.layout.iter().zip(&mut self.children) {
+            let child = child.mounted(context);
+            context.set_child_layout(
+                &child,
+                Rect::new(
+                    self.layout
+                        .orientation
+                        .make_point(layout.offset, UPx::ZERO)
+                        .into_signed(),
+                    self.layout
+                        .orientation
+                        .make_size(layout.size, self.layout.others[0])
+                        .into_signed(),
+                ),
+            );
+        }
+
+        let mut metrics = self.metrics.lock();
+        metrics.layouts = self.layout.to_vec();
+        if requested.len() != self.limits.len() - 1 {
+            self.positions.set(metrics.resolved_positions());
+        }
+
+        content_size
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        for child in &mut self.children {
+            child.unmount_in(context);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct SplitDivider {
+    index: usize,
+    orientation: Orientation,
+    metrics: Arc<Mutex<SplitMetrics>>,
+    positions: Dynamic<Vec<Lp>>,
+    hovered: bool,
+    grab: Option<Px>,
+    last_click: Option<Instant>,
+}
+
+impl SplitDivider {
+    fn cursor(&self) -> CursorIcon {
+        match self.orientation {
+            Orientation::Row => CursorIcon::RowResize,
+            Orientation::Column => CursorIcon::ColResize,
+        }
+    }
+
+    fn current_position(&self) -> UPx {
+        self.metrics.lock().divider_position(self.index)
+    }
+
+    fn move_to(&self, requested: Px) {
+        let metrics = self.metrics.lock();
+        let position = metrics.clamp_divider(self.index, requested);
+        self.store_position(&metrics, position);
+    }
+
+    fn toggle_collapsed(&self) {
+        let mut metrics = self.metrics.lock();
+        let pane = metrics.pane(self.index);
+        let position = if pane.size == 0 {
+            let restore = metrics.restore[self.index].take().unwrap_or_else(|| {
+                let next = metrics.pane(self.index + 1);
+                pane.offset + (next.offset + next.size - pane.offset) / 2
+            });
+            metrics.clamp_divider(self.index, restore.into_signed())
+        } else {
+            let current = metrics.divider_position(self.index);
+            metrics.restore[self.index] = Some(current);
+            pane.offset
+        };
+        self.store_position(&metrics, position);
+    }
+
+    fn store_position(&self, metrics: &SplitMetrics, position: UPx) {
+        let mut positions = self.positions.lock();
+        if positions.len() != metrics.limits.len() - 1 {
+            *positions = metrics.resolved_positions();
+        }
+        let position = position.into_lp(metrics.scale);
+        if positions[self.index] != position {
+            positions[self.index] = position;
+        }
+    }
+}
+
+impl Widget for SplitDivider {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        if self.hovered || self.grab.is_some() {
+            let highlight = context.get(&HighlightColor);
+            context.fill(highlight);
+        }
+
+        let (length, other) = self.orientation.split_size(context.gfx.region().size);
+        let line_width = context
+            .get(&OutlineWidth)
+            .into_px(context.gfx.scale())
+            .round()
+            .max(Px::new(1));
+        context.gfx.draw_shape(&Shape::filled_rect(
+            Rect::new(
+                self.orientation
+                    .make_point(((length - line_width) / 2).round(), Px::ZERO),
+                self.orientation.make_size(line_width, other),
+            ),
+            context.get(&DividerColor),
+        ));
+
+        if context.focused(true) {
+            context.draw_focus_ring();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        _context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        available_space.map(ConstraintLimit::max)
+    }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        true
+    }
+
+    fn hover(
+        &mut self,
+        _location: Point<Px>,
+        context: &mut EventContext<'_>,
+    ) -> Option<CursorIcon> {
+        if !context.enabled() {
+            return Some(CursorIcon::NotAllowed);
+        }
+        if !self.hovered {
+            self.hovered = true;
+            context.set_needs_redraw();
+        }
+        Some(self.cursor())
+    }
+
+    fn unhover(&mut self, context: &mut EventContext<'_>) {
+        if self.hovered {
+            self.hovered = false;
+            context.set_needs_redraw();
+        }
+    }
+
+    fn accept_focus(&mut self, context: &mut EventContext<'_>) -> bool {
+        context.enabled() && context.get(&AutoFocusableControls).is_all()
+    }
+
+    fn focus(&mut self, context: &mut EventContext<'_>) {
+        context.set_needs_redraw();
+    }
+
+    fn blur(&mut self, context: &mut EventContext<'_>) {
+        context.set_needs_redraw();
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left || !context.enabled() {
+            return IGNORED;
+        }
+
+        context.focus();
+        let now = Instant::now();
+        let threshold = context.cushy().multi_click_threshold();
+        if self.last_click.map_or(false, |last_click| {
+            now.saturating_duration_since(last_click) < threshold
+        }) {
+            self.last_click = None;
+            self.toggle_collapsed();
+        } else {
+            self.last_click = Some(now);
+            self.grab = Some(self.orientation.split_point(location).0);
+            context.set_needs_redraw();
+        }
+
+        HANDLED
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        let Some(grab) = self.grab else {
+            return;
+        };
+
+        // `location` is relative to the divider's current position.
+        let offset = self.orientation.split_point(location).0 - grab;
+        if offset != 0 {
+            self.move_to(self.current_position().into_signed() + offset);
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        _location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        self.grab = None;
+        context.set_needs_redraw();
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let Key::Named(key) = input.logical_key else {
+            return IGNORED;
+        };
+        let handled = matches!(
+            (self.orientation, key),
+            (
+                Orientation::Column,
+                NamedKey::ArrowLeft | NamedKey::ArrowRight
+            ) | (Orientation::Row, NamedKey::ArrowUp | NamedKey::ArrowDown)
+                | (
+                    _,
+                    NamedKey::Home | NamedKey::End | NamedKey::Enter | NamedKey::Space
+                )
+        );
+        if !handled {
+            return IGNORED;
+        }
+
+        if input.state.is_pressed() {
+            let step = context
+                .get(&LineHeight)
+                .into_px(context.kludgine.scale())
+                .round();
+            let current = self.current_position().into_signed();
+            match key {
+                NamedKey::ArrowLeft | NamedKey::ArrowUp => self.move_to(current - step),
+                NamedKey::ArrowRight | NamedKey::ArrowDown => self.move_to(current + step),
+                NamedKey::Home => self.move_to(Px::ZERO),
+                NamedKey::End => self.move_to(Px::MAX),
+                _ => self.toggle_collapsed(),
+            }
+        }
+
+        HANDLED
+    }
+}
+
+define_components! {
+    Split {
+        /// The thickness of the dividers between the panes of a [`Split`].
+        DividerSize(Dimension, "divider_size", @IntrinsicPadding)
+        /// The [`Color`] of the line drawn in the dividers of a [`Split`].
+        DividerColor(Color, "divider_color", @OutlineColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Lp, Px, UPx};
+    use figures::{Fraction, Point, ScreenScale, Size, Zero};
+    use kludgine::app::winit::event::{ElementState, MouseButton};
+    use kludgine::app::winit::keyboard::NamedKey;
+
+    use super::{Split, SplitDivider, SplitMetrics, SplitPane};
+    use crate::value::{Dynamic, Source};
+    use crate::widget::{MakeWidget, MountedWidget};
+    use crate::widgets::grid::StackLayout;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    fn metrics(limits: Vec<(UPx, Option<UPx>)>, sizes: &[u32]) -> SplitMetrics {
+        // Each pane is separated by a divider that is 10 pixels wide.
+        let mut layouts = Vec::new();
+        let mut offset = UPx::ZERO;
+        for (index, size) in sizes.iter().enumerate() {
+            if index > 0 {
+                layouts.push(StackLayout {
+                    offset,
+                    size: UPx::new(10),
+                });
+                offset += UPx::new(10);
+            }
+            layouts.push(StackLayout {
+                offset,
+                size: UPx::new(*size),
+            });
+            offset += UPx::new(*size);
+        }
+        SplitMetrics {
+            scale: Fraction::ONE,
+            layouts,
+            restore: vec![None; limits.len() - 1],
+            limits,
+        }
+    }
+
+    #[test]
+    fn split_requested_sizes() {
+        let split = metrics(vec![(UPx::ZERO, None); 3], &[]);
+        // Panes can be collapsed, and the final pane receives the remaining
+        // space.
+        assert_eq!(
+            split.requested_sizes(
+                &[Lp::ZERO, UPx::new(100).into_lp(Fraction::ONE)],
+                UPx::new(10),
+                UPx::new(300),
+            ),
+            [UPx::ZERO, UPx::new(90)]
+        );
+
+        // Limits on the final pane shrink the panes before it.
+        let split = metrics(
+            vec![
+                (UPx::new(50), None),
+                (UPx::new(50), Some(UPx::new(100))),
+                (UPx::new(100), None),
+            ],
+            &[],
+        );
+        assert_eq!(
+            split.requested_sizes(
+                &[
+                    UPx::new(150).into_lp(Fraction::ONE),
+                    UPx::new(260).into_lp(Fraction::ONE),
+                ],
+                UPx::new(10),
+                UPx::new(300),
+            ),
+            [UPx::new(150), UPx::new(50)]
+        );
+    }
+
+    #[test]
+    fn split_clamp_divider() {
+        let split = metrics(
+            vec![(UPx::new(50), None), (UPx::new(50), Some(UPx::new(150)))],
+            &[150, 150],
+        );
+        assert_eq!(split.divider_position(0), UPx::new(150));
+        assert_eq!(split.clamp_divider(0, Px::ZERO), UPx::new(150));
+        assert_eq!(split.clamp_divider(0, Px::new(200)), UPx::new(200));
+        assert_eq!(split.clamp_divider(0, Px::MAX), UPx::new(250));
+    }
+
+    struct TestSplit {
+        recorder: VirtualRecorder,
+        positions: Dynamic<Vec<Lp>>,
+    }
+
+    impl TestSplit {
+        fn new(right: SplitPane) -> Self {
+            let positions = Dynamic::<Vec<Lp>>::default();
+            let mut recorder = Split::columns([SplitPane::new("Left"), right])
+                .positions(positions.clone())
+                .build_recorder()
+                .size(Size::new(UPx::new(400), UPx::new(200)))
+                .finish()
+                .expect("error creating recorder");
+            recorder.refresh().expect("error refreshing");
+            Self {
+                recorder,
+                positions,
+            }
+        }
+
+        fn divider(&self) -> MountedWidget {
+            self.recorder
+                .window
+                .find_widget::<SplitDivider>()
+                .expect("divider")
+        }
+
+        /// Returns the horizontal location of the divider.
+        fn divider_x(&self) -> Px {
+            self.divider()
+                .last_layout()
+                .expect("divider laid out")
+                .origin
+                .x
+        }
+
+        fn pane_width(&self, text: &str) -> Px {
+            self.recorder
+                .window
+                .find_text(text)
+                .and_then(|pane| pane.last_layout())
+                .expect("pane laid out")
+                .size
+                .width
+        }
+
+        fn divider_center(&self) -> Point<Px> {
+            let layout = self.divider().last_layout().expect("divider laid out");
+            layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2)
+        }
+
+        fn mouse_button(&mut self, state: ElementState) {
+            let _ =
+                self.recorder
+                    .window
+                    .mouse_input(DeviceId::Virtual(0), state, MouseButton::Left);
+        }
+
+        fn drag_by(&mut self, offset: Px) {
+            let start = self.divider_center();
+            let window = &mut self.recorder.window;
+            window.cursor_moved(DeviceId::Virtual(0), start);
+            self.mouse_button(ElementState::Pressed);
+            self.recorder
+                .window
+                .cursor_moved(DeviceId::Virtual(0), start + Point::new(offset, Px::ZERO));
+            self.mouse_button(ElementState::Released);
+            self.recorder.refresh().expect("error refreshing");
+        }
+
+        fn double_click(&mut self) {
+            let center = self.divider_center();
+            self.recorder
+                .window
+                .cursor_moved(DeviceId::Virtual(0), center);
+            for _ in 0..2 {
+                self.mouse_button(ElementState::Pressed);
+                self.mouse_button(ElementState::Released);
+            }
+            self.recorder.refresh().expect("error refreshing");
+        }
+
+        fn press(&mut self, key: NamedKey) {
+            let _ = self.recorder.window.press_key(key);
+            self.recorder.refresh().expect("error refreshing");
+        }
+    }
+
+    #[test]
+    fn panes_share_space() {
+        let split = TestSplit::new(SplitPane::new("Right"));
+        // Without positions, the panes are given equal space and the resolved
+        // position is stored.
+        let left = split.pane_width("Left");
+        assert!(
+            (left - split.pane_width("Right")).get().abs() <= 1,
+            "panes are not equal"
+        );
+        assert_eq!(split.divider_x(), left);
+        assert_eq!(split.positions.get().len(), 1);
+    }
+
+    #[test]
+    fn drag_divider() {
+        let mut split = TestSplit::new(SplitPane::new("Right"));
+        let start = split.divider_x();
+        let positions = split.positions.get();
+
+        split.drag_by(Px::new(50));
+        assert_eq!(split.divider_x(), start + Px::new(50));
+        assert_eq!(split.pane_width("Left"), start + Px::new(50));
+        assert_ne!(split.positions.get(), positions);
+
+        split.drag_by(Px::new(-100));
+        assert_eq!(split.divider_x(), start - Px::new(50));
+    }
+
+    #[test]
+    fn drag_respects_limits() {
+        let mut split = TestSplit::new(SplitPane::new("Right").minimum(Px::new(150)));
+        let start = split.divider_x();
+        split.drag_by(Px::new(400));
+        assert!(split.divider_x() > start);
+        assert_eq!(split.pane_width("Right"), Px::new(150));
+    }
+
+    #[test]
+    fn double_click_collapses() {
+        let mut split = TestSplit::new(SplitPane::new("Right"));
+        let start = split.divider_x();
+
+        split.double_click();
+        assert_eq!(split.divider_x(), Px::ZERO);
+        assert_eq!(split.pane_width("Left"), Px::ZERO);
+
+        // Double-clicking again restores the pane to its previous size.
+        split.double_click();
+        assert_eq!(split.divider_x(), start);
+    }
+
+    #[test]
+    fn keyboard_adjustment() {
+        let mut split = TestSplit::new(SplitPane::new("Right"));
+        let divider = split.divider();
+        split.recorder.window.focus(&divider);
+        split.recorder.refresh().expect("error refreshing");
+        split.recorder.window.assert_focused(&divider);
+        let start = split.divider_x();
+
+        split.press(NamedKey::ArrowRight);
+        let step = split.divider_x() - start;
+        assert!(step > Px::ZERO);
+        split.press(NamedKey::ArrowLeft);
+        assert_eq!(split.divider_x(), start);
+        // Arrows perpendicular to the divider are ignored.
+        split.press(NamedKey::ArrowDown);
+        assert_eq!(split.divider_x(), start);
+
+        split.press(NamedKey::Home);
+        assert_eq!(split.divider_x(), Px::ZERO);
+        split.press(NamedKey::End);
+        assert_eq!(split.pane_width("Right"), Px::ZERO);
+
+        // Enter collapses the pane before the divider and restores it.
+        split.press(NamedKey::Enter);
+        assert_eq!(split.divider_x(), Px::ZERO);
+        split.press(NamedKey::Enter);
+        assert!(split.divider_x() > Px::ZERO);
+    }
+}
@@ -9,7 +9,9 @@ use crate::styles::components::{
     LineHeight8, TextColor, TextSize, TextSize1, TextSize2, TextSize3, TextSize4, TextSize5,
     TextSize6, TextSize7, TextSize8,
 };
-use crate::styles::{ComponentDefinition, IntoComponentValue, IntoDynamicComponentValue, Styles};
+use crate::styles::{
+    ComponentDefinition, IntoComponentValue, IntoDynamicComponentValue, StyleSheet, Styles,
+};
 use crate::value::{Destination, IntoValue, Mutable, Value};
 use crate::widget::{MakeWidget, WidgetRef, WrapperWidget};
 
@@ -17,6 +19,7 @@ use crate::widget::{MakeWidget, WidgetRef, WrapperWidget};
 #[derive(Debug)]
 pub struct Style {
     styles: Value<Styles>,
+    style_sheet: Option<Value<StyleSheet>>,
     child: WidgetRef,
 }
 
@@ -26,6 +29,7 @@ impl Style {
     pub fn new(styles: impl IntoValue<Styles>, child: impl MakeWidget) -> Self {
         Self {
             styles: styles.into_value(),
+            style_sheet: None,
             child: WidgetRef::new(child),
         }
     }
@@ -104,6 +108,15 @@ impl Style {
         self
     }
 
+    /// Applies the rules in `style_sheet` to all contained widgets.
+    ///
+    /// See [`StyleSheet`] for more information.
+    #[must_use]
+    pub fn with_style_sheet(mut self, style_sheet: impl IntoValue<StyleSheet>) -> Style {
+        self.style_sheet = Some(style_sheet.into_value());
+        self
+    }
+
     /// Styles `self` with the largest of 6 heading styles.
     #[must_use]
     pub fn h1(self) -> Style {
@@ -228,5 +241,8 @@ impl WrapperWidget for Style {
 
     fn mounted(&mut self, context: &mut EventContext<'_>) {
         context.attach_styles(self.styles.clone());
+        if let Some(style_sheet) = &self.style_sheet {
+            context.attach_style_sheet(style_sheet.clone());
+        }
     }
 }
@@ -0,0 +1,486 @@
+//! A widget that displays a scalable vector graphic (SVG).
+
+use std::fmt::{self, Debug, Display};
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+use figures::units::UPx;
+use figures::{FloatConversion, Fraction, IntoUnsigned, Rect, ScreenScale, Size, Zero};
+use image::{DynamicImage, RgbaImage};
+use kludgine::shapes::Shape;
+use kludgine::wgpu::FilterMode;
+use kludgine::LazyTexture;
+use resvg::{tiny_skia, usvg};
+
+use super::image::{Aspect, ImageCornerRadius, ImageScaling};
+use crate::animation::ZeroToOne;
+use crate::context::{GraphicsContext, LayoutContext, Trackable};
+use crate::styles::components::TextColor;
+use crate::value::{Generation, IntoValue, Source, Value};
+use crate::widget::Widget;
+use crate::ConstraintLimit;
+
+/// The maximum number of rasterized images kept for each [`Svg`].
+const MAX_CACHED_IMAGES: usize = 4;
+
+/// A parsed scalable vector graphic (SVG) document.
+///
+/// Cloning a document is cheap, as the parsed document is shared.
+#[derive(Clone)]
+pub struct SvgDocument(Arc<usvg::Tree>);
+
+impl SvgDocument {
+    /// Parses an SVG document from `data`.
+    ///
+    /// `data` may also be a gzip-compressed SVG document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is not a valid SVG document.
+    pub fn from_data(data: &[u8]) -> Result<Self, SvgError> {
+        let tree = usvg::Tree::from_data(data, &usvg::Options::default())?;
+        Ok(Self(Arc::new(tree)))
+    }
+
+    /// Reads and parses the SVG document at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid SVG
+    /// document.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, SvgError> {
+        Self::from_data(&std::fs::read(path)?)
+    }
+
+    /// Returns the size of this document, in pixels at a scale of 1.0.
+    #[must_use]
+    pub fn size(&self) -> Size<f32> {
+        let size = self.0.size();
+        Size::new(size.width(), size.height())
+    }
+
+    /// Returns the size of this document, in pixels at `scale`.
+    fn scaled_size(&self, scale: Fraction) -> Size<UPx> {
+        self.size()
+            .map(|dim| UPx::from_float((dim * scale.into_f32()).ceil()))
+    }
+
+    /// Renders this document into an image of `size` pixels.
+    ///
+    /// When `mask` is true, every pixel's color is replaced with white while
+    /// preserving its alpha, allowing the image to be tinted when drawn.
+    fn rasterize(&self, size: Size<UPx>, mask: bool) -> Option<DynamicImage> {
+        let mut pixmap = tiny_skia::Pixmap::new(size.width.get(), size.height.get())?;
+        let document_size = self.size();
+        resvg::render(
+            &self.0,
+            tiny_skia::Transform::from_scale(
+                size.width.into_float() / document_size.width,
+                size.height.into_float() / document_size.height,
+            ),
+            &mut pixmap.as_mut(),
+        );
+
+        // tiny-skia produces premultiplied colors, while textures expect
+        // straight alpha.
+        let pixels = pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                if mask {
+                    [255, 255, 255, pixel.alpha()]
+                } else {
+                    let pixel = pixel.demultiply();
+                    [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+                }
+            })
+            .collect();
+        RgbaImage::from_raw(size.width.get(), size.height.get(), pixels)
+            .map(DynamicImage::ImageRgba8)
+    }
+}
+
+impl Debug for SvgDocument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SvgDocument")
+            .field("size", &self.size())
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for SvgDocument {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// An error loading an [`SvgDocument`].
+#[derive(Debug)]
+pub enum SvgError {
+    /// An error occurred reading the document.
+    Io(io::Error),
+    /// An error occurred parsing the document.
+    Parse(usvg::Error),
+}
+
+impl From<io::Error> for SvgError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<usvg::Error> for SvgError {
+    fn from(value: usvg::Error) -> Self {
+        Self::Parse(value)
+    }
+}
+
+impl Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgError::Io(err) => write!(f, "error reading svg: {err}"),
+            SvgError::Parse(err) => write!(f, "error parsing svg: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SvgError {}
+
+/// A widget that displays a scalable vector graphic (SVG).
+///
+/// The document is rasterized at the window's effective scale, which includes
+/// the window's zoom factor, so that it is always drawn crisply. The most
+/// recently used rasterized images are cached by scale and size, allowing the
+/// scale to change without needing to render the document again.
+///
+/// The document's size at a scale of 1.0 is treated as its natural size, and
+/// is scaled using the same [`ImageScaling`] strategies as
+/// [`Image`](super::Image).
+#[derive(Debug)]
+pub struct Svg {
+    /// The document to render.
+    pub document: Value<SvgDocument>,
+    /// The scaling strategy to apply.
+    pub scaling: Value<ImageScaling>,
+    /// The opacity to render the image with.
+    pub opacity: Value<ZeroToOne>,
+    tinted: bool,
+    rasterized: Vec<Rasterized>,
+    generation: Option<Generation>,
+}
+
+impl Svg {
+    /// Returns a new widget that renders `document`, using the default
+    /// [`ImageScaling`] strategy.
+    pub fn new(document: impl IntoValue<SvgDocument>) -> Self {
+        Self {
+            document: document.into_value(),
+            scaling: Value::default(),
+            opacity: Value::Constant(ZeroToOne::ONE),
+            tinted: false,
+            rasterized: Vec::new(),
+            generation: None,
+        }
+    }
+
+    /// Applies the `scaling` strategies and returns self.
+    #[must_use]
+    pub fn scaling(mut self, scaling: impl IntoValue<ImageScaling>) -> Self {
+        self.scaling = scaling.into_value();
+        self
+    }
+
+    /// Applies the aspect-fit scaling strategy and returns self.
+    ///
+    /// See [`Image::aspect_fit`](super::Image::aspect_fit) for more
+    /// information.
+    #[must_use]
+    pub fn aspect_fit(self) -> Self {
+        self.scaling(ImageScaling::Aspect {
+            mode: Aspect::Fit,
+            orientation: Size::ZERO,
+        })
+    }
+
+    /// Applies the aspect-fill scaling strategy and returns self.
+    ///
+    /// See [`Image::aspect_fill`](super::Image::aspect_fill) for more
+    /// information.
+    #[must_use]
+    pub fn aspect_fill(self) -> Self {
+        self.scaling(ImageScaling::Aspect {
+            mode: Aspect::Fill,
+            orientation: Size::ZERO,
+        })
+    }
+
+    /// Applies the stretch scaling strategy and returns self.
+    #[must_use]
+    pub fn stretch(self) -> Self {
+        self.scaling(ImageScaling::Stretch)
+    }
+
+    /// Applies a scaling factor strategy and returns self.
+    ///
+    /// The document will be displayed at a scaling factor of `amount`,
+    /// relative to its natural size.
+    #[must_use]
+    pub fn scaled(self, amount: impl IntoValue<f32>) -> Self {
+        self.scaling(match amount.into_value() {
+            Value::Constant(amount) => Value::Constant(ImageScaling::Scale(amount)),
+            Value::Dynamic(amount) => Value::Dynamic(amount.map_each_cloned(ImageScaling::Scale)),
+        })
+    }
+
+    /// Applies `opacity` when drawing the image, returns self.
+    #[must_use]
+    pub fn opacity(mut self, opacity: impl IntoValue<ZeroToOne>) -> Self {
+        self.opacity = opacity.into_value();
+        self
+    }
+
+    /// Draws this document using [`TextColor`], and returns self.
+    ///
+    /// Only the opacity of the document is preserved when tinting. This is
+    /// intended for monochrome icons, allowing them to follow the current
+    /// theme.
+    #[must_use]
+    pub fn tinted(mut self) -> Self {
+        self.tinted = true;
+        self
+    }
+
+    /// Returns the texture of the document rasterized at `size` for `scale`.
+    fn texture(&mut self, scale: Fraction, size: Size<UPx>) -> Option<&LazyTexture> {
+        let generation = self.document.generation();
+        if generation != self.generation {
+            self.generation = generation;
+            self.rasterized.clear();
+        }
+
+        // The cache is ordered from least to most recently used.
+        if let Some(index) = self
+            .rasterized
+            .iter()
+            .position(|rasterized| rasterized.scale == scale && rasterized.size == size)
+        {
+            let rasterized = self.rasterized.remove(index);
+            self.rasterized.push(rasterized);
+        } else {
+            let image = self
+                .document
+                .map(|document| document.rasterize(size, self.tinted))?;
+            if self.rasterized.len() >= MAX_CACHED_IMAGES {
+                self.rasterized.remove(0);
+            }
+            self.rasterized.push(Rasterized {
+                scale,
+                size,
+                texture: LazyTexture::from_image(image, FilterMode::Linear),
+            });
+        }
+        self.rasterized.last().map(|rasterized| &rasterized.texture)
+    }
+}
+
+impl Widget for Svg {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        self.document.invalidate_when_changed(context);
+        let opacity = self.opacity.get_tracking_redraw(context);
+        let scaling = self.scaling.get_tracking_invalidate(context);
+        let radii = context
+            .get(&ImageCornerRadius)
+            .map(|r| r.into_px(context.gfx.scale()));
+        let color = if self.tinted {
+            context.get(&TextColor)
+        } else {
+            kludgine::Color::WHITE
+        };
+        let scale = context.gfx.scale();
+
+        let natural_size = self.document.map(|document| document.scaled_size(scale));
+        let rect = scaling.render_area(natural_size, context.gfx.size());
+        let size = rect.size.into_unsigned();
+        if size.width == 0 || size.height == 0 {
+            return;
+        }
+
+        if let Some(texture) = self.texture(scale, size) {
+            context.gfx.draw_textured_shape(
+                &Shape::textured_round_rect(rect, radii, Rect::<UPx>::from(size), color),
+                texture,
+                opacity,
+            );
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        self.document.invalidate_when_changed(context);
+        let scaling = self.scaling.get_tracking_invalidate(context);
+        let natural_size = self
+            .document
+            .map(|document| document.scaled_size(context.gfx.scale()));
+        scaling.layout_size(natural_size, available_space)
+    }
+}
+
+#[derive(Debug)]
+struct Rasterized {
+    scale: Fraction,
+    size: Size<UPx>,
+    texture: LazyTexture,
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+    use figures::{FloatConversion, Fraction, Point, Size};
+    use kludgine::Color;
+
+    use super::{Svg, SvgDocument, SvgError};
+    use crate::styles::components::TextColor;
+    use crate::value::{Destination, Dynamic};
+    use crate::widget::MakeWidget;
+
+    /// A 100px square document filled with black.
+    const SQUARE: &[u8] = br#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+        <rect width="100" height="100" fill="black"/>
+    </svg>"#;
+
+    fn square() -> SvgDocument {
+        SvgDocument::from_data(SQUARE).expect("valid svg")
+    }
+
+    fn cached(svg: &Svg) -> Vec<(Fraction, Size<UPx>)> {
+        svg.rasterized
+            .iter()
+            .map(|rasterized| (rasterized.scale, rasterized.size))
+            .collect()
+    }
+
+    #[test]
+    fn invalid_documents() {
+        assert!(matches!(
+            SvgDocument::from_data(b"not an svg"),
+            Err(SvgError::Parse(_))
+        ));
+        assert!(matches!(
+            SvgDocument::open("this-file-does-not-exist.svg"),
+            Err(SvgError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn caches_by_scale_and_size() {
+        let mut svg = Svg::new(square());
+        let one = (Fraction::ONE, Size::new(UPx::new(100), UPx::new(100)));
+        let two = (Fraction::new(2, 1), Size::new(UPx::new(200), UPx::new(200)));
+
+        assert!(svg.texture(one.0, one.1).is_some());
+        assert!(svg.texture(two.0, two.1).is_some());
+        assert_eq!(cached(&svg), [one, two]);
+
+        // Requesting a cached rasterization does not render it again, but
+        // marks it as the most recently used.
+        assert!(svg.texture(one.0, one.1).is_some());
+        assert_eq!(cached(&svg), [two, one]);
+
+        // The same scale at a different size is cached separately.
+        let stretched = (Fraction::ONE, Size::new(UPx::new(150), UPx::new(100)));
+        assert!(svg.texture(stretched.0, stretched.1).is_some());
+        assert_eq!(cached(&svg), [two, one, stretched]);
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut svg = Svg::new(square());
+        let entry = |scale, size| (scale, Size::new(UPx::new(size), UPx::new(size)));
+        let entries = [
+            entry(Fraction::ONE, 100),
+            entry(Fraction::new(2, 1), 200),
+            entry(Fraction::new(3, 1), 300),
+            entry(Fraction::new(4, 1), 400),
+        ];
+        for (scale, size) in &entries {
+            assert!(svg.texture(*scale, *size).is_some());
+        }
+        assert!(svg.texture(entries[0].0, entries[0].1).is_some());
+
+        let five = entry(Fraction::new(5, 1), 500);
+        assert!(svg.texture(five.0, five.1).is_some());
+        assert_eq!(
+            cached(&svg),
+            [entries[2], entries[3], entries[0], five],
+            "the least recently used entry should have been evicted"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn zoom_rerenders() {
+        let zoom = Dynamic::new(Fraction::ONE);
+        let mut recorder = Svg::new(square())
+            .build_recorder()
+            .size(Size::new(UPx::new(400), UPx::new(400)))
+            .zoom(zoom.clone())
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let svg = recorder.window.find_widget::<Svg>().expect("svg widget");
+        let cached_scales = || {
+            cached(svg.lock().downcast_ref::<Svg>().expect("svg widget"))
+                .into_iter()
+                .map(|(scale, size)| (scale.into_f32(), size.width.get()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(cached_scales(), [(1., 100)]);
+
+        zoom.set(Fraction::new(2, 1));
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(cached_scales(), [(1., 100), (2., 200)]);
+
+        // Returning to a previous zoom reuses the cached rasterization.
+        zoom.set(Fraction::ONE);
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(cached_scales(), [(2., 200), (1., 100)]);
+    }
+
+    #[test]
+    fn tint_follows_text_color() {
+        let color = Dynamic::new(Color::RED);
+        let mut recorder = Svg::new(square())
+            .tinted()
+            .stretch()
+            .with(&TextColor, color.clone())
+            .build_recorder()
+            .size(Size::new(UPx::new(100), UPx::new(100)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        recorder.assert_pixel_color(
+            Point::new(UPx::new(50), UPx::new(50)),
+            Color::RED,
+            "tinted svg",
+        );
+
+        color.set(Color::BLUE);
+        recorder.refresh().expect("error refreshing");
+        recorder.assert_pixel_color(
+            Point::new(UPx::new(50), UPx::new(50)),
+            Color::BLUE,
+            "tinted svg",
+        );
+
+        // Changing the tint does not require rendering the document again.
+        let svg = recorder.window.find_widget::<Svg>().expect("svg widget");
+        assert_eq!(
+            cached(svg.lock().downcast_ref::<Svg>().expect("svg widget")).len(),
+            1
+        );
+    }
+}
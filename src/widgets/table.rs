@@ -0,0 +1,944 @@
+//! A widget that displays rows of data in columns with headers.
+
+use figures::units::{Px, UPx};
+use figures::{IntoSigned, IntoUnsigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use kempt::Set;
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::shapes::{PathBuilder, Shape};
+
+use super::grid::{GridDimension, GridLayout, Orientation, StackLayout};
+use crate::context::{EventContext, GraphicsContext, LayoutContext};
+use crate::styles::components::{
+    HighlightColor, IntrinsicPadding, LineHeight, OutlineColor, OutlineWidth, TextColor,
+};
+use crate::styles::Dimension;
+use crate::utils::ModifiersExt;
+use crate::value::{Destination, Dynamic, Generation, IntoDynamic, IntoValue, Source, Value};
+use crate::widget::{
+    Callback, EventHandling, MakeWidget, MakeWidgetWithTag, Widget, WidgetInstance, WidgetRef,
+    WidgetTag, HANDLED, IGNORED,
+};
+use crate::widgets::VirtualList;
+use crate::window::DeviceId;
+use crate::ConstraintLimit;
+
+/// A virtualized table of rows and columns.
+///
+/// The columns of a table are defined at runtime using [`TableColumn`]s. Each
+/// column has a header widget and a [`GridDimension`] that controls how wide
+/// the column is. Because rows are loaded lazily, columns using
+/// [`GridDimension::FitContent`] are sized to fit their header.
+///
+/// Rows are loaded as they become visible using the same strategy as
+/// [`VirtualList`]: each cell is created by invoking the table's cell function
+/// with the row and column index, and each row is sized to match the first
+/// visible row.
+///
+/// Tables support:
+///
+/// - Sorting: clicking the header of a [sortable](TableColumn::sortable)
+///   column updates [`Table::sort`]. The table does not reorder its rows
+///   itself; the cell function should consult the sort state to determine
+///   which record belongs in each row. All visible rows are reloaded when the
+///   sort changes.
+/// - Resizing: dragging the divider to the right of a column's header changes
+///   the column to a [`GridDimension::Measured`] dimension.
+/// - Selection: clicking rows updates [`Table::selection`] according to the
+///   table's [`TableSelection`] mode.
+pub struct Table {
+    columns: Vec<TableColumn>,
+    row_count: Value<usize>,
+    make_cell: Callback<(usize, usize), WidgetInstance>,
+    selection: Dynamic<Set<usize>>,
+    selection_mode: TableSelection,
+    sort: Dynamic<Option<TableSort>>,
+}
+
+impl Table {
+    /// Returns a new table displaying `row_count` rows of `columns`.
+    ///
+    /// `make_cell` is invoked with `(row, column)` each time a cell becomes
+    /// visible.
+    #[must_use]
+    pub fn new<MakeCell, Cell>(
+        columns: impl IntoIterator<Item = TableColumn>,
+        row_count: impl IntoValue<usize>,
+        mut make_cell: MakeCell,
+    ) -> Self
+    where
+        MakeCell: FnMut(usize, usize) -> Cell + Send + 'static,
+        Cell: MakeWidget,
+    {
+        Self {
+            columns: columns.into_iter().collect(),
+            row_count: row_count.into_value(),
+            make_cell: Callback::new(move |(row, column)| make_cell(row, column).make_widget()),
+            selection: Dynamic::default(),
+            selection_mode: TableSelection::default(),
+            sort: Dynamic::default(),
+        }
+    }
+
+    /// Sets the selection mode for this table and returns self.
+    ///
+    /// By default, [`TableSelection::Single`] is used.
+    #[must_use]
+    pub fn selection_mode(mut self, mode: TableSelection) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Sets the dynamic that contains the indices of the selected rows and
+    /// returns self.
+    #[must_use]
+    pub fn selection(mut self, selection: impl IntoDynamic<Set<usize>>) -> Self {
+        self.selection = selection.into_dynamic();
+        self
+    }
+
+    /// Sets the dynamic that contains the column this table is sorted by and
+    /// returns self.
+    #[must_use]
+    pub fn sort(mut self, sort: impl IntoDynamic<Option<TableSort>>) -> Self {
+        self.sort = sort.into_dynamic();
+        self
+    }
+
+    /// Returns the dynamic containing the indices of the selected rows.
+    #[must_use]
+    pub const fn selected_rows(&self) -> &Dynamic<Set<usize>> {
+        &self.selection
+    }
+
+    /// Returns the dynamic containing the column this table is sorted by.
+    #[must_use]
+    pub const fn sorted_by(&self) -> &Dynamic<Option<TableSort>> {
+        &self.sort
+    }
+}
+
+impl MakeWidgetWithTag for Table {
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let column_layouts = Dynamic::<Vec<StackLayout>>::default();
+        let selection = RowSelection {
+            selected: self.selection,
+            anchor: Dynamic::default(),
+            mode: self.selection_mode,
+        };
+
+        let mut make_cell = self.make_cell;
+        let column_count = self.columns.len();
+        let list = VirtualList::new(self.row_count, {
+            let column_layouts = column_layouts.clone();
+            move |row| TableRow {
+                row,
+                cells: (0..column_count)
+                    .map(|column| WidgetRef::new(make_cell.invoke((row, column))))
+                    .collect(),
+                column_layouts: column_layouts.clone(),
+                selection: selection.clone(),
+            }
+        });
+        list.content_watcher().watch(&self.sort);
+
+        let mut headers = Vec::with_capacity(column_count);
+        let mut dimensions = Vec::with_capacity(column_count);
+        let mut sortable = Vec::with_capacity(column_count);
+        for column in self.columns {
+            headers.push(WidgetRef::new(column.header));
+            dimensions.push(column.dimension);
+            sortable.push(column.sortable);
+        }
+
+        TableHeader {
+            cells: headers,
+            sortable,
+            dimensions: Dynamic::new(dimensions),
+            dimensions_generation: None,
+            layout: GridLayout::new(Orientation::Column),
+            column_layouts,
+            gutter: UPx::ZERO,
+            sort: self.sort,
+            scroll: list.scroll.clone(),
+            interaction: None,
+        }
+        .and(list.expand())
+        .into_rows()
+        .gutter(Px::ZERO)
+        .make_with_tag(tag)
+    }
+}
+
+/// A column in a [`Table`].
+#[derive(Debug)]
+pub struct TableColumn {
+    header: WidgetInstance,
+    dimension: GridDimension,
+    sortable: bool,
+}
+
+impl TableColumn {
+    /// Returns a new column displaying `header` and sized using
+    /// [`GridDimension::FitContent`].
+    #[must_use]
+    pub fn new(header: impl MakeWidget) -> Self {
+        Self {
+            header: header.make_widget(),
+            dimension: GridDimension::FitContent,
+            sortable: false,
+        }
+    }
+
+    /// Sets the dimension used to size this column and returns self.
+    #[must_use]
+    pub fn dimension(mut self, dimension: GridDimension) -> Self {
+        self.dimension = dimension;
+        self
+    }
+
+    /// Allows the table to be sorted by this column by clicking its header,
+    /// and returns self.
+    #[must_use]
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+}
+
+/// The column and direction a [`Table`] is sorted by.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TableSort {
+    /// The index of the column being sorted.
+    pub column: usize,
+    /// The direction of the sort.
+    pub direction: SortDirection,
+}
+
+/// The direction of a sort.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum SortDirection {
+    /// Values are sorted from lowest to highest.
+    #[default]
+    Ascending,
+    /// Values are sorted from highest to lowest.
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction.
+    #[must_use]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The strategy a [`Table`] uses to select rows.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum TableSelection {
+    /// Rows cannot be selected.
+    None,
+    /// At most one row can be selected at a time.
+    #[default]
+    Single,
+    /// Any number of rows can be selected.
+    ///
+    /// Clicking a row while holding the primary modifier toggles the row's
+    /// selection, and clicking a row while holding shift selects the range of
+    /// rows between the previously clicked row and the clicked row.
+    Multiple,
+}
+
+#[derive(Debug, Clone)]
+struct RowSelection {
+    selected: Dynamic<Set<usize>>,
+    anchor: Dynamic<Option<usize>>,
+    mode: TableSelection,
+}
+
+impl RowSelection {
+    fn click(&self, row: usize, context: &EventContext<'_>) {
+        let modifiers = context.modifiers();
+        let mut selected = self.selected.lock();
+        match self.mode {
+            TableSelection::None => return,
+            TableSelection::Multiple if modifiers.state().shift_key() => {
+                let anchor = self.anchor.get().unwrap_or(row);
+                selected.clear();
+                for row in anchor.min(row)..=anchor.max(row) {
+                    selected.insert(row);
+                }
+                // Extending a range keeps the original anchor.
+                return;
+            }
+            TableSelection::Multiple if modifiers.primary() => {
+                if selected.contains(&row) {
+                    selected.remove(&row);
+                } else {
+                    selected.insert(row);
+                }
+            }
+            TableSelection::Single | TableSelection::Multiple => {
+                selected.clear();
+                selected.insert(row);
+            }
+        }
+        drop(selected);
+        self.anchor.set(Some(row));
+    }
+}
+
+#[derive(Debug)]
+struct TableHeader {
+    cells: Vec<WidgetRef>,
+    sortable: Vec<bool>,
+    dimensions: Dynamic<Vec<GridDimension>>,
+    dimensions_generation: Option<Generation>,
+    layout: GridLayout,
+    column_layouts: Dynamic<Vec<StackLayout>>,
+    gutter: UPx,
+    sort: Dynamic<Option<TableSort>>,
+    scroll: Dynamic<Point<UPx>>,
+    interaction: Option<HeaderInteraction>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HeaderInteraction {
+    Pressed {
+        column: usize,
+    },
+    Resizing {
+        column: usize,
+        start: Px,
+        width: UPx,
+    },
+}
+
+impl TableHeader {
+    fn synchronize_dimensions(&mut self, context: &mut LayoutContext<'_, '_, '_, '_>) {
+        context.invalidate_when_changed(&self.dimensions);
+        let generation = Some(self.dimensions.generation());
+        if generation != self.dimensions_generation {
+            self.dimensions_generation = generation;
+            self.dimensions.map_ref(|dimensions| {
+                self.layout.truncate(0);
+                for (index, dimension) in dimensions.iter().enumerate() {
+                    self.layout.insert(index, *dimension, context.gfx.scale());
+                }
+            });
+        }
+    }
+
+    fn sort_indicator_width(&self, column: usize, indicator_size: UPx) -> UPx {
+        if self.sortable[column] {
+            indicator_size + self.gutter
+        } else {
+            UPx::ZERO
+        }
+    }
+
+    fn content_x(&self, x: Px) -> Px {
+        x + self.scroll.get().x.into_signed()
+    }
+
+    fn divider_at(&self, location: Point<Px>) -> Option<usize> {
+        let x = self.content_x(location.x);
+        let grab_distance = (self.gutter / 2).into_signed().max(Px::new(2));
+        self.layout.iter().position(|column| {
+            let divider = (column.offset + column.size + self.gutter / 2).into_signed();
+            x >= divider - grab_distance && x <= divider + grab_distance
+        })
+    }
+
+    fn column_at(&self, location: Point<Px>) -> Option<usize> {
+        let x = self.content_x(location.x);
+        self.layout.iter().position(|column| {
+            x >= column.offset.into_signed()
+                && x < (column.offset + column.size + self.gutter).into_signed()
+        })
+    }
+
+    fn draw_sort_indicator(
+        &self,
+        column: &StackLayout,
+        direction: SortDirection,
+        context: &mut GraphicsContext<'_, '_, '_, '_>,
+    ) {
+        let size = context
+            .get(&SortIndicatorSize)
+            .into_px(context.gfx.scale())
+            .round();
+        let region = context.gfx.region().size;
+        let right = (column.offset + column.size).into_signed() - self.scroll.get().x.into_signed();
+        let left = right - size;
+        let top = ((region.height - size) / 2).round();
+        let bottom = top + size;
+        let middle = left + size / 2;
+
+        let path = match direction {
+            SortDirection::Ascending => PathBuilder::new(Point::new(left, bottom))
+                .line_to(Point::new(middle, top))
+                .line_to(Point::new(right, bottom))
+                .close(),
+            SortDirection::Descending => PathBuilder::new(Point::new(left, top))
+                .line_to(Point::new(middle, bottom))
+                .line_to(Point::new(right, top))
+                .close(),
+        };
+        context.gfx.draw_shape(&path.fill(context.get(&TextColor)));
+    }
+}
+
+impl Widget for TableHeader {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        for cell in &mut self.cells {
+            let cell = cell.mounted(context);
+            context.for_other(&cell).redraw();
+        }
+
+        let scroll_x = self.scroll.get_tracking_redraw(context).x.into_signed();
+        let height = context.gfx.region().size.height;
+        let line_width = context
+            .get(&OutlineWidth)
+            .into_px(context.gfx.scale())
+            .round()
+            .max(Px::new(1));
+        let divider_color = context.get(&OutlineColor);
+        for column in self.layout.iter() {
+            let divider = (column.offset + column.size + self.gutter / 2).into_signed()
+                - scroll_x
+                - line_width / 2;
+            context.gfx.draw_shape(&Shape::filled_rect(
+                Rect::new(Point::new(divider, Px::ZERO), Size::new(line_width, height)),
+                divider_color,
+            ));
+        }
+
+        if let Some(sort) = self.sort.get_tracking_redraw(context) {
+            if let Some(column) = self.layout.get(sort.column) {
+                self.draw_sort_indicator(column, sort.direction, context);
+            }
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        self.synchronize_dimensions(context);
+        self.gutter = context
+            .get(&IntrinsicPadding)
+            .into_upx(context.gfx.scale())
+            .round();
+        let indicator_size = context
+            .get(&SortIndicatorSize)
+            .into_upx(context.gfx.scale())
+            .round();
+        let indicator_widths = (0..self.cells.len())
+            .map(|column| self.sort_indicator_width(column, indicator_size))
+            .collect::<Vec<_>>();
+
+        let content_size = self.layout.update(
+            available_space,
+            self.gutter,
+            context.gfx.scale(),
+            |column, _, mut constraints, persist| {
+                let cell = self.cells[column].mounted(context);
+                let mut context = context.for_other(&cell);
+                if !persist {
+                    context = context.as_temporary();
+                }
+                constraints.width -= indicator_widths[column];
+                let mut size = context.layout(constraints);
+                size.width += indicator_widths[column];
+                size
+            },
+        );
+
+        let scroll_x = self.scroll.get_tracking_invalidate(context).x.into_signed();
+        for (column, layout) in self.layout.iter().enumerate() {
+            let cell = self.cells[column].mounted(context);
+            context.set_child_layout(
+                &cell,
+                Rect::new(
+                    Point::new(layout.offset.into_signed() - scroll_x, Px::ZERO),
+                    Size::new(
+                        layout.size.saturating_sub(indicator_widths[column]),
+                        content_size.height,
+                    )
+                    .into_signed(),
+                ),
+            );
+        }
+        self.column_layouts.set(self.layout.to_vec());
+
+        Size::new(
+            available_space.width.fill_or_fit(content_size.width),
+            content_size.height,
+        )
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        for cell in &mut self.cells {
+            cell.unmount_in(context);
+        }
+    }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        true
+    }
+
+    fn hover(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> Option<CursorIcon> {
+        if !context.enabled() {
+            None
+        } else if matches!(self.interaction, Some(HeaderInteraction::Resizing { .. }))
+            || self.divider_at(location).is_some()
+        {
+            Some(CursorIcon::ColResize)
+        } else if self
+            .column_at(location)
+            .map_or(false, |column| self.sortable[column])
+        {
+            Some(CursorIcon::Pointer)
+        } else {
+            None
+        }
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left || !context.enabled() {
+            return IGNORED;
+        }
+
+        if let Some(column) = self.divider_at(location) {
+            self.interaction = Some(HeaderInteraction::Resizing {
+                column,
+                start: location.x,
+                width: self.layout[column].size,
+            });
+            HANDLED
+        } else if let Some(column) = self
+            .column_at(location)
+            .filter(|column| self.sortable[*column])
+        {
+            self.interaction = Some(HeaderInteraction::Pressed { column });
+            HANDLED
+        } else {
+            IGNORED
+        }
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        let Some(HeaderInteraction::Resizing {
+            column,
+            start,
+            width,
+        }) = self.interaction
+        else {
+            return;
+        };
+
+        let minimum = context
+            .get(&MinimumColumnWidth)
+            .into_px(context.kludgine.scale())
+            .round();
+        let width = (width.into_signed() + location.x - start).max(minimum);
+        if self.layout[column].size != width.into_unsigned() {
+            self.dimensions.lock()[column] = GridDimension::Measured {
+                size: Dimension::Px(width),
+            };
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        let Some(HeaderInteraction::Pressed { column }) = self.interaction.take() else {
+            return;
+        };
+        if location.and_then(|location| self.column_at(location)) == Some(column) {
+            let mut sort = self.sort.lock();
+            *sort = Some(TableSort {
+                column,
+                direction: match *sort {
+                    Some(sort) if sort.column == column => sort.direction.reversed(),
+                    _ => SortDirection::Ascending,
+                },
+            });
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TableRow {
+    row: usize,
+    cells: Vec<WidgetRef>,
+    column_layouts: Dynamic<Vec<StackLayout>>,
+    selection: RowSelection,
+}
+
+impl Widget for TableRow {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        context.redraw_when_changed(&self.selection.selected);
+        if self
+            .selection
+            .selected
+            .map_ref(|selected| selected.contains(&self.row))
+        {
+            let highlight = context.get(&HighlightColor);
+            context.fill(highlight);
+        }
+
+        for cell in &mut self.cells {
+            let cell = cell.mounted(context);
+            context.for_other(&cell).redraw();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        let columns = self.column_layouts.get_tracking_invalidate(context);
+        let mut height = UPx::ZERO;
+        for (cell, column) in self.cells.iter_mut().zip(&columns) {
+            let cell = cell.mounted(context);
+            let size = context.for_other(&cell).layout(Size::new(
+                ConstraintLimit::Fill(column.size),
+                ConstraintLimit::SizeToFit(available_space.height.max()),
+            ));
+            height = height.max(size.height);
+        }
+
+        let mut width = UPx::ZERO;
+        for (cell, column) in self.cells.iter_mut().zip(&columns) {
+            let cell = cell.mounted(context);
+            context.set_child_layout(
+                &cell,
+                Rect::new(
+                    Point::new(column.offset, UPx::ZERO),
+                    Size::new(column.size, height),
+                )
+                .into_signed(),
+            );
+            width = column.offset + column.size;
+        }
+
+        Size::new(available_space.width.fit_measured(width), height)
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        for cell in &mut self.cells {
+            cell.unmount_in(context);
+        }
+    }
+
+    fn hit_test(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> bool {
+        // Interactive cells receive their own clicks. Rows are only selected
+        // by clicks that no cell accepts.
+        self.selection.mode != TableSelection::None && context.child_at(location).is_none()
+    }
+
+    fn mouse_down(
+        &mut self,
+        _location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button == MouseButton::Left && context.enabled() {
+            self.selection.click(self.row, context);
+            HANDLED
+        } else {
+            IGNORED
+        }
+    }
+}
+
+define_components! {
+    Table {
+        /// The size of the indicator drawn in the header of the column a
+        /// [`Table`] is sorted by.
+        SortIndicatorSize(Dimension, "sort_indicator_size", |context| context.get(&LineHeight) / 2)
+        /// The smallest width a [`Table`] column can be resized to.
+        MinimumColumnWidth(Dimension, "minimum_column_width", |context| context.get(&LineHeight) * 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{IntoSigned, Point, Size};
+    use kempt::Set;
+    use kludgine::app::winit::event::{ElementState, Modifiers, MouseButton};
+    use kludgine::app::winit::keyboard::ModifiersState;
+
+    use super::{SortDirection, Table, TableColumn, TableHeader, TableSelection, TableSort};
+    use crate::styles::Dimension;
+    use crate::utils::ModifiersStateExt;
+    use crate::value::{Destination, Dynamic, Source};
+    use crate::widget::MakeWidget;
+    use crate::widgets::grid::GridDimension;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    struct TestTable {
+        recorder: VirtualRecorder,
+        selection: Dynamic<Set<usize>>,
+        sort: Dynamic<Option<TableSort>>,
+        presses: Dynamic<usize>,
+    }
+
+    impl TestTable {
+        /// Returns a table with a name column, a value column, and a column of
+        /// buttons.
+        fn new(mode: TableSelection) -> Self {
+            let selection = Dynamic::<Set<usize>>::default();
+            let sort = Dynamic::<Option<TableSort>>::default();
+            let presses = Dynamic::new(0_usize);
+            let recorder = Table::new(
+                [
+                    TableColumn::new("Name").sortable(),
+                    TableColumn::new("Value").sortable(),
+                    TableColumn::new("Action"),
+                ],
+                5,
+                {
+                    let presses = presses.clone();
+                    move |row, column| match column {
+                        0 => format!("Row {row}").make_widget(),
+                        1 => (row * 10).to_string().make_widget(),
+                        _ => format!("Press {row}")
+                            .into_button()
+                            .on_click({
+                                let presses = presses.clone();
+                                move |_| *presses.lock() += 1
+                            })
+                            .make_widget(),
+                    }
+                },
+            )
+            .selection(selection.clone())
+            .sort(sort.clone())
+            .selection_mode(mode)
+            .build_recorder()
+            .size(Size::new(UPx::new(400), UPx::new(300)))
+            .finish()
+            .expect("error creating recorder");
+            let mut table = Self {
+                recorder,
+                selection,
+                sort,
+                presses,
+            };
+            table.recorder.refresh().expect("error refreshing");
+            table
+        }
+
+        fn click_text(&mut self, text: &str, modifiers: ModifiersState) {
+            let widget = self
+                .recorder
+                .window
+                .find_text(text)
+                .unwrap_or_else(|| panic!("{text} not found"));
+            self.recorder
+                .window
+                .set_modifiers(Modifiers::from(modifiers));
+            let _ = self.recorder.window.click(&widget);
+            self.recorder.window.set_modifiers(Modifiers::default());
+            self.recorder.refresh().expect("error refreshing");
+        }
+
+        fn selected(&self) -> Vec<usize> {
+            self.selection.get().iter().copied().collect()
+        }
+
+        fn drag(&mut self, from: Point<Px>, to: Point<Px>) {
+            let window = &mut self.recorder.window;
+            window.cursor_moved(DeviceId::Virtual(0), from);
+            let _ = window.mouse_input(
+                DeviceId::Virtual(0),
+                ElementState::Pressed,
+                MouseButton::Left,
+            );
+            window.cursor_moved(DeviceId::Virtual(0), to);
+            let _ = window.mouse_input(
+                DeviceId::Virtual(0),
+                ElementState::Released,
+                MouseButton::Left,
+            );
+            self.recorder.refresh().expect("error refreshing");
+        }
+    }
+
+    #[test]
+    fn header_click_sorts() {
+        let mut table = TestTable::new(TableSelection::Single);
+        assert_eq!(table.sort.get(), None);
+
+        table.click_text("Value", ModifiersState::empty());
+        assert_eq!(
+            table.sort.get(),
+            Some(TableSort {
+                column: 1,
+                direction: SortDirection::Ascending
+            })
+        );
+
+        // Clicking the sorted column reverses the direction.
+        table.click_text("Value", ModifiersState::empty());
+        assert_eq!(
+            table.sort.get(),
+            Some(TableSort {
+                column: 1,
+                direction: SortDirection::Descending
+            })
+        );
+        table.click_text("Value", ModifiersState::empty());
+        assert_eq!(
+            table.sort.get(),
+            Some(TableSort {
+                column: 1,
+                direction: SortDirection::Ascending
+            })
+        );
+
+        // Clicking another column sorts by it in ascending order.
+        table.sort.set(Some(TableSort {
+            column: 1,
+            direction: SortDirection::Descending,
+        }));
+        table.click_text("Name", ModifiersState::empty());
+        assert_eq!(
+            table.sort.get(),
+            Some(TableSort {
+                column: 0,
+                direction: SortDirection::Ascending
+            })
+        );
+
+        // Columns that are not sortable ignore clicks.
+        table.click_text("Action", ModifiersState::empty());
+        assert_eq!(table.sort.get().map(|sort| sort.column), Some(0));
+    }
+
+    #[test]
+    fn divider_drag_resizes() {
+        let mut table = TestTable::new(TableSelection::Single);
+        let header = table
+            .recorder
+            .window
+            .find_widget::<TableHeader>()
+            .expect("table header");
+        let layout = header.last_layout().expect("header laid out");
+        let (divider, width, dimensions) = {
+            let header = header.lock();
+            let header = header.downcast_ref::<TableHeader>().expect("table header");
+            let column = header.layout[0];
+            (
+                (column.offset + column.size + header.gutter / 2).into_signed(),
+                column.size.into_signed(),
+                header.dimensions.clone(),
+            )
+        };
+        let start = Point::new(
+            layout.origin.x + divider,
+            layout.origin.y + layout.size.height / 2,
+        );
+
+        table.drag(start, start + Point::new(Px::new(30), Px::ZERO));
+        assert!(
+            matches!(
+                dimensions.get()[0],
+                GridDimension::Measured {
+                    size: Dimension::Px(size)
+                } if size == width + Px::new(30)
+            ),
+            "column was not resized: {:?}",
+            dimensions.get()[0]
+        );
+        let resized = header
+            .lock()
+            .downcast_ref::<TableHeader>()
+            .expect("table header")
+            .layout[0]
+            .size
+            .into_signed();
+        assert_eq!(resized, width + Px::new(30));
+        // Resizing a column does not sort it.
+        assert_eq!(table.sort.get(), None);
+    }
+
+    #[test]
+    fn single_selection() {
+        let mut table = TestTable::new(TableSelection::Single);
+        table.click_text("Row 1", ModifiersState::empty());
+        assert_eq!(table.selected(), [1]);
+
+        // Modifiers do not extend a single selection.
+        table.click_text("Row 3", ModifiersState::PRIMARY);
+        assert_eq!(table.selected(), [3]);
+        table.click_text("20", ModifiersState::SHIFT);
+        assert_eq!(table.selected(), [2]);
+    }
+
+    #[test]
+    fn multiple_selection() {
+        let mut table = TestTable::new(TableSelection::Multiple);
+        table.click_text("Row 1", ModifiersState::empty());
+        table.click_text("Row 3", ModifiersState::PRIMARY);
+        assert_eq!(table.selected(), [1, 3]);
+
+        // Shift selects the range from the last clicked row.
+        table.click_text("Row 0", ModifiersState::SHIFT);
+        assert_eq!(table.selected(), [0, 1, 2, 3]);
+        table.click_text("Row 4", ModifiersState::SHIFT);
+        assert_eq!(table.selected(), [3, 4]);
+
+        // The primary modifier toggles rows.
+        table.click_text("Row 3", ModifiersState::PRIMARY);
+        assert_eq!(table.selected(), [4]);
+
+        // A plain click replaces the selection.
+        table.click_text("Row 2", ModifiersState::empty());
+        assert_eq!(table.selected(), [2]);
+    }
+
+    #[test]
+    fn interactive_cells_receive_clicks() {
+        let mut table = TestTable::new(TableSelection::Single);
+        table.click_text("Row 1", ModifiersState::empty());
+
+        table.click_text("Press 3", ModifiersState::empty());
+        assert_eq!(table.presses.get(), 1);
+        assert_eq!(table.selected(), [1]);
+
+        // Rows cannot be selected when selection is disabled, but their cells
+        // remain interactive.
+        let mut table = TestTable::new(TableSelection::None);
+        table.click_text("Row 1", ModifiersState::empty());
+        assert_eq!(table.selected(), Vec::<usize>::new());
+        table.click_text("Press 1", ModifiersState::empty());
+        assert_eq!(table.presses.get(), 1);
+    }
+}
@@ -0,0 +1,766 @@
+//! A widget that shows one of several pages of content, selected using a strip
+//! of tabs.
+
+use std::fmt::{self, Debug};
+
+use figures::units::{Lp, Px, UPx};
+use figures::{IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::keyboard::{Key, NamedKey};
+use kludgine::app::winit::window::CursorIcon;
+use kludgine::shapes::Shape;
+use kludgine::Color;
+
+use crate::context::{EventContext, GraphicsContext, LayoutContext};
+use crate::styles::components::{HighlightColor, IntrinsicPadding};
+use crate::styles::Dimension;
+use crate::value::{Destination, Dynamic, Generation, IntoDynamic, MapEach, Source};
+use crate::widget::{
+    EventHandling, MakeWidget, MakeWidgetWithTag, SharedCallback, Widget, WidgetId, WidgetInstance,
+    WidgetRef, WidgetTag, WrapperWidget, HANDLED, IGNORED,
+};
+use crate::widgets::button::{ButtonHoverBackground, ButtonKind};
+use crate::widgets::{Scroll, Space, Switcher};
+use crate::window::{DeviceId, KeyEvent};
+use crate::ConstraintLimit;
+
+/// A widget that displays the contents of the selected [`Tab`] beneath a strip
+/// of tabs.
+///
+/// The tabs are stored in a `Dynamic<Vec<Tab>>`, allowing tabs to be added and
+/// removed while the widget is shown. The index of the selected tab is stored
+/// in a `Dynamic<Option<usize>>`.
+///
+/// Tabs can be reordered by dragging them within the strip, and the strip
+/// scrolls horizontally when the tabs do not fit. While a widget within the
+/// tabs is focused, Ctrl+Tab selects the next tab and Ctrl+Shift+Tab selects
+/// the previous tab.
+#[derive(Debug)]
+pub struct Tabs {
+    tabs: Dynamic<Vec<Tab>>,
+    selected: Dynamic<Option<usize>>,
+    on_close: Option<SharedCallback<usize, bool>>,
+}
+
+impl Tabs {
+    /// Returns a new widget displaying `tabs`, with the first tab selected.
+    #[must_use]
+    pub fn new(tabs: impl IntoDynamic<Vec<Tab>>) -> Self {
+        let tabs = tabs.into_dynamic();
+        let selected = Dynamic::new(tabs.map_ref(|tabs| (!tabs.is_empty()).then_some(0)));
+        Self {
+            tabs,
+            selected,
+            on_close: None,
+        }
+    }
+
+    /// Sets the dynamic used to store the index of the selected tab.
+    #[must_use]
+    pub fn selected(mut self, selected: impl IntoDynamic<Option<usize>>) -> Self {
+        self.selected = selected.into_dynamic();
+        self
+    }
+
+    /// Invokes `on_close` with the index of a tab when its close button is
+    /// clicked.
+    ///
+    /// The tab is only closed if `on_close` returns true, allowing closing to
+    /// be vetoed, such as when a tab contains unsaved changes.
+    #[must_use]
+    pub fn on_close<F>(mut self, on_close: F) -> Self
+    where
+        F: FnMut(usize) -> bool + Send + 'static,
+    {
+        self.on_close = Some(SharedCallback::new(on_close));
+        self
+    }
+
+    /// Returns the dynamic containing the tabs of this widget.
+    #[must_use]
+    pub const fn tabs(&self) -> &Dynamic<Vec<Tab>> {
+        &self.tabs
+    }
+
+    /// Returns the dynamic containing the index of the selected tab.
+    #[must_use]
+    pub const fn selected_tab(&self) -> &Dynamic<Option<usize>> {
+        &self.selected
+    }
+}
+
+impl MakeWidgetWithTag for Tabs {
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let state = TabsState {
+            tabs: self.tabs,
+            selected: self.selected,
+            on_close: self.on_close,
+        };
+        let contents = (&state.tabs, &state.selected).map_each(|(tabs, selected)| {
+            selected
+                .and_then(|selected| tabs.get(selected))
+                .map_or_else(|| Space::clear().make_widget(), |tab| tab.content.clone())
+        });
+        let strip = TabStrip {
+            state: state.clone(),
+            children: Vec::new(),
+            layouts: Vec::new(),
+            layout_generation: None,
+            hovered: None,
+            dragging: None,
+        };
+
+        TabsContainer {
+            child: WidgetRef::new(
+                Scroll::horizontal(strip)
+                    .and(Switcher::new(contents).expand())
+                    .into_rows()
+                    .gutter(Px::ZERO),
+            ),
+            state,
+        }
+        .make_with_tag(tag)
+    }
+}
+
+/// A page of content shown in a [`Tabs`] widget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tab {
+    label: WidgetInstance,
+    content: WidgetInstance,
+    closable: bool,
+}
+
+impl Tab {
+    /// Returns a new tab that displays `label` in the tab strip and `content`
+    /// when selected.
+    pub fn new(label: impl MakeWidget, content: impl MakeWidget) -> Self {
+        Self {
+            label: label.make_widget(),
+            content: content.make_widget(),
+            closable: false,
+        }
+    }
+
+    /// Shows a button that closes this tab.
+    #[must_use]
+    pub fn closable(mut self) -> Self {
+        self.closable = true;
+        self
+    }
+
+    /// Returns the id of this tab's content widget, which uniquely identifies
+    /// this tab.
+    #[must_use]
+    pub fn id(&self) -> WidgetId {
+        self.content.id()
+    }
+}
+
+#[derive(Clone)]
+struct TabsState {
+    tabs: Dynamic<Vec<Tab>>,
+    selected: Dynamic<Option<usize>>,
+    on_close: Option<SharedCallback<usize, bool>>,
+}
+
+impl TabsState {
+    fn select_next(&self, forward: bool) -> bool {
+        let count = self.tabs.map_ref(Vec::len);
+        if count == 0 {
+            return false;
+        }
+        let next = match (self.selected.get(), forward) {
+            (Some(selected), true) => (selected + 1) % count,
+            (Some(selected), false) => (selected + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+        self.selected.set(Some(next));
+        true
+    }
+
+    fn close(&self, id: WidgetId) {
+        let Some(index) = self
+            .tabs
+            .map_ref(|tabs| tabs.iter().position(|tab| tab.id() == id))
+        else {
+            return;
+        };
+
+        if self
+            .on_close
+            .as_ref()
+            .map_or(true, |on_close| on_close.invoke(index))
+        {
+            let mut tabs = self.tabs.lock();
+            tabs.remove(index);
+            let remaining = tabs.len();
+            drop(tabs);
+            self.selected.set(selection_after_remove(
+                self.selected.get(),
+                index,
+                remaining,
+            ));
+        }
+    }
+
+    fn move_tab(&self, from: usize, to: usize) {
+        let mut tabs = self.tabs.lock();
+        let tab = tabs.remove(from);
+        tabs.insert(to, tab);
+        drop(tabs);
+        self.selected
+            .set(selection_after_move(self.selected.get(), from, to));
+    }
+}
+
+impl Debug for TabsState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TabsState")
+            .field("tabs", &self.tabs)
+            .field("selected", &self.selected)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the selected index after the tab at `removed` has been removed,
+/// leaving `remaining` tabs.
+fn selection_after_remove(
+    selected: Option<usize>,
+    removed: usize,
+    remaining: usize,
+) -> Option<usize> {
+    let selected = selected?;
+    if selected > removed {
+        Some(selected - 1)
+    } else if selected == removed {
+        remaining.checked_sub(1).map(|last| selected.min(last))
+    } else {
+        Some(selected)
+    }
+}
+
+/// Returns the selected index after the tab at `from` has been moved to `to`.
+fn selection_after_move(selected: Option<usize>, from: usize, to: usize) -> Option<usize> {
+    let selected = selected?;
+    Some(if selected == from {
+        to
+    } else if from < selected && selected <= to {
+        selected - 1
+    } else if to <= selected && selected < from {
+        selected + 1
+    } else {
+        selected
+    })
+}
+
+#[derive(Debug)]
+struct TabsContainer {
+    child: WidgetRef,
+    state: TabsState,
+}
+
+impl WrapperWidget for TabsContainer {
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.child
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let modifiers = context.modifiers().state();
+        if input.logical_key != Key::Named(NamedKey::Tab) || !modifiers.control_key() {
+            return IGNORED;
+        }
+
+        if input.state.is_pressed() && !self.state.select_next(!modifiers.shift_key()) {
+            return IGNORED;
+        }
+        HANDLED
+    }
+}
+
+#[derive(Debug)]
+struct TabStrip {
+    state: TabsState,
+    children: Vec<(WidgetId, WidgetRef)>,
+    layouts: Vec<Rect<Px>>,
+    layout_generation: Option<Generation>,
+    hovered: Option<usize>,
+    dragging: Option<usize>,
+}
+
+impl TabStrip {
+    fn synchronize_children(&mut self, context: &mut LayoutContext<'_, '_, '_, '_>) {
+        let tabs = self.state.tabs.get();
+        let unchanged = tabs.len() == self.children.len()
+            && tabs
+                .iter()
+                .zip(&self.children)
+                .all(|(tab, (id, _))| tab.id() == *id);
+        if !unchanged {
+            // Reuse the existing widgets for tabs that are still present.
+            let mut previous = std::mem::take(&mut self.children);
+            for tab in &tabs {
+                let id = tab.id();
+                let child = if let Some(index) =
+                    previous.iter().position(|(existing, _)| *existing == id)
+                {
+                    previous.remove(index).1
+                } else {
+                    WidgetRef::new(tab_chrome(tab, &self.state))
+                };
+                self.children.push((id, child));
+            }
+
+            for (_, mut removed) in previous {
+                removed.unmount_in(context);
+            }
+        }
+
+        // Keep the selection valid when tabs are removed externally.
+        if let Some(selected) = self.state.selected.get() {
+            if selected >= tabs.len() {
+                self.state.selected.set(tabs.len().checked_sub(1));
+            }
+        }
+    }
+
+    fn tab_at(&self, location: Point<Px>) -> Option<usize> {
+        self.layouts
+            .iter()
+            .position(|layout| layout.contains(location))
+    }
+}
+
+fn tab_chrome(tab: &Tab, state: &TabsState) -> WidgetInstance {
+    if tab.closable {
+        let id = tab.id();
+        let state = state.clone();
+        tab.label
+            .clone()
+            .and(
+                "\u{d7}"
+                    .into_button()
+                    .kind(ButtonKind::Transparent)
+                    .prevent_focus()
+                    .on_click(move |_| state.close(id)),
+            )
+            .into_columns()
+            .make_widget()
+    } else {
+        tab.label.clone()
+    }
+}
+
+impl Widget for TabStrip {
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let selected = self.state.selected.get_tracking_redraw(context);
+        let indicator = context
+            .get(&TabIndicatorSize)
+            .into_px(context.gfx.scale())
+            .round();
+
+        for (index, layout) in self.layouts.iter().enumerate() {
+            if self.hovered == Some(index) || self.dragging == Some(index) {
+                let color = context.get(&ButtonHoverBackground);
+                context.gfx.draw_shape(&Shape::filled_rect(*layout, color));
+            }
+            if selected == Some(index) {
+                let color = context.get(&TabIndicatorColor);
+                context.gfx.draw_shape(&Shape::filled_rect(
+                    Rect::new(
+                        Point::new(
+                            layout.origin.x,
+                            layout.origin.y + layout.size.height - indicator,
+                        ),
+                        Size::new(layout.size.width, indicator),
+                    ),
+                    color,
+                ));
+            }
+        }
+
+        for (_, child) in &mut self.children {
+            let child = child.mounted(context);
+            context.for_other(&child).redraw();
+        }
+    }
+
+    fn layout(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        context.invalidate_when_changed(&self.state.tabs);
+        self.synchronize_children(context);
+
+        let scale = context.gfx.scale();
+        let padding = context.get(&IntrinsicPadding).into_upx(scale).round();
+        let indicator = context.get(&TabIndicatorSize).into_upx(scale).round();
+        let child_constraints = Size::new(
+            ConstraintLimit::SizeToFit(UPx::MAX),
+            ConstraintLimit::SizeToFit(available_space.height.max()),
+        );
+
+        let measured = self
+            .children
+            .iter_mut()
+            .map(|(_, child)| {
+                let child = child.mounted(context);
+                let size = context.for_other(&child).layout(child_constraints);
+                (child, size)
+            })
+            .collect::<Vec<_>>();
+        let content_height = measured
+            .iter()
+            .map(|(_, size)| size.height)
+            .max()
+            .unwrap_or_default();
+        let height = content_height + padding * 2 + indicator;
+
+        self.layouts.clear();
+        let mut x = UPx::ZERO;
+        for (child, size) in measured {
+            let width = size.width + padding * 2;
+            context.set_child_layout(
+                &child,
+                Rect::new(
+                    Point::new(x + padding, padding + (content_height - size.height) / 2),
+                    size,
+                )
+                .into_signed(),
+            );
+            self.layouts
+                .push(Rect::new(Point::new(x, UPx::ZERO), Size::new(width, height)).into_signed());
+            x += width;
+        }
+        self.layout_generation = Some(self.state.tabs.generation());
+
+        Size::new(x.max(available_space.width.min()), height)
+    }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        true
+    }
+
+    fn hover(&mut self, location: Point<Px>, context: &mut EventContext<'_>) -> Option<CursorIcon> {
+        let hovered = self.tab_at(location);
+        if hovered != self.hovered {
+            self.hovered = hovered;
+            context.set_needs_redraw();
+        }
+        None
+    }
+
+    fn unhover(&mut self, context: &mut EventContext<'_>) {
+        if self.hovered.take().is_some() {
+            context.set_needs_redraw();
+        }
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left || !context.enabled() {
+            return IGNORED;
+        }
+        let Some(index) = self.tab_at(location) else {
+            return IGNORED;
+        };
+
+        self.state.selected.set(Some(index));
+        self.dragging = Some(index);
+        context.set_needs_redraw();
+        HANDLED
+    }
+
+    fn mouse_drag(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        _context: &mut EventContext<'_>,
+    ) {
+        let Some(dragging) = self.dragging else {
+            return;
+        };
+        // Wait until the previous move has been laid out before moving again.
+        if self.layout_generation != Some(self.state.tabs.generation()) {
+            return;
+        }
+        let Some(target) = self.tab_at(Point::new(location.x, Px::ZERO)) else {
+            return;
+        };
+
+        // Only move once the cursor has crossed the middle of the other tab,
+        // which prevents tabs of differing widths from swapping back and
+        // forth.
+        let layout = self.layouts[target];
+        let middle = layout.origin.x + layout.size.width / 2;
+        if (target > dragging && location.x > middle) || (target < dragging && location.x < middle)
+        {
+            self.state.move_tab(dragging, target);
+            self.dragging = Some(target);
+        }
+    }
+
+    fn mouse_up(
+        &mut self,
+        _location: Option<Point<Px>>,
+        _device_id: DeviceId,
+        _button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) {
+        self.dragging = None;
+        context.set_needs_redraw();
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        for (_, child) in &mut self.children {
+            child.unmount_in(context);
+        }
+    }
+}
+
+define_components! {
+    Tabs {
+        /// The thickness of the line drawn beneath the selected tab of a
+        /// [`Tabs`] widget.
+        TabIndicatorSize(Dimension, "tab_indicator_size", Dimension::Lp(Lp::points(2)))
+        /// The [`Color`] of the line drawn beneath the selected tab of a
+        /// [`Tabs`] widget.
+        TabIndicatorColor(Color, "tab_indicator_color", @HighlightColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use figures::units::{Px, UPx};
+    use figures::{Point, Size};
+    use kludgine::app::winit::event::{ElementState, Modifiers, MouseButton};
+    use kludgine::app::winit::keyboard::{ModifiersState, NamedKey};
+
+    use super::{selection_after_move, selection_after_remove, Tab, Tabs};
+    use crate::value::{Destination, Dynamic, Source};
+    use crate::widget::{MakeWidget, MountedWidget};
+    use crate::widgets::Input;
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    #[test]
+    fn tab_selection_after_remove() {
+        assert_eq!(selection_after_remove(Some(2), 0, 3), Some(1));
+        assert_eq!(selection_after_remove(Some(0), 2, 3), Some(0));
+        assert_eq!(selection_after_remove(Some(1), 1, 2), Some(1));
+        assert_eq!(selection_after_remove(Some(2), 2, 2), Some(1));
+        assert_eq!(selection_after_remove(Some(0), 0, 0), None);
+        assert_eq!(selection_after_remove(None, 0, 1), None);
+    }
+
+    #[test]
+    fn tab_selection_after_move() {
+        assert_eq!(selection_after_move(Some(1), 1, 3), Some(3));
+        assert_eq!(selection_after_move(Some(2), 1, 3), Some(1));
+        assert_eq!(selection_after_move(Some(2), 3, 1), Some(3));
+        assert_eq!(selection_after_move(Some(0), 1, 3), Some(0));
+        assert_eq!(selection_after_move(None, 1, 3), None);
+    }
+
+    struct TestTabs {
+        recorder: VirtualRecorder,
+        tabs: Dynamic<Vec<Tab>>,
+        selected: Dynamic<Option<usize>>,
+        allow_close: Dynamic<bool>,
+    }
+
+    impl TestTabs {
+        fn new() -> Self {
+            let tabs = Dynamic::new(
+                ["First", "Second", "Third"]
+                    .into_iter()
+                    .map(|label| {
+                        Tab::new(label, format!("{label} contents").into_input()).closable()
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            let selected = Dynamic::new(Some(0));
+            let allow_close = Dynamic::new(false);
+            let mut recorder = Tabs::new(tabs.clone())
+                .selected(selected.clone())
+                .on_close({
+                    let allow_close = allow_close.clone();
+                    move |_| allow_close.get()
+                })
+                .build_recorder()
+                .size(Size::new(UPx::new(400), UPx::new(200)))
+                .finish()
+                .expect("error creating recorder");
+            recorder.refresh().expect("error refreshing");
+            Self {
+                recorder,
+                tabs,
+                selected,
+                allow_close,
+            }
+        }
+
+        /// Returns the displayed tab labels from left to right.
+        fn labels(&self) -> Vec<String> {
+            ["First", "Second", "Third"]
+                .into_iter()
+                .filter_map(|label| {
+                    let widget = self.recorder.window.find_text(label)?;
+                    Some((widget.last_layout()?.origin.x, label.to_string()))
+                })
+                .collect::<BTreeMap<_, _>>()
+                .into_values()
+                .collect()
+        }
+
+        fn label(&self, text: &str) -> MountedWidget {
+            self.recorder.window.find_text(text).expect("tab label")
+        }
+
+        fn focus_content(&mut self) {
+            let input = self
+                .recorder
+                .window
+                .find_widget::<Input<String>>()
+                .expect("tab content");
+            self.recorder.window.focus(&input);
+            self.recorder.refresh().expect("error refreshing");
+            self.recorder.window.assert_focused(&input);
+        }
+
+        fn press_tab(&mut self, modifiers: ModifiersState) {
+            self.recorder
+                .window
+                .set_modifiers(Modifiers::from(modifiers));
+            let _ = self.recorder.window.press_key(NamedKey::Tab);
+            self.recorder.window.set_modifiers(Modifiers::default());
+            self.recorder.refresh().expect("error refreshing");
+        }
+
+        fn mouse_button(&mut self, state: ElementState) {
+            let _ =
+                self.recorder
+                    .window
+                    .mouse_input(DeviceId::Virtual(0), state, MouseButton::Left);
+        }
+    }
+
+    #[test]
+    fn close_veto() {
+        let mut tabs = TestTabs::new();
+        let close = tabs
+            .recorder
+            .window
+            .find_text("\u{d7}")
+            .expect("close button");
+
+        // Returning false from the callback keeps the tab.
+        let _ = tabs.recorder.window.click(&close);
+        tabs.recorder.refresh().expect("error refreshing");
+        assert_eq!(tabs.tabs.map_ref(Vec::len), 3);
+        assert_eq!(tabs.labels(), ["First", "Second", "Third"]);
+        assert_eq!(tabs.selected.get(), Some(0));
+
+        tabs.allow_close.set(true);
+        let _ = tabs.recorder.window.click(&close);
+        tabs.recorder.refresh().expect("error refreshing");
+        assert_eq!(tabs.tabs.map_ref(Vec::len), 2);
+        assert_eq!(tabs.labels(), ["Second", "Third"]);
+        assert_eq!(tabs.selected.get(), Some(0));
+    }
+
+    #[test]
+    fn keyboard_switching() {
+        let mut tabs = TestTabs::new();
+        let next_tab = ModifiersState::CONTROL;
+        let previous_tab = ModifiersState::CONTROL | ModifiersState::SHIFT;
+
+        tabs.focus_content();
+        tabs.press_tab(next_tab);
+        assert_eq!(tabs.selected.get(), Some(1));
+        tabs.focus_content();
+        tabs.press_tab(next_tab);
+        assert_eq!(tabs.selected.get(), Some(2));
+        // Switching wraps around in both directions.
+        tabs.focus_content();
+        tabs.press_tab(next_tab);
+        assert_eq!(tabs.selected.get(), Some(0));
+        tabs.focus_content();
+        tabs.press_tab(previous_tab);
+        assert_eq!(tabs.selected.get(), Some(2));
+        tabs.focus_content();
+        tabs.press_tab(previous_tab);
+        assert_eq!(tabs.selected.get(), Some(1));
+
+        // Tab without Control continues to move focus rather than switching
+        // tabs.
+        tabs.focus_content();
+        tabs.press_tab(ModifiersState::empty());
+        tabs.press_tab(ModifiersState::SHIFT);
+        assert_eq!(tabs.selected.get(), Some(1));
+    }
+
+    #[test]
+    fn drag_reorders() {
+        let mut tabs = TestTabs::new();
+        let first = tabs.label("First").last_layout().expect("laid out");
+        tabs.recorder.window.cursor_moved(
+            DeviceId::Virtual(0),
+            first.origin + Point::new(first.size.width / 2, first.size.height / 2),
+        );
+        tabs.mouse_button(ElementState::Pressed);
+
+        // Moving onto the first half of the next tab does not reorder the
+        // tabs.
+        let second = tabs.label("Second").last_layout().expect("laid out");
+        tabs.recorder.window.cursor_moved(
+            DeviceId::Virtual(0),
+            second.origin + Point::new(Px::new(1), Px::new(1)),
+        );
+        tabs.recorder.refresh().expect("error refreshing");
+        assert_eq!(tabs.labels(), ["First", "Second", "Third"]);
+
+        // Crossing the middle of the next tab moves the dragged tab.
+        tabs.recorder.window.cursor_moved(
+            DeviceId::Virtual(0),
+            second.origin + Point::new(second.size.width - Px::new(1), Px::new(1)),
+        );
+        tabs.recorder.refresh().expect("error refreshing");
+        assert_eq!(tabs.labels(), ["Second", "First", "Third"]);
+
+        // The drag continues until the button is released.
+        let third = tabs.label("Third").last_layout().expect("laid out");
+        tabs.recorder.window.cursor_moved(
+            DeviceId::Virtual(0),
+            third.origin + Point::new(third.size.width - Px::new(1), Px::new(1)),
+        );
+        tabs.mouse_button(ElementState::Released);
+        tabs.recorder.refresh().expect("error refreshing");
+        assert_eq!(tabs.labels(), ["Second", "Third", "First"]);
+        // The dragged tab remains selected.
+        assert_eq!(tabs.selected.get(), Some(2));
+        assert_eq!(
+            tabs.tabs.map_ref(|tabs| tabs[2].id()),
+            tabs.recorder
+                .window
+                .find_widget::<Input<String>>()
+                .expect("tab content")
+                .id()
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use std::fmt::Debug;
+use std::time::Instant;
 
 use figures::units::{Px, UPx};
 use figures::{Point, Size};
 use intentional::Cast;
-use kludgine::app::winit::event::{ElementState, MouseScrollDelta, TouchPhase};
+use kludgine::app::winit::event::{ElementState, MouseButton, MouseScrollDelta, TouchPhase};
 use kludgine::app::winit::window::CursorIcon;
 use kludgine::tilemap;
 use kludgine::tilemap::TileMapFocus;
@@ -12,6 +13,7 @@ use crate::context::{EventContext, GraphicsContext, LayoutContext, Trackable};
 use crate::tick::Tick;
 use crate::value::{Dynamic, IntoValue, Value};
 use crate::widget::{EventHandling, Widget, HANDLED, IGNORED};
+use crate::widgets::gestures::{Gesture, GestureRecognizer};
 use crate::window::{DeviceId, KeyEvent};
 use crate::ConstraintLimit;
 
@@ -23,6 +25,7 @@ pub struct TileMap<Layers> {
     focus: Value<TileMapFocus>,
     zoom: f32,
     tick: Option<Tick>,
+    gestures: GestureRecognizer,
 }
 
 impl<Layers> TileMap<Layers> {
@@ -32,6 +35,7 @@ impl<Layers> TileMap<Layers> {
             focus: Value::default(),
             zoom: 1.,
             tick: None,
+            gestures: GestureRecognizer::new(),
         }
     }
 
@@ -142,6 +146,51 @@ where
         HANDLED
     }
 
+    fn touch_down(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        self.gestures.set_scale(context.kludgine.scale());
+        self.gestures
+            .touch_down(device_id, location, Instant::now());
+        HANDLED
+    }
+
+    fn touch_moved(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        context: &mut EventContext<'_>,
+    ) {
+        if let Some(Gesture::Pinch(pinch)) =
+            self.gestures
+                .touch_moved(device_id, location, Instant::now())
+        {
+            self.zoom *= pinch.scale;
+            context.set_needs_redraw();
+        }
+    }
+
+    fn touch_up(
+        &mut self,
+        location: Point<Px>,
+        device_id: DeviceId,
+        cancelled: bool,
+        context: &mut EventContext<'_>,
+    ) {
+        // Taps are delivered to the tick as clicks of the primary button.
+        if let Some(Gesture::Tap(location)) =
+            self.gestures
+                .touch_up(device_id, location, cancelled, Instant::now())
+        {
+            self.hover(location, context);
+            let _ = self.mouse_down(location, device_id, MouseButton::Left, context);
+            self.mouse_up(Some(location), device_id, MouseButton::Left, context);
+        }
+    }
+
     fn hover(&mut self, local: Point<Px>, context: &mut EventContext<'_>) -> Option<CursorIcon> {
         if let Some(tick) = &self.tick {
             let size = context.last_layout().map(|rect| rect.size)?;
@@ -182,7 +231,7 @@ where
         &mut self,
         _location: Point<Px>,
         _device_id: DeviceId,
-        button: kludgine::app::winit::event::MouseButton,
+        button: MouseButton,
         context: &mut EventContext<'_>,
     ) -> EventHandling {
         if let Some(tick) = &self.tick {
@@ -198,7 +247,7 @@ where
         &mut self,
         _location: Option<Point<Px>>,
         _device_id: DeviceId,
-        button: kludgine::app::winit::event::MouseButton,
+        button: MouseButton,
         _context: &mut EventContext<'_>,
     ) {
         if let Some(tick) = &self.tick {
@@ -206,3 +255,53 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use figures::units::UPx;
+    use figures::{Point, Px2D, Size};
+    use kludgine::app::winit::event::TouchPhase;
+    use kludgine::tilemap::DebugGrid;
+
+    use super::TileMap;
+    use crate::widget::{MakeWidget, MountedWidget};
+    use crate::window::DeviceId;
+
+    const FIRST: DeviceId = DeviceId::finger(None, 0);
+    const SECOND: DeviceId = DeviceId::finger(None, 1);
+
+    fn zoom(tilemap: &MountedWidget) -> f32 {
+        tilemap
+            .lock()
+            .downcast_ref::<TileMap<DebugGrid>>()
+            .expect("tilemap")
+            .zoom
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn pinch_to_zoom() {
+        let mut recorder = TileMap::new(DebugGrid)
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        let window = &mut recorder.window;
+        let tilemap = window
+            .find_widget::<TileMap<DebugGrid>>()
+            .expect("tilemap widget");
+        assert_eq!(zoom(&tilemap), 1.);
+
+        window.touch(FIRST, TouchPhase::Started, Point::px(50, 100));
+        window.touch(SECOND, TouchPhase::Started, Point::px(100, 100));
+        window.touch(SECOND, TouchPhase::Moved, Point::px(150, 100));
+        assert_eq!(zoom(&tilemap), 2.);
+        // Pinching fingers together zooms back out.
+        window.touch(SECOND, TouchPhase::Moved, Point::px(75, 100));
+        assert_eq!(zoom(&tilemap), 0.5);
+        window.touch(FIRST, TouchPhase::Ended, Point::px(50, 100));
+        window.touch(SECOND, TouchPhase::Ended, Point::px(75, 100));
+        assert_eq!(zoom(&tilemap), 0.5);
+    }
+}
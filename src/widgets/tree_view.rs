@@ -0,0 +1,905 @@
+//! A widget that displays a hierarchy of expandable rows.
+
+use std::fmt::{self, Debug};
+use std::sync::Arc;
+
+use figures::units::{Px, UPx};
+use figures::{Angle, IntoSigned, Point, Rect, Round, ScreenScale, Size, Zero};
+use kempt::{Map, Set};
+use kludgine::app::winit::event::MouseButton;
+use kludgine::app::winit::keyboard::{Key, NamedKey};
+use kludgine::shapes::{PathBuilder, Shape};
+use kludgine::{Color, DrawableExt};
+use parking_lot::Mutex;
+
+use crate::context::{EventContext, GraphicsContext, LayoutContext};
+use crate::styles::components::{
+    HighlightColor, LineHeight, OutlineColor, OutlineWidth, TextColor,
+};
+use crate::styles::Dimension;
+use crate::value::{Destination, Dynamic, Generation, IntoDynamic, Source, Watcher};
+use crate::widget::{
+    EventHandling, MakeWidget, MakeWidgetWithTag, Widget, WidgetId, WidgetInstance, WidgetRef,
+    WidgetTag, WrapperWidget, HANDLED, IGNORED,
+};
+use crate::widgets::scroll::ScrollIntoView;
+use crate::widgets::{Space, VirtualList, VirtualListScroller};
+use crate::window::{DeviceId, KeyEvent};
+use crate::ConstraintLimit;
+
+/// A source of hierarchical data for a [`TreeView`].
+///
+/// Nodes are identified by [`TreeSource::Node`], which is used to track which
+/// nodes are expanded and selected. Children are only requested from the
+/// source the first time their parent is expanded.
+pub trait TreeSource: Send + 'static {
+    /// The type that uniquely identifies a node in the tree.
+    type Node: Clone + Ord + Debug + Send + 'static;
+
+    /// Returns the nodes at the root of the tree.
+    fn roots(&mut self) -> Vec<Self::Node>;
+
+    /// Returns true if `node` can be expanded to show children.
+    ///
+    /// This function should be inexpensive, as it is invoked for each node
+    /// shown in the tree.
+    fn has_children(&mut self, node: &Self::Node) -> bool;
+
+    /// Returns the children of `node`.
+    ///
+    /// This function is invoked the first time `node` is expanded. The result
+    /// is cached until the tree is reloaded using [`TreeView::reload_watcher`].
+    fn children(&mut self, node: &Self::Node) -> Vec<Self::Node>;
+
+    /// Returns the widget to display for `node`.
+    fn node_widget(&mut self, node: &Self::Node) -> WidgetInstance;
+}
+
+/// A virtualized view of hierarchical data.
+///
+/// Each row of a tree view displays a node from a [`TreeSource`], indented by
+/// its depth. Nodes with children can be expanded and collapsed by clicking
+/// their indicator or by using the arrow keys while the tree is focused:
+///
+/// - Up/Down: Select the previous/next visible node.
+/// - Right: Expand the selected node, or select its first child if it is
+///   already expanded.
+/// - Left: Collapse the selected node, or select its parent if it is already
+///   collapsed.
+/// - Home/End: Select the first/last visible node.
+///
+/// Only the rows currently visible are loaded, using the same strategy as
+/// [`VirtualList`]. Each row is sized to match the first visible row.
+pub struct TreeView<S>
+where
+    S: TreeSource,
+{
+    source: S,
+    expanded: Dynamic<Set<S::Node>>,
+    selected: Dynamic<Option<S::Node>>,
+    reload: Watcher,
+}
+
+impl<S> TreeView<S>
+where
+    S: TreeSource,
+{
+    /// Returns a new tree view displaying the nodes from `source`.
+    #[must_use]
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            expanded: Dynamic::default(),
+            selected: Dynamic::default(),
+            reload: Watcher::default(),
+        }
+    }
+
+    /// Sets the dynamic that contains the set of expanded nodes and returns
+    /// self.
+    #[must_use]
+    pub fn expanded(mut self, expanded: impl IntoDynamic<Set<S::Node>>) -> Self {
+        self.expanded = expanded.into_dynamic();
+        self
+    }
+
+    /// Sets the dynamic that contains the selected node and returns self.
+    #[must_use]
+    pub fn selected(mut self, selected: impl IntoDynamic<Option<S::Node>>) -> Self {
+        self.selected = selected.into_dynamic();
+        self
+    }
+
+    /// Returns the dynamic containing the set of expanded nodes.
+    #[must_use]
+    pub const fn expanded_nodes(&self) -> &Dynamic<Set<S::Node>> {
+        &self.expanded
+    }
+
+    /// Returns the dynamic containing the selected node.
+    #[must_use]
+    pub const fn selected_node(&self) -> &Dynamic<Option<S::Node>> {
+        &self.selected
+    }
+
+    /// Returns a [`Watcher`] that when notified will discard all loaded
+    /// children and reload the tree from its source.
+    #[must_use]
+    pub const fn reload_watcher(&self) -> &Watcher {
+        &self.reload
+    }
+}
+
+impl<S> MakeWidgetWithTag for TreeView<S>
+where
+    S: TreeSource,
+{
+    fn make_with_tag(self, tag: WidgetTag) -> WidgetInstance {
+        let tree = tag.id();
+        let row_count = Dynamic::new(0);
+        let shared = Arc::new(TreeShared {
+            state: Mutex::new(TreeState {
+                source: self.source,
+                roots: None,
+                children: Map::new(),
+                rows: Vec::new(),
+                expanded_generation: None,
+                reload_generation: self.reload.get(),
+            }),
+            expanded: self.expanded,
+            selected: self.selected,
+            reload: self.reload,
+            row_count: row_count.clone(),
+            rows_changed: Watcher::default(),
+        });
+
+        let list = VirtualList::new(row_count, {
+            let shared = shared.clone();
+            move |index| {
+                let mut state = shared.state.lock();
+                let Some(row) = state.rows.get(index).cloned() else {
+                    return Space::clear().make_widget();
+                };
+                let content = state.source.node_widget(&row.node);
+                drop(state);
+                TreeRow {
+                    row,
+                    content: WidgetRef::new(content),
+                    shared: shared.clone(),
+                    tree,
+                }
+                .make_widget()
+            }
+        });
+
+        Tree {
+            list_contents: list.content_watcher().clone(),
+            rows_generation: shared.rows_changed.get(),
+            scroller: list.scroller(),
+            list: WidgetRef::new(list),
+            shared,
+        }
+        .make_with_tag(tag)
+    }
+}
+
+struct TreeShared<S>
+where
+    S: TreeSource,
+{
+    state: Mutex<TreeState<S>>,
+    expanded: Dynamic<Set<S::Node>>,
+    selected: Dynamic<Option<S::Node>>,
+    reload: Watcher,
+    row_count: Dynamic<usize>,
+    rows_changed: Watcher,
+}
+
+impl<S> TreeShared<S>
+where
+    S: TreeSource,
+{
+    /// Rebuilds the list of visible rows if the expanded nodes have changed
+    /// or a reload was requested.
+    fn refresh(&self) {
+        let expanded_generation = self.expanded.generation();
+        let reload_generation = self.reload.get();
+        let mut state = self.state.lock();
+        if state.reload_generation != reload_generation {
+            state.reload_generation = reload_generation;
+            state.roots = None;
+            state.children.clear();
+            state.expanded_generation = None;
+        }
+        if state.expanded_generation == Some(expanded_generation) {
+            return;
+        }
+
+        state.expanded_generation = Some(expanded_generation);
+        self.expanded.map_ref(|expanded| state.rebuild(expanded));
+        let row_count = state.rows.len();
+        drop(state);
+
+        self.row_count.set(row_count);
+        self.rows_changed.notify();
+    }
+
+    fn set_expanded(&self, node: &S::Node, expanded: bool) {
+        let mut nodes = self.expanded.lock();
+        if expanded {
+            nodes.insert(node.clone());
+        } else {
+            nodes.remove(node);
+        }
+        drop(nodes);
+        self.refresh();
+    }
+}
+
+impl<S> Debug for TreeShared<S>
+where
+    S: TreeSource,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeShared")
+            .field("expanded", &self.expanded)
+            .field("selected", &self.selected)
+            .finish_non_exhaustive()
+    }
+}
+
+struct TreeState<S>
+where
+    S: TreeSource,
+{
+    source: S,
+    roots: Option<Vec<S::Node>>,
+    children: Map<S::Node, Vec<S::Node>>,
+    rows: Vec<TreeRowInfo<S::Node>>,
+    expanded_generation: Option<Generation>,
+    reload_generation: usize,
+}
+
+impl<S> TreeState<S>
+where
+    S: TreeSource,
+{
+    fn rebuild(&mut self, expanded: &Set<S::Node>) {
+        self.rows.clear();
+        if self.roots.is_none() {
+            self.roots = Some(self.source.roots());
+        }
+        for root in self.roots.clone().unwrap_or_default() {
+            self.push_row(root, 0, None, expanded);
+        }
+    }
+
+    fn push_row(
+        &mut self,
+        node: S::Node,
+        depth: usize,
+        parent: Option<usize>,
+        expanded: &Set<S::Node>,
+    ) {
+        let has_children = self.source.has_children(&node);
+        let is_expanded = has_children && expanded.contains(&node);
+        let index = self.rows.len();
+        self.rows.push(TreeRowInfo {
+            node: node.clone(),
+            depth,
+            parent,
+            has_children,
+            expanded: is_expanded,
+        });
+
+        if is_expanded {
+            if self.children.get(&node).is_none() {
+                let children = self.source.children(&node);
+                self.children.insert(node.clone(), children);
+            }
+            let children = self.children.get(&node).cloned().unwrap_or_default();
+            for child in children {
+                self.push_row(child, depth + 1, Some(index), expanded);
+            }
+        }
+    }
+
+    fn index_of(&self, node: &S::Node) -> Option<usize> {
+        self.rows.iter().position(|row| &row.node == node)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TreeRowInfo<Node> {
+    node: Node,
+    depth: usize,
+    parent: Option<usize>,
+    has_children: bool,
+    expanded: bool,
+}
+
+struct Tree<S>
+where
+    S: TreeSource,
+{
+    list: WidgetRef,
+    list_contents: Watcher,
+    rows_generation: usize,
+    shared: Arc<TreeShared<S>>,
+    scroller: VirtualListScroller,
+}
+
+impl<S> Debug for Tree<S>
+where
+    S: TreeSource,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tree")
+            .field("list", &self.list)
+            .field("shared", &self.shared)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> Tree<S>
+where
+    S: TreeSource,
+{
+    fn select(&self, index: usize) {
+        let state = self.shared.state.lock();
+        let Some(row) = state.rows.get(index) else {
+            return;
+        };
+        let node = row.node.clone();
+        drop(state);
+
+        self.shared.selected.set(Some(node));
+        self.scroller.scroll_to_row(index, ScrollIntoView::new());
+    }
+
+    fn navigate(&self, key: NamedKey) {
+        self.shared.refresh();
+        let state = self.shared.state.lock();
+        let Some(last) = state.rows.len().checked_sub(1) else {
+            return;
+        };
+        let current = self
+            .shared
+            .selected
+            .map_ref(|selected| selected.as_ref().and_then(|node| state.index_of(node)));
+        let Some(current) = current else {
+            drop(state);
+            self.select(if key == NamedKey::End { last } else { 0 });
+            return;
+        };
+
+        let row = &state.rows[current];
+        let target = match key {
+            NamedKey::ArrowUp => current.saturating_sub(1),
+            NamedKey::ArrowDown => (current + 1).min(last),
+            NamedKey::Home => 0,
+            NamedKey::End => last,
+            NamedKey::ArrowRight if row.has_children && !row.expanded => {
+                let node = row.node.clone();
+                drop(state);
+                self.shared.set_expanded(&node, true);
+                return;
+            }
+            NamedKey::ArrowRight if row.expanded && current < last => current + 1,
+            NamedKey::ArrowLeft if row.expanded => {
+                let node = row.node.clone();
+                drop(state);
+                self.shared.set_expanded(&node, false);
+                return;
+            }
+            NamedKey::ArrowLeft => row.parent.unwrap_or(current),
+            _ => current,
+        };
+        drop(state);
+
+        self.select(target);
+    }
+}
+
+impl<S> WrapperWidget for Tree<S>
+where
+    S: TreeSource,
+{
+    fn child_mut(&mut self) -> &mut WidgetRef {
+        &mut self.list
+    }
+
+    fn adjust_child_constraints(
+        &mut self,
+        available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<ConstraintLimit> {
+        context.invalidate_when_changed(&self.shared.expanded);
+        context.invalidate_when_changed(&self.shared.reload);
+        self.shared.refresh();
+
+        // The list must discard its loaded rows any time the visible rows
+        // change, as the same index may now refer to a different node.
+        let rows_generation = self.shared.rows_changed.get();
+        if rows_generation != self.rows_generation {
+            self.rows_generation = rows_generation;
+            self.list_contents.notify();
+        }
+
+        available_space
+    }
+
+    fn redraw_foreground(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        if context.focused(true) {
+            context.draw_focus_ring();
+        }
+    }
+
+    fn accept_focus(&mut self, context: &mut EventContext<'_>) -> bool {
+        context.enabled()
+    }
+
+    fn focus(&mut self, context: &mut EventContext<'_>) {
+        context.set_needs_redraw();
+    }
+
+    fn blur(&mut self, context: &mut EventContext<'_>) {
+        context.set_needs_redraw();
+    }
+
+    fn keyboard_input(
+        &mut self,
+        _device_id: DeviceId,
+        input: KeyEvent,
+        _is_synthetic: bool,
+        _context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        let Key::Named(
+            key @ (NamedKey::ArrowUp
+            | NamedKey::ArrowDown
+            | NamedKey::ArrowLeft
+            | NamedKey::ArrowRight
+            | NamedKey::Home
+            | NamedKey::End),
+        ) = input.logical_key
+        else {
+            return IGNORED;
+        };
+
+        if input.state.is_pressed() {
+            self.navigate(key);
+        }
+
+        HANDLED
+    }
+}
+
+struct TreeRow<S>
+where
+    S: TreeSource,
+{
+    row: TreeRowInfo<S::Node>,
+    content: WidgetRef,
+    shared: Arc<TreeShared<S>>,
+    tree: WidgetId,
+}
+
+impl<S> Debug for TreeRow<S>
+where
+    S: TreeSource,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeRow")
+            .field("row", &self.row)
+            .field("content", &self.content)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> TreeRow<S>
+where
+    S: TreeSource,
+{
+    fn draw_indicator(&self, indent: Px, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        let depth = i32::try_from(self.row.depth).unwrap_or(i32::MAX);
+        let height = context.gfx.region().size.height;
+        let radius = (indent / 4).round();
+        let pt1 = Point::new(radius, Px::ZERO);
+        let pt2 = Point::new(radius, Px::ZERO).rotate_by(Angle::degrees(120));
+        let pt3 = Point::new(radius, Px::ZERO).rotate_by(Angle::degrees(240));
+        let path = PathBuilder::new(pt1).line_to(pt2).line_to(pt3).close();
+        let center = Point::new(indent * depth + indent / 2, height / 2).round();
+        let angle = if self.row.expanded {
+            Angle::degrees(90)
+        } else {
+            Angle::degrees(0)
+        };
+
+        context.gfx.draw_shape(
+            path.fill(context.get(&TextColor))
+                .translate_by(center)
+                .rotate_by(angle),
+        );
+    }
+}
+
+impl<S> Widget for TreeRow<S>
+where
+    S: TreeSource,
+{
+    fn redraw(&mut self, context: &mut GraphicsContext<'_, '_, '_, '_>) {
+        context.redraw_when_changed(&self.shared.selected);
+        if self
+            .shared
+            .selected
+            .map_ref(|selected| selected.as_ref() == Some(&self.row.node))
+        {
+            let highlight = context.get(&HighlightColor);
+            context.fill(highlight);
+        }
+
+        let indent = context
+            .get(&IndentSize)
+            .into_px(context.gfx.scale())
+            .round();
+        let height = context.gfx.region().size.height;
+        let line_width = context
+            .get(&OutlineWidth)
+            .into_px(context.gfx.scale())
+            .round()
+            .max(Px::new(1));
+        let guide_color = context.get(&IndentGuideColor);
+        for level in 0..self.row.depth {
+            let level = i32::try_from(level).unwrap_or(i32::MAX);
+            let x = indent * level + indent / 2 - line_width / 2;
+            context.gfx.draw_shape(&Shape::filled_rect(
+                Rect::new(Point::new(x, Px::ZERO), Size::new(line_width, height)),
+                guide_color,
+            ));
+        }
+
+        if self.row.has_children {
+            self.draw_indicator(indent, context);
+        }
+
+        let content = self.content.mounted(context);
+        context.for_other(&content).redraw();
+    }
+
+    fn layout(
+        &mut self,
+        mut available_space: Size<ConstraintLimit>,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) -> Size<UPx> {
+        let indent = context
+            .get(&IndentSize)
+            .into_upx(context.gfx.scale())
+            .round();
+        let inset = indent * u32::try_from(self.row.depth + 1).unwrap_or(u32::MAX);
+        let width_constraint = available_space.width;
+        available_space.width -= inset;
+
+        let content = self.content.mounted(context);
+        let content_size = context.for_other(&content).layout(available_space);
+        let height = content_size.height.max(indent);
+        context.set_child_layout(
+            &content,
+            Rect::new(
+                Point::new(inset, UPx::ZERO),
+                Size::new(content_size.width, height),
+            )
+            .into_signed(),
+        );
+
+        Size::new(
+            width_constraint.fit_measured(inset + content_size.width),
+            height,
+        )
+    }
+
+    fn unmounted(&mut self, context: &mut EventContext<'_>) {
+        self.content.unmount_in(context);
+    }
+
+    fn hit_test(&mut self, _location: Point<Px>, _context: &mut EventContext<'_>) -> bool {
+        true
+    }
+
+    fn mouse_down(
+        &mut self,
+        location: Point<Px>,
+        _device_id: DeviceId,
+        button: MouseButton,
+        context: &mut EventContext<'_>,
+    ) -> EventHandling {
+        if button != MouseButton::Left || !context.enabled() {
+            return IGNORED;
+        }
+
+        if let Some(mut tree) = context.for_other(&self.tree) {
+            tree.focus();
+        }
+
+        let indent = context
+            .get(&IndentSize)
+            .into_px(context.kludgine.scale())
+            .round();
+        let depth = i32::try_from(self.row.depth).unwrap_or(i32::MAX);
+        let indicator_start = indent * depth;
+        if self.row.has_children
+            && location.x >= indicator_start
+            && location.x < indicator_start + indent
+        {
+            self.shared.set_expanded(&self.row.node, !self.row.expanded);
+        }
+        self.shared.selected.set(Some(self.row.node.clone()));
+
+        HANDLED
+    }
+}
+
+define_components! {
+    TreeView {
+        /// The amount each level of a [`TreeView`] is indented by.
+        IndentSize(Dimension, "indent_size", @LineHeight)
+        /// The [`Color`] of the guides drawn to show the indentation of nodes
+        /// in a [`TreeView`].
+        IndentGuideColor(Color, "indent_guide_color", @OutlineColor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::{Point, Size, Zero};
+    use kludgine::app::winit::event::{ElementState, MouseButton};
+    use kludgine::app::winit::keyboard::NamedKey;
+
+    use super::{Tree, TreeRow, TreeSource, TreeView};
+    use crate::value::{Dynamic, Source};
+    use crate::widget::{MakeWidget, MountedWidget, WidgetInstance};
+    use crate::window::{DeviceId, VirtualRecorder};
+
+    /// A tree with roots A, B, and C that each have two children.
+    struct Letters;
+
+    impl TreeSource for Letters {
+        type Node = String;
+
+        fn roots(&mut self) -> Vec<Self::Node> {
+            vec![String::from("A"), String::from("B"), String::from("C")]
+        }
+
+        fn has_children(&mut self, node: &Self::Node) -> bool {
+            node.len() == 1
+        }
+
+        fn children(&mut self, node: &Self::Node) -> Vec<Self::Node> {
+            (1..=2).map(|child| format!("{node}.{child}")).collect()
+        }
+
+        fn node_widget(&mut self, node: &Self::Node) -> WidgetInstance {
+            node.clone().make_widget()
+        }
+    }
+
+    struct TestTree {
+        recorder: VirtualRecorder,
+        expanded: Dynamic<kempt::Set<String>>,
+        selected: Dynamic<Option<String>>,
+    }
+
+    impl TestTree {
+        fn new() -> Self {
+            Self::with_height(300)
+        }
+
+        fn with_height(height: u32) -> Self {
+            let expanded = Dynamic::<kempt::Set<String>>::default();
+            let selected = Dynamic::<Option<String>>::default();
+            let mut recorder = TreeView::new(Letters)
+                .expanded(expanded.clone())
+                .selected(selected.clone())
+                .build_recorder()
+                .size(Size::new(UPx::new(300), UPx::new(height)))
+                .finish()
+                .expect("error creating recorder");
+            recorder.refresh().expect("error refreshing");
+            Self {
+                recorder,
+                expanded,
+                selected,
+            }
+        }
+
+        fn tree(&self) -> MountedWidget {
+            self.recorder
+                .window
+                .find_widget::<Tree<Letters>>()
+                .expect("tree widget")
+        }
+
+        fn is_expanded(&self, node: &str) -> bool {
+            self.expanded
+                .map_ref(|expanded| expanded.contains(&node.to_owned()))
+        }
+
+        /// Returns the nodes of the currently displayed rows, from top to
+        /// bottom.
+        fn visible_rows(&self) -> Vec<String> {
+            let mut rows = self
+                .recorder
+                .window
+                .find_widgets::<TreeRow<Letters>>()
+                .into_iter()
+                .map(|row| {
+                    let top = row.last_layout().map_or(Px::ZERO, |layout| layout.origin.y);
+                    let node = row
+                        .lock()
+                        .downcast_ref::<TreeRow<Letters>>()
+                        .expect("tree row")
+                        .row
+                        .node
+                        .clone();
+                    (top, node)
+                })
+                .collect::<Vec<_>>();
+            rows.sort_by_key(|(top, _)| *top);
+            rows.into_iter().map(|(_, node)| node).collect()
+        }
+
+        fn row(&self, node: &str) -> MountedWidget {
+            self.recorder
+                .window
+                .find_widgets::<TreeRow<Letters>>()
+                .into_iter()
+                .find(|row| {
+                    row.lock()
+                        .downcast_ref::<TreeRow<Letters>>()
+                        .is_some_and(|row| row.row.node == node)
+                })
+                .expect("row not found")
+        }
+
+        /// Clicks the expand/collapse indicator of the root `node`.
+        fn click_indicator(&mut self, node: &str) {
+            let layout = self
+                .row(node)
+                .last_layout()
+                .expect("row should be laid out");
+            let location = layout.origin + Point::new(Px::new(2), layout.size.height / 2);
+            let window = &mut self.recorder.window;
+            window.cursor_moved(DeviceId::Virtual(0), location);
+            let _ = window.mouse_input(
+                DeviceId::Virtual(0),
+                ElementState::Pressed,
+                MouseButton::Left,
+            );
+            let _ = window.mouse_input(
+                DeviceId::Virtual(0),
+                ElementState::Released,
+                MouseButton::Left,
+            );
+            self.recorder.refresh().expect("error refreshing");
+        }
+
+        fn press(&mut self, key: NamedKey) {
+            let _ = self.recorder.window.press_key(key);
+            self.recorder.refresh().expect("error refreshing");
+        }
+
+        fn selected(&self) -> Option<String> {
+            self.selected.get()
+        }
+
+        /// Asserts that the row displaying `node` is entirely within the
+        /// tree's visible area.
+        #[track_caller]
+        fn assert_row_visible(&self, node: &str) {
+            let visible = self.tree().last_layout().expect("tree laid out");
+            let layout = self.row(node).last_layout().expect("row laid out");
+            assert!(
+                layout.origin.y >= visible.origin.y
+                    && layout.origin.y + layout.size.height
+                        <= visible.origin.y + visible.size.height,
+                "{node} is not visible in {visible:?}: {layout:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn expand_collapse() {
+        let mut tree = TestTree::new();
+        assert_eq!(tree.visible_rows(), ["A", "B", "C"]);
+
+        // Clicking the indicator expands the node, selects it, and focuses
+        // the tree.
+        tree.click_indicator("A");
+        assert!(tree.is_expanded("A"));
+        assert_eq!(tree.visible_rows(), ["A", "A.1", "A.2", "B", "C"]);
+        assert_eq!(tree.selected().as_deref(), Some("A"));
+        assert!(tree.tree().focused());
+
+        // Clicking the content of a row only selects it.
+        let label = tree.recorder.window.find_text("A.2").expect("A.2 label");
+        let _ = tree.recorder.window.click(&label);
+        tree.recorder.refresh().expect("error refreshing");
+        assert_eq!(tree.selected().as_deref(), Some("A.2"));
+        assert_eq!(tree.visible_rows(), ["A", "A.1", "A.2", "B", "C"]);
+
+        tree.click_indicator("A");
+        assert!(!tree.is_expanded("A"));
+        assert_eq!(tree.visible_rows(), ["A", "B", "C"]);
+
+        // Expanded nodes can also be set directly.
+        tree.expanded.lock().insert(String::from("C"));
+        tree.recorder.refresh().expect("error refreshing");
+        assert_eq!(tree.visible_rows(), ["A", "B", "C", "C.1", "C.2"]);
+    }
+
+    #[test]
+    fn keyboard_navigation() {
+        let mut tree = TestTree::new();
+        let widget = tree.tree();
+        tree.recorder.window.focus(&widget);
+        tree.recorder.refresh().expect("error refreshing");
+        assert!(widget.focused());
+
+        // With nothing selected, the first row is selected.
+        tree.press(NamedKey::ArrowDown);
+        assert_eq!(tree.selected().as_deref(), Some("A"));
+        tree.press(NamedKey::ArrowDown);
+        assert_eq!(tree.selected().as_deref(), Some("B"));
+
+        // Right expands the selection, and then moves to its first child.
+        tree.press(NamedKey::ArrowRight);
+        assert!(tree.is_expanded("B"));
+        assert_eq!(tree.selected().as_deref(), Some("B"));
+        tree.press(NamedKey::ArrowRight);
+        assert_eq!(tree.selected().as_deref(), Some("B.1"));
+        tree.press(NamedKey::ArrowDown);
+        assert_eq!(tree.selected().as_deref(), Some("B.2"));
+
+        // Left moves to the parent, and then collapses it.
+        tree.press(NamedKey::ArrowLeft);
+        assert_eq!(tree.selected().as_deref(), Some("B"));
+        tree.press(NamedKey::ArrowLeft);
+        assert!(!tree.is_expanded("B"));
+        assert_eq!(tree.visible_rows(), ["A", "B", "C"]);
+
+        tree.press(NamedKey::End);
+        assert_eq!(tree.selected().as_deref(), Some("C"));
+        tree.press(NamedKey::ArrowDown);
+        assert_eq!(tree.selected().as_deref(), Some("C"));
+        tree.press(NamedKey::Home);
+        assert_eq!(tree.selected().as_deref(), Some("A"));
+        tree.press(NamedKey::ArrowUp);
+        assert_eq!(tree.selected().as_deref(), Some("A"));
+    }
+
+    #[test]
+    fn selection_scrolls_into_view() {
+        let mut tree = TestTree::with_height(80);
+        tree.expanded
+            .set(["A", "B", "C"].into_iter().map(String::from).collect());
+        tree.recorder.refresh().expect("error refreshing");
+        let widget = tree.tree();
+        tree.recorder.window.focus(&widget);
+        tree.recorder.refresh().expect("error refreshing");
+
+        tree.press(NamedKey::End);
+        assert_eq!(tree.selected().as_deref(), Some("C.2"));
+        tree.assert_row_visible("C.2");
+
+        tree.press(NamedKey::Home);
+        assert_eq!(tree.selected().as_deref(), Some("A"));
+        tree.assert_row_visible("A");
+
+        // Moving down one row at a time keeps the selection visible.
+        for _ in 0..5 {
+            tree.press(NamedKey::ArrowDown);
+        }
+        assert_eq!(tree.selected().as_deref(), Some("B.2"));
+        tree.assert_row_visible("B.2");
+    }
+}
@@ -6,7 +6,8 @@ use cushy::context::LayoutContext;
 use cushy::ConstraintLimit;
 use figures::IntoUnsigned;
 
-use super::scroll::OwnedWidget;
+use super::scroll::{scroll_region_into_view, scroll_to_show, OwnedWidget, ScrollIntoView};
+use crate::animation::{AnimationHandle, AnimationTarget, IntoAnimate, Spawn};
 use crate::context::{AsEventContext, EventContext, Trackable};
 use crate::figures::units::{Px, UPx};
 use crate::figures::{IntoSigned, Point, Rect, Round, Size, Zero};
@@ -68,6 +69,8 @@ pub struct VirtualList {
     item_size: Dynamic<Size<UPx>>,
 
     visible_range: Dynamic<Range<usize>>,
+    scroll_target: Dynamic<Option<(usize, ScrollIntoView)>>,
+    scroll_animation: AnimationHandle,
 }
 
 impl VirtualList {
@@ -141,6 +144,8 @@ impl VirtualList {
             item_size,
             item_count,
             visible_range: Dynamic::default(),
+            scroll_target: Dynamic::default(),
+            scroll_animation: AnimationHandle::default(),
         }
     }
 
@@ -176,6 +181,22 @@ impl VirtualList {
         self.visible_range.create_reader()
     }
 
+    /// Returns a handle that can be used to scroll this list to a specific
+    /// row, even after this list has been mounted.
+    #[must_use]
+    pub fn scroller(&self) -> VirtualListScroller {
+        VirtualListScroller(self.scroll_target.clone())
+    }
+
+    /// Scrolls this list to show the row at `index` the next time it is laid
+    /// out.
+    ///
+    /// Only the vertical scroll is affected. To scroll after this list has been
+    /// turned into a widget, use [`VirtualList::scroller`].
+    pub fn scroll_to_row(&self, index: usize, options: ScrollIntoView) {
+        self.scroller().scroll_to_row(index, options);
+    }
+
     fn show_scrollbars(&mut self, context: &mut EventContext<'_>) {
         let mut vertical = self.vertical_scroll.expect_made_mut().widget().lock();
         vertical
@@ -262,10 +283,18 @@ impl VirtualList {
         }
 
         self.layout_scrollbars(available_space, new_control_size, context);
-        let scroll = self.scroll.get_tracking_invalidate(context);
 
         let max_scroll_x = item_size.width.saturating_sub(new_control_size.width);
         let max_scroll_y = content_height.saturating_sub(new_control_size.height);
+        self.apply_scroll_target(
+            item_count,
+            item_size.height,
+            new_control_size.height,
+            max_scroll_y,
+            context,
+        );
+
+        let scroll = self.scroll.get_tracking_invalidate(context);
         let scroll = scroll.min(Point::new(max_scroll_x, max_scroll_y));
 
         let start_item = (scroll.y.floor() / item_size.height).floor().get() as usize;
@@ -331,6 +360,51 @@ impl VirtualList {
         new_control_size
     }
 
+    fn apply_scroll_target(
+        &mut self,
+        item_count: usize,
+        item_height: UPx,
+        control_height: UPx,
+        max_scroll_y: UPx,
+        context: &mut LayoutContext<'_, '_, '_, '_>,
+    ) {
+        let Some((row, options)) = self.scroll_target.get_tracking_invalidate(context) else {
+            return;
+        };
+        // Clearing the target should not cause another layout.
+        let mut target = self.scroll_target.lock();
+        target.prevent_notifications();
+        *target = None;
+        drop(target);
+
+        let row = row.min(item_count - 1);
+        let row_top = item_height * u32::try_from(row).unwrap_or(u32::MAX);
+        let current = self.scroll.get();
+        let y = scroll_to_show(
+            current.y.into_signed(),
+            row_top.into_signed() - current.y.into_signed(),
+            item_height.into_signed(),
+            control_height.into_signed(),
+            max_scroll_y.into_signed(),
+            options.align,
+        )
+        .into_unsigned();
+        if y != current.y {
+            let target = Point::new(current.x, y);
+            if let Some((duration, easing)) = options.animation {
+                self.scroll_animation = self
+                    .scroll
+                    .transition_to(target)
+                    .over(duration)
+                    .with_easing(easing)
+                    .spawn();
+            } else {
+                self.scroll_animation.clear();
+                self.scroll.set(target);
+            }
+        }
+    }
+
     fn calculate_item_size(
         &mut self,
         available_space: Size<ConstraintLimit>,
@@ -379,6 +453,30 @@ impl Widget for VirtualList {
             .unhover(context);
     }
 
+    fn scroll_into_view(
+        &mut self,
+        region: Rect<Px>,
+        options: &ScrollIntoView,
+        context: &mut EventContext<'_>,
+    ) -> Rect<Px> {
+        let Some(viewport) = context.last_layout() else {
+            return region;
+        };
+        let scrolled = scroll_region_into_view(
+            &self.scroll,
+            &mut self.scroll_animation,
+            self.max_scroll.get(),
+            Point::new(true, true),
+            viewport,
+            region,
+            options,
+        );
+        if scrolled != region {
+            self.show_scrollbars(context);
+        }
+        scrolled
+    }
+
     fn mounted(&mut self, context: &mut EventContext<'_>) {
         for child in &mut self.items {
             child.mounted.remount_if_needed(context);
@@ -439,6 +537,7 @@ impl Widget for VirtualList {
                 .is_break();
         }
         if handled {
+            self.scroll_animation.clear();
             self.show_scrollbars(context);
             context.set_needs_redraw();
 
@@ -448,3 +547,65 @@ impl Widget for VirtualList {
         }
     }
 }
+
+/// A handle that scrolls a [`VirtualList`] to show a specific row.
+#[derive(Debug, Clone)]
+pub struct VirtualListScroller(Dynamic<Option<(usize, ScrollIntoView)>>);
+
+impl VirtualListScroller {
+    /// Scrolls the list to show the row at `index` the next time it is laid
+    /// out.
+    ///
+    /// If `index` is past the end of the list, the last row will be shown.
+    pub fn scroll_to_row(&self, index: usize, options: ScrollIntoView) {
+        self.0.set(Some((index, options)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use figures::units::{Px, UPx};
+    use figures::Size;
+
+    use super::VirtualList;
+    use crate::value::Source;
+    use crate::widget::MakeWidget;
+    use crate::widgets::scroll::{ScrollAlign, ScrollIntoView};
+
+    #[test]
+    fn scroll_to_unrealized_row() {
+        let list = VirtualList::new(100, |row| format!("Row {row}"));
+        let scroller = list.scroller();
+        let visible = list.visible_range();
+        let mut recorder = list
+            .build_recorder()
+            .size(Size::new(UPx::new(200), UPx::new(200)))
+            .finish()
+            .expect("error creating recorder");
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(visible.get().start, 0);
+        assert!(recorder.window.find_text("Row 50").is_none());
+
+        scroller.scroll_to_row(50, ScrollIntoView::new().align(ScrollAlign::Start));
+        recorder.refresh().expect("error refreshing");
+        assert_eq!(visible.get().start, 50);
+        let row = recorder
+            .window
+            .find_text("Row 50")
+            .and_then(|row| row.last_layout())
+            .expect("row realized");
+        assert_eq!(row.origin.y, Px::ZERO);
+        assert!(recorder.window.find_text("Row 0").is_none());
+
+        // Rows past the end of the list show the last row.
+        scroller.scroll_to_row(1_000, ScrollIntoView::new());
+        recorder.refresh().expect("error refreshing");
+        let row = recorder
+            .window
+            .find_text("Row 99")
+            .and_then(|row| row.last_layout())
+            .expect("row realized");
+        assert!(row.origin.y >= Px::ZERO);
+        assert!(row.origin.y + row.size.height <= Px::new(200));
+    }
+}
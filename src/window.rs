@@ -43,8 +43,10 @@ use sealed::{Ize, PreShowCallback, WindowExecute};
 use tracing::Level;
 use unicode_segmentation::UnicodeSegmentation;
 
+#[cfg(feature = "accessibility")]
+use crate::accessibility::{AccessibilityTree, Action, ActionRequest, TreeUpdate};
 use crate::animation::{
-    AnimationTarget, Easing, LinearInterpolate, PercentBetween, Spawn, ZeroToOne,
+    AnimationTarget, Easing, LinearInterpolate, PercentBetween, Spawn, SpawnedAnimations, ZeroToOne,
 };
 use crate::app::{Application, Cushy, Open, PendingApp, Run};
 use crate::context::sealed::{InvalidationStatus, Trackable as _};
@@ -62,7 +64,7 @@ use crate::value::{
 };
 use crate::widget::{
     EventHandling, MakeWidget, MountedWidget, Notify, OnceCallback, RootBehavior, SharedCallback,
-    WidgetId, WidgetInstance, HANDLED, IGNORED,
+    Widget, WidgetId, WidgetInstance, HANDLED, IGNORED,
 };
 use crate::widgets::shortcuts::{ShortcutKey, ShortcutMap};
 use crate::window::sealed::WindowCommand;
@@ -575,6 +577,8 @@ where
     fullscreen: Option<Value<Option<Fullscreen>>>,
     shortcuts: Value<ShortcutMap>,
     on_file_drop: Option<Notify<FileDrop>>,
+    #[cfg(feature = "accessibility")]
+    on_accessibility_update: Option<Notify<TreeUpdate>>,
 }
 
 impl<Behavior> Default for Window<Behavior>
@@ -673,6 +677,8 @@ where
             shortcuts: Value::default(),
             on_init: None,
             on_file_drop: None,
+            #[cfg(feature = "accessibility")]
+            on_accessibility_update: None,
         }
     }
 
@@ -1040,6 +1046,33 @@ where
         self
     }
 
+    /// Invokes `on_update` each time this window's accessibility tree changes.
+    ///
+    /// The first update contains the entire tree. Each subsequent update only
+    /// contains the nodes that have changed, making these updates suitable
+    /// for forwarding to an AccessKit platform adapter.
+    #[cfg(feature = "accessibility")]
+    pub fn on_accessibility_update<Function>(self, on_update: Function) -> Self
+    where
+        Function: FnMut(TreeUpdate) + Send + 'static,
+    {
+        self.on_accessibility_update_notify(on_update)
+    }
+
+    /// Notifies `on_update` each time this window's accessibility tree changes.
+    ///
+    /// The first update contains the entire tree. Each subsequent update only
+    /// contains the nodes that have changed, making these updates suitable
+    /// for forwarding to an AccessKit platform adapter.
+    #[cfg(feature = "accessibility")]
+    pub fn on_accessibility_update_notify(
+        mut self,
+        on_update: impl Into<Notify<TreeUpdate>>,
+    ) -> Self {
+        self.on_accessibility_update = Some(on_update.into());
+        self
+    }
+
     /// Sets the window's title.
     pub fn titled(mut self, title: impl IntoValue<MaybeLocalized>) -> Self {
         self.title = title.into_value();
@@ -1184,6 +1217,8 @@ where
                     fullscreen: this.fullscreen.unwrap_or_default(),
                     shortcuts: this.shortcuts,
                     on_file_drop: this.on_file_drop,
+                    #[cfg(feature = "accessibility")]
+                    on_accessibility_update: this.on_accessibility_update,
                 }),
                 pending: this.pending,
             },
@@ -1344,6 +1379,7 @@ struct OpenWindow<T> {
     contents: Drawing,
     cursor: CursorState,
     mouse_buttons: AHashMap<DeviceId, AHashMap<MouseButton, WidgetId>>,
+    touches: AHashMap<DeviceId, TouchTarget>,
     redraw_status: InvalidationStatus,
     initial_frame: bool,
     occluded: Dynamic<bool>,
@@ -1382,9 +1418,16 @@ struct OpenWindow<T> {
     enabled_buttons: Tracked<Value<WindowButtons>>,
     fullscreen: Tracked<Value<Option<Fullscreen>>>,
     modifiers: Dynamic<Modifiers>,
+    animations: SpawnedAnimations,
     shortcuts: Value<ShortcutMap>,
     on_file_drop: Option<Notify<FileDrop>>,
     disabled_resize_automatically: bool,
+    #[cfg(feature = "accessibility")]
+    title: Value<MaybeLocalized>,
+    #[cfg(feature = "accessibility")]
+    accessibility: AccessibilityTree,
+    #[cfg(feature = "accessibility")]
+    on_accessibility_update: Option<Notify<TreeUpdate>>,
 }
 
 impl<T> OpenWindow<T>
@@ -1794,6 +1837,7 @@ where
                 widget: None,
             },
             mouse_buttons: AHashMap::default(),
+            touches: AHashMap::default(),
             redraw_status,
             initial_frame: true,
             occluded: settings.occluded,
@@ -1830,11 +1874,18 @@ where
             outer_position: Tracked::from(settings.outer_position).ignoring_first(),
             window_icon: Tracked::from(settings.window_icon).ignoring_first_if(Option::is_none),
             modifiers: settings.modifiers,
+            animations: SpawnedAnimations::default(),
             enabled_buttons: Tracked::from(settings.enabled_buttons).ignoring_first(),
             fullscreen: Tracked::from(settings.fullscreen).ignoring_first(),
             shortcuts: settings.shortcuts,
             on_file_drop: settings.on_file_drop,
             disabled_resize_automatically: false,
+            #[cfg(feature = "accessibility")]
+            title: settings.title.clone(),
+            #[cfg(feature = "accessibility")]
+            accessibility: AccessibilityTree::default(),
+            #[cfg(feature = "accessibility")]
+            on_accessibility_update: settings.on_accessibility_update,
         };
 
         this.synchronize_platform_window(&mut window);
@@ -1870,6 +1921,7 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
 
         self.synchronize_platform_window(&mut window);
         self.new_frame(graphics);
@@ -1978,6 +2030,20 @@ where
         }
 
         layout_context.as_event_context().update_hovered_widget();
+
+        #[cfg(feature = "accessibility")]
+        {
+            let title = self
+                .title
+                .map(|title| title.localize_for_cushy(self.app.cushy()));
+            let (nodes, focus) =
+                crate::accessibility::build_nodes(&mut layout_context, Some(title));
+            if let Some(update) = self.accessibility.update(nodes, focus) {
+                if let Some(on_update) = &mut self.on_accessibility_update {
+                    on_update.notify(update);
+                }
+            }
+        }
     }
 
     fn mount_and_focus_root(root: &MountedWidget, context: &mut LayoutContext<'_, '_, '_, '_>) {
@@ -2019,6 +2085,7 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         let mut window = RunningWindow::new(
             window,
             kludgine.id(),
@@ -2167,6 +2234,7 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         let mut window = RunningWindow::new(
             window,
             kludgine.id(),
@@ -2228,6 +2296,7 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         let mut window = RunningWindow::new(
             window,
             kludgine.id(),
@@ -2274,6 +2343,7 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         let mut window = RunningWindow::new(
             window,
             kludgine.id(),
@@ -2321,6 +2391,7 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         let mut window = RunningWindow::new(
             window,
             kludgine.id(),
@@ -2331,8 +2402,18 @@ where
             self.inner_size.source(),
             &self.close_requested,
         );
+        self.cursor_moved_in(&mut window, kludgine, device_id, position.into());
+    }
 
-        let location = position.into();
+    fn cursor_moved_in<W>(
+        &mut self,
+        window: &mut RunningWindow<W>,
+        kludgine: &mut Kludgine,
+        device_id: DeviceId,
+        location: Point<Px>,
+    ) where
+        W: PlatformWindowImplementation,
+    {
         self.cursor.location = Some(location);
         self.cursor_position.set_and_read(location);
 
@@ -2340,7 +2421,7 @@ where
             WidgetContext::new(
                 self.root.clone(),
                 &self.current_theme,
-                &mut window,
+                window,
                 &mut self.fonts,
                 self.theme_mode.get(),
                 &mut self.cursor,
@@ -2361,7 +2442,7 @@ where
                     WidgetContext::new(
                         handler.clone(),
                         &self.current_theme,
-                        &mut window,
+                        window,
                         &mut self.fonts,
                         self.theme_mode.get(),
                         &mut self.cursor,
@@ -2384,26 +2465,33 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
+        let mut window = RunningWindow::new(
+            window,
+            kludgine.id(),
+            &self.redraw_status,
+            &self.app,
+            &self.focused,
+            &self.occluded,
+            self.inner_size.source(),
+            &self.close_requested,
+        );
+        self.cursor_left_in(&mut window, kludgine);
+    }
+
+    fn cursor_left_in<W>(&mut self, window: &mut RunningWindow<W>, kludgine: &mut Kludgine)
+    where
+        W: PlatformWindowImplementation,
+    {
         self.cursor.location = None;
         self.cursor_position
             .set_and_read(Point::squared(Px::new(-1)));
         if self.cursor.widget.take().is_some() {
-            let mut window = RunningWindow::new(
-                window,
-                kludgine.id(),
-                &self.redraw_status,
-                &self.app,
-                &self.focused,
-                &self.occluded,
-                self.inner_size.source(),
-                &self.close_requested,
-            );
-
             let mut context = EventContext::new(
                 WidgetContext::new(
                     self.root.clone(),
                     &self.current_theme,
-                    &mut window,
+                    window,
                     &mut self.fonts,
                     self.theme_mode.get(),
                     &mut self.cursor,
@@ -2436,6 +2524,19 @@ where
             self.inner_size.source(),
             &self.close_requested,
         );
+        self.mouse_down_in(&mut window, kludgine, device_id, button)
+    }
+
+    fn mouse_down_in<W>(
+        &mut self,
+        window: &mut RunningWindow<W>,
+        kludgine: &mut Kludgine,
+        device_id: DeviceId,
+        button: MouseButton,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
         if let (Some(location), Some(hovered)) = (
             self.cursor.location,
             self.cursor
@@ -2448,7 +2549,7 @@ where
                     WidgetContext::new(
                         hovered.clone(),
                         &self.current_theme,
-                        &mut window,
+                        window,
                         &mut self.fonts,
                         self.theme_mode.get(),
                         &mut self.cursor,
@@ -2476,7 +2577,7 @@ where
                 WidgetContext::new(
                     self.root.clone(),
                     &self.current_theme,
-                    &mut window,
+                    window,
                     &mut self.fonts,
                     self.theme_mode.get(),
                     &mut self.cursor,
@@ -2510,6 +2611,19 @@ where
             self.inner_size.source(),
             &self.close_requested,
         );
+        self.mouse_up_in(&mut window, kludgine, device_id, button)
+    }
+
+    fn mouse_up_in<W>(
+        &mut self,
+        window: &mut RunningWindow<W>,
+        kludgine: &mut Kludgine,
+        device_id: DeviceId,
+        button: MouseButton,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
         let Some(device_buttons) = self.mouse_buttons.get_mut(&device_id) else {
             return IGNORED;
         };
@@ -2527,7 +2641,7 @@ where
             WidgetContext::new(
                 handler,
                 &self.current_theme,
-                &mut window,
+                window,
                 &mut self.fonts,
                 self.theme_mode.get(),
                 &mut self.cursor,
@@ -2562,12 +2676,308 @@ where
     {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         match state {
             ElementState::Pressed => self.mouse_down(window, kludgine, device_id, button),
             ElementState::Released => self.mouse_up(window, kludgine, device_id, button),
         }
     }
 
+    fn touch<W>(
+        &mut self,
+        window: W,
+        kludgine: &mut Kludgine,
+        device_id: DeviceId,
+        phase: TouchPhase,
+        location: Point<Px>,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        let cushy = self.app.cushy().clone();
+        let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
+        let mut window = RunningWindow::new(
+            window,
+            kludgine.id(),
+            &self.redraw_status,
+            &self.app,
+            &self.focused,
+            &self.occluded,
+            self.inner_size.source(),
+            &self.close_requested,
+        );
+        match phase {
+            TouchPhase::Started => self.touch_down(&mut window, kludgine, device_id, location),
+            TouchPhase::Moved => self.touch_moved(&mut window, kludgine, device_id, location),
+            TouchPhase::Ended | TouchPhase::Cancelled => self.touch_up(
+                &mut window,
+                kludgine,
+                device_id,
+                location,
+                phase == TouchPhase::Cancelled,
+            ),
+        }
+    }
+
+    fn touch_down<W>(
+        &mut self,
+        window: &mut RunningWindow<W>,
+        kludgine: &mut Kludgine,
+        device_id: DeviceId,
+        location: Point<Px>,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        let mut context = EventContext::new(
+            WidgetContext::new(
+                self.root.clone(),
+                &self.current_theme,
+                window,
+                &mut self.fonts,
+                self.theme_mode.get(),
+                &mut self.cursor,
+                #[cfg(feature = "localization")]
+                &self.app.cushy().data.localizations,
+            ),
+            kludgine,
+        );
+        let mut handler = None;
+        for widget in context.tree.widgets_under_point(location) {
+            let mut widget_context = context.for_other(&widget);
+            let Some(layout) = widget_context.last_layout() else {
+                continue;
+            };
+            if widget_context.hit_test(location - layout.origin) {
+                handler = recursively_handle_event(&mut widget_context, |context| {
+                    let Some(layout) = context.last_layout() else {
+                        return IGNORED;
+                    };
+                    context.touch_down(location - layout.origin, device_id)
+                });
+                break;
+            }
+        }
+        drop(context);
+
+        if let Some(handler) = handler {
+            self.touches
+                .insert(device_id, TouchTarget::Widget(handler.id()));
+            HANDLED
+        } else if self.touches.is_empty() {
+            // Deliver the touch as a left mouse button so that widgets without
+            // touch support can be used.
+            self.touches.insert(device_id, TouchTarget::Mouse);
+            self.cursor_moved_in(window, kludgine, device_id, location);
+            self.mouse_down_in(window, kludgine, device_id, MouseButton::Left)
+        } else {
+            IGNORED
+        }
+    }
+
+    fn touch_moved<W>(
+        &mut self,
+        window: &mut RunningWindow<W>,
+        kludgine: &mut Kludgine,
+        device_id: DeviceId,
+        location: Point<Px>,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        match self.touches.get(&device_id).copied() {
+            Some(TouchTarget::Widget(handler)) => {
+                let Some(handler) = self.tree.widget(handler) else {
+                    return IGNORED;
+                };
+                let mut context = EventContext::new(
+                    WidgetContext::new(
+                        handler,
+                        &self.current_theme,
+                        window,
+                        &mut self.fonts,
+                        self.theme_mode.get(),
+                        &mut self.cursor,
+                        #[cfg(feature = "localization")]
+                        &self.app.cushy().data.localizations,
+                    ),
+                    kludgine,
+                );
+                let Some(layout) = context.last_layout() else {
+                    return IGNORED;
+                };
+                context.touch_moved(location - layout.origin, device_id);
+                HANDLED
+            }
+            Some(TouchTarget::Mouse) => {
+                self.cursor_moved_in(window, kludgine, device_id, location);
+                HANDLED
+            }
+            None => IGNORED,
+        }
+    }
+
+    fn touch_up<W>(
+        &mut self,
+        window: &mut RunningWindow<W>,
+        kludgine: &mut Kludgine,
+        device_id: DeviceId,
+        location: Point<Px>,
+        cancelled: bool,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        match self.touches.remove(&device_id) {
+            Some(TouchTarget::Widget(handler)) => {
+                let Some(handler) = self.tree.widget(handler) else {
+                    return IGNORED;
+                };
+                let mut context = EventContext::new(
+                    WidgetContext::new(
+                        handler,
+                        &self.current_theme,
+                        window,
+                        &mut self.fonts,
+                        self.theme_mode.get(),
+                        &mut self.cursor,
+                        #[cfg(feature = "localization")]
+                        &self.app.cushy().data.localizations,
+                    ),
+                    kludgine,
+                );
+                let Some(layout) = context.last_layout() else {
+                    return IGNORED;
+                };
+                context.touch_up(location - layout.origin, device_id, cancelled);
+                HANDLED
+            }
+            Some(TouchTarget::Mouse) => {
+                self.cursor_moved_in(window, kludgine, device_id, location);
+                let _ = self.mouse_up_in(window, kludgine, device_id, MouseButton::Left);
+                // Fingers do not hover once lifted.
+                self.cursor_left_in(window, kludgine);
+                HANDLED
+            }
+            None => IGNORED,
+        }
+    }
+
+    fn focus_widget<W>(&mut self, window: W, kludgine: &mut Kludgine, widget: &MountedWidget)
+    where
+        W: PlatformWindowImplementation,
+    {
+        let cushy = self.app.cushy().clone();
+        let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
+        let mut window = RunningWindow::new(
+            window,
+            kludgine.id(),
+            &self.redraw_status,
+            &self.app,
+            &self.focused,
+            &self.occluded,
+            self.inner_size.source(),
+            &self.close_requested,
+        );
+        let mut context = EventContext::new(
+            WidgetContext::new(
+                widget.clone(),
+                &self.current_theme,
+                &mut window,
+                &mut self.fonts,
+                self.theme_mode.get(),
+                &mut self.cursor,
+                #[cfg(feature = "localization")]
+                &self.app.cushy().data.localizations,
+            ),
+            kludgine,
+        );
+        context.focus();
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_action<W>(
+        &mut self,
+        window: W,
+        kludgine: &mut Kludgine,
+        request: &ActionRequest,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        let cushy = self.app.cushy().clone();
+        let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
+        let Some(target) = self.tree.widget(WidgetId::from(request.target)) else {
+            return IGNORED;
+        };
+        let mut window = RunningWindow::new(
+            window,
+            kludgine.id(),
+            &self.redraw_status,
+            &self.app,
+            &self.focused,
+            &self.occluded,
+            self.inner_size.source(),
+            &self.close_requested,
+        );
+        let mut context = EventContext::new(
+            WidgetContext::new(
+                target.clone(),
+                &self.current_theme,
+                &mut window,
+                &mut self.fonts,
+                self.theme_mode.get(),
+                &mut self.cursor,
+                #[cfg(feature = "localization")]
+                &self.app.cushy().data.localizations,
+            ),
+            kludgine,
+        );
+        if recursively_handle_event(&mut context, |context| {
+            context.accessibility_action(request)
+        })
+        .is_some()
+        {
+            return HANDLED;
+        }
+        if !context.enabled() {
+            return IGNORED;
+        }
+
+        match request.action {
+            Action::Focus => {
+                context.focus();
+                HANDLED
+            }
+            Action::Click => {
+                // Activation changes are applied when the context is dropped,
+                // so the widget must be deactivated using a separate context
+                // for it to observe a full press and release.
+                context.activate();
+                drop(context);
+                EventContext::new(
+                    WidgetContext::new(
+                        target,
+                        &self.current_theme,
+                        &mut window,
+                        &mut self.fonts,
+                        self.theme_mode.get(),
+                        &mut self.cursor,
+                        #[cfg(feature = "localization")]
+                        &self.app.cushy().data.localizations,
+                    ),
+                    kludgine,
+                )
+                .deactivate();
+                HANDLED
+            }
+            _ => IGNORED,
+        }
+    }
+
     fn handle_drop(
         &mut self,
         drop: DropEvent<PathBuf>,
@@ -2639,6 +3049,7 @@ where
     ) {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         self.focused.set(window.focused());
         self.occluded.set(window.occluded());
         let inner_size = window.inner_size();
@@ -2729,6 +3140,7 @@ where
     ) -> bool {
         let cushy = self.app.cushy().clone();
         let _guard = cushy.enter_runtime();
+        let _animations = self.animations.track();
         Self::request_close(
             &mut self.behavior,
             &mut RunningWindow::new(
@@ -2895,6 +3307,21 @@ where
         self.mouse_input(window, kludgine, device_id.into(), state, button);
     }
 
+    fn touch(
+        &mut self,
+        window: kludgine::app::Window<'_, WindowCommand>,
+        kludgine: &mut Kludgine,
+        touch: winit::event::Touch,
+    ) {
+        self.touch(
+            window,
+            kludgine,
+            DeviceId::finger(Some(touch.device_id), touch.id),
+            touch.phase,
+            touch.location.into(),
+        );
+    }
+
     fn theme_changed(
         &mut self,
         window: kludgine::app::Window<'_, WindowCommand>,
@@ -2963,6 +3390,10 @@ where
                     window.winit().set_maximized(maximize);
                 }
             }
+            #[cfg(feature = "accessibility")]
+            WindowCommand::AccessibilityAction(request) => {
+                self.accessibility_action(window, kludgine, &request);
+            }
             WindowCommand::Execute(func) => {
                 let mut window = RunningWindow::new(
                     window,
@@ -3051,6 +3482,16 @@ fn recursively_handle_event(
     }
 }
 
+/// The destination of events for a finger touching a window.
+#[derive(Clone, Copy, Debug)]
+enum TouchTarget {
+    /// The touch is being tracked by a widget.
+    Widget(WidgetId),
+    /// No widget handled the touch, and it is being delivered as a left mouse
+    /// button.
+    Mouse,
+}
+
 #[derive(Default)]
 pub(crate) struct CursorState {
     pub(crate) location: Option<Point<Px>>,
@@ -3076,6 +3517,8 @@ pub(crate) mod sealed {
     use kludgine::app::winit::window::{Fullscreen, UserAttentionType, WindowButtons, WindowLevel};
     use kludgine::Color;
 
+    #[cfg(feature = "accessibility")]
+    use crate::accessibility::TreeUpdate;
     use crate::context::sealed::InvalidationStatus;
     use crate::context::EventContext;
     use crate::fonts::FontCollection;
@@ -3137,6 +3580,8 @@ pub(crate) mod sealed {
         pub fullscreen: Value<Option<Fullscreen>>,
         pub shortcuts: Value<ShortcutMap>,
         pub on_file_drop: Option<Notify<FileDrop>>,
+        #[cfg(feature = "accessibility")]
+        pub on_accessibility_update: Option<Notify<TreeUpdate>>,
     }
 
     pub struct WindowExecute(Box<dyn ExecuteFunc>);
@@ -3185,6 +3630,8 @@ pub(crate) mod sealed {
         Ize(Option<Ize>),
         SetTitle(MaybeLocalized),
         Execute(WindowExecute),
+        #[cfg(feature = "accessibility")]
+        AccessibilityAction(accesskit::ActionRequest),
     }
 
     #[derive(Debug, Clone)]
@@ -3377,6 +3824,17 @@ impl WindowHandle {
         self.inner
             .send(WindowCommand::Execute(WindowExecute::new(func)));
     }
+
+    /// Performs an action requested by an assistive technology.
+    ///
+    /// Applications that drive an AccessKit platform adapter using
+    /// [`Window::on_accessibility_update`] should forward the adapter's
+    /// [`ActionRequest`]s to this function. See
+    /// [`Widget::accessibility_action`] for how requests are handled.
+    #[cfg(feature = "accessibility")]
+    pub fn accessibility_action(&self, request: ActionRequest) {
+        self.inner.send(WindowCommand::AccessibilityAction(request));
+    }
 }
 
 impl Eq for WindowHandle {}
@@ -3420,6 +3878,13 @@ impl InnerWindowHandle {
                 WindowCommand::Execute(_func) => {
                     tracing::error!("ignoring execution of window function on virtual window");
                 }
+                #[cfg(feature = "accessibility")]
+                WindowCommand::AccessibilityAction(_request) => {
+                    tracing::error!(
+                        "ignoring accessibility action on virtual window; use \
+                         VirtualWindow::accessibility_action instead"
+                    );
+                }
                 WindowCommand::ResetDeadKeys
                 | WindowCommand::RequestUserAttention(_)
                 | WindowCommand::Focus
@@ -3600,6 +4065,13 @@ impl VirtualState {
             size: Size::upx(800, 600),
         }
     }
+
+    fn window_handle(&self, redraw_status: InvalidationStatus) -> WindowHandle {
+        WindowHandle {
+            inner: InnerWindowHandle::Virtual(self.dynamic.clone()),
+            redraw_status,
+        }
+    }
 }
 
 /// Window state that is able to be updated outside of event handling,
@@ -3638,10 +4110,7 @@ impl PlatformWindowImplementation for &mut VirtualState {
     }
 
     fn handle(&self, redraw_status: InvalidationStatus) -> WindowHandle {
-        WindowHandle {
-            inner: InnerWindowHandle::Virtual(self.dynamic.clone()),
-            redraw_status,
-        }
+        self.window_handle(redraw_status)
     }
 
     fn set_needs_redraw(&mut self) {
@@ -3740,6 +4209,16 @@ impl StandaloneWindowBuilder {
         self
     }
 
+    /// Sets this window's `zoom` factor.
+    ///
+    /// The zoom factor is multiplied with the DPI scaling factor to allow an
+    /// additional scaling factor to be applied.
+    #[must_use]
+    pub fn zoom(mut self, zoom: impl IntoDynamic<Fraction>) -> Self {
+        self.zoom = zoom.into_dynamic();
+        self
+    }
+
     /// Sets the window not fill its background before rendering its contents.
     #[must_use]
     pub fn transparent(mut self) -> Self {
@@ -3820,6 +4299,8 @@ impl StandaloneWindowBuilder {
                 shortcuts: Value::default(),
                 on_init: None,
                 on_file_drop: None,
+                #[cfg(feature = "accessibility")]
+                on_accessibility_update: None,
             },
         );
 
@@ -3988,6 +4469,14 @@ impl CushyWindow {
         self.window.moved(new_position, new_position);
     }
 
+    /// Returns the accessibility tree built during the last call to
+    /// [`prepare()`](Self::prepare).
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub const fn accessibility_tree(&self) -> &AccessibilityTree {
+        &self.window.accessibility
+    }
+
     /// Provide keyboard input to this virtual window.
     ///
     /// Returns whether the event was [`HANDLED`] or [`IGNORED`].
@@ -4032,6 +4521,15 @@ impl CushyWindow {
         self.window.ime(window, &mut self.kludgine, ime)
     }
 
+    /// Notifies the window that the keyboard modifiers reported by `window`
+    /// have changed.
+    pub fn modifiers_changed<W>(&mut self, window: W)
+    where
+        W: PlatformWindowImplementation,
+    {
+        self.window.modifiers.set(window.modifiers());
+    }
+
     /// Provides cursor movement events to this window.
     pub fn cursor_moved<W>(
         &mut self,
@@ -4069,6 +4567,51 @@ impl CushyWindow {
         self.window
             .mouse_input(window, &mut self.kludgine, device_id, state, button)
     }
+
+    /// Provides touch input for the finger `device_id` to this window.
+    ///
+    /// Returns whether the event was [`HANDLED`] or [`IGNORED`].
+    pub fn touch<W>(
+        &mut self,
+        window: W,
+        device_id: DeviceId,
+        phase: TouchPhase,
+        location: impl Into<Point<Px>>,
+    ) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        self.window.touch(
+            window,
+            &mut self.kludgine,
+            device_id,
+            phase,
+            location.into(),
+        )
+    }
+
+    /// Focuses `widget`, if it accepts focus.
+    ///
+    /// Focus changes are applied before this function returns.
+    pub fn focus_widget<W>(&mut self, window: W, widget: &MountedWidget)
+    where
+        W: PlatformWindowImplementation,
+    {
+        self.window.focus_widget(window, &mut self.kludgine, widget);
+    }
+
+    /// Performs an action requested by an assistive technology, returning
+    /// whether a widget handled it.
+    ///
+    /// See [`Widget::accessibility_action`] for how requests are handled.
+    #[cfg(feature = "accessibility")]
+    pub fn accessibility_action<W>(&mut self, window: W, request: &ActionRequest) -> EventHandling
+    where
+        W: PlatformWindowImplementation,
+    {
+        self.window
+            .accessibility_action(window, &mut self.kludgine, request)
+    }
 }
 
 /// A virtual Cushy window.
@@ -4223,6 +4766,14 @@ impl VirtualWindow {
         self.cushy.set_position(new_position);
     }
 
+    /// Returns the accessibility tree built during the last call to
+    /// [`prepare()`](Self::prepare).
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub const fn accessibility_tree(&self) -> &AccessibilityTree {
+        self.cushy.accessibility_tree()
+    }
+
     /// Provide keyboard input to this virtual window.
     ///
     /// Returns whether the event was [`HANDLED`] or [`IGNORED`].
@@ -4256,6 +4807,12 @@ impl VirtualWindow {
         self.cushy.ime(&mut self.state, ime)
     }
 
+    /// Sets the keyboard modifiers that are currently pressed.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.state.modifiers = modifiers;
+        self.cushy.modifiers_changed(&mut self.state);
+    }
+
     /// Provides cursor movement events to this window.
     pub fn cursor_moved(&mut self, device_id: DeviceId, position: impl Into<Point<Px>>) {
         self.cushy
@@ -4279,6 +4836,229 @@ impl VirtualWindow {
         self.cushy
             .mouse_input(&mut self.state, device_id, state, button)
     }
+
+    /// Provides touch input for the finger `device_id` to this window.
+    ///
+    /// Use [`DeviceId::finger`] to create a device id for a simulated finger.
+    /// A touch begins with [`TouchPhase::Started`], continues with any number
+    /// of [`TouchPhase::Moved`] events, and finishes with either
+    /// [`TouchPhase::Ended`] or [`TouchPhase::Cancelled`].
+    ///
+    /// Returns whether the event was [`HANDLED`] or [`IGNORED`].
+    pub fn touch(
+        &mut self,
+        device_id: DeviceId,
+        phase: TouchPhase,
+        location: impl Into<Point<Px>>,
+    ) -> EventHandling {
+        self.cushy
+            .touch(&mut self.state, device_id, phase, location)
+    }
+
+    /// Returns the root widget of this window.
+    #[must_use]
+    pub const fn root_widget(&self) -> &MountedWidget {
+        &self.cushy.window.root
+    }
+
+    /// Returns every widget mounted in this window.
+    ///
+    /// Widgets are returned in depth-first order, beginning with the root
+    /// widget. Widgets are only mounted once the window has been prepared.
+    #[must_use]
+    pub fn widgets(&self) -> Vec<MountedWidget> {
+        let mut widgets = Vec::new();
+        let mut to_visit = vec![self.cushy.window.root.clone()];
+        while let Some(widget) = to_visit.pop() {
+            to_visit.extend(
+                self.cushy
+                    .window
+                    .tree
+                    .children(widget.node_id)
+                    .into_iter()
+                    .rev(),
+            );
+            widgets.push(widget);
+        }
+        widgets
+    }
+
+    /// Returns the mounted widget with `id`, if found.
+    ///
+    /// A [`WidgetTag`](crate::widget::WidgetTag) can be used to find a
+    /// specific widget.
+    #[must_use]
+    pub fn widget(&self, id: impl Into<WidgetId>) -> Option<MountedWidget> {
+        self.cushy.window.tree.widget(id.into())
+    }
+
+    /// Returns the first mounted widget whose type is `W`.
+    #[must_use]
+    pub fn find_widget<W>(&self) -> Option<MountedWidget>
+    where
+        W: Widget,
+    {
+        self.widgets()
+            .into_iter()
+            .find(|widget| widget.lock().downcast_ref::<W>().is_some())
+    }
+
+    /// Returns all mounted widgets whose type is `W`.
+    #[must_use]
+    pub fn find_widgets<W>(&self) -> Vec<MountedWidget>
+    where
+        W: Widget,
+    {
+        self.widgets()
+            .into_iter()
+            .filter(|widget| widget.lock().downcast_ref::<W>().is_some())
+            .collect()
+    }
+
+    /// Returns the first mounted widget whose
+    /// [displayed text](Widget::displayed_text) is `text`.
+    ///
+    /// The displayed text of widgets is updated during layout.
+    #[must_use]
+    pub fn find_text(&self, text: &str) -> Option<MountedWidget> {
+        self.widgets()
+            .into_iter()
+            .find(|widget| widget.lock().as_widget().displayed_text() == Some(text))
+    }
+
+    /// Returns the text currently displayed by `widget`.
+    #[must_use]
+    pub fn text_of(&self, widget: &MountedWidget) -> Option<String> {
+        widget.lock().as_widget().displayed_text().map(String::from)
+    }
+
+    /// Returns true if `widget` and all of its parents are enabled.
+    #[must_use]
+    pub fn is_enabled(&self, widget: &MountedWidget) -> bool {
+        widget.enabled(
+            &self
+                .state
+                .window_handle(self.cushy.window.redraw_status.clone()),
+        )
+    }
+
+    /// Focuses `widget`, if it accepts focus.
+    pub fn focus(&mut self, widget: &MountedWidget) {
+        self.cushy.focus_widget(&mut self.state, widget);
+    }
+
+    /// Performs an action requested by an assistive technology, returning
+    /// whether a widget handled it.
+    ///
+    /// See [`Widget::accessibility_action`] for how requests are handled.
+    #[cfg(feature = "accessibility")]
+    pub fn accessibility_action(&mut self, request: &ActionRequest) -> EventHandling {
+        self.cushy.accessibility_action(&mut self.state, request)
+    }
+
+    /// Moves the cursor to the center of `widget` and clicks the left mouse
+    /// button.
+    ///
+    /// Returns whether pressing the button was [`HANDLED`] or [`IGNORED`].
+    /// Widgets that have not been laid out cannot be clicked.
+    pub fn click(&mut self, widget: &MountedWidget) -> EventHandling {
+        let Some(layout) = widget.last_layout() else {
+            return IGNORED;
+        };
+        let center = layout.origin + Point::new(layout.size.width / 2, layout.size.height / 2);
+        self.cursor_moved(DeviceId::Virtual(0), center);
+        let handled = self.mouse_input(
+            DeviceId::Virtual(0),
+            ElementState::Pressed,
+            MouseButton::Left,
+        );
+        let _ = self.mouse_input(
+            DeviceId::Virtual(0),
+            ElementState::Released,
+            MouseButton::Left,
+        );
+        handled
+    }
+
+    /// Presses and releases `key`, returning whether the press was
+    /// [`HANDLED`] or [`IGNORED`].
+    ///
+    /// The key is sent to the focused widget as a synthetic key event, along
+    /// with the modifiers most recently provided to
+    /// [`set_modifiers()`](Self::set_modifiers). [`Key::Character`] keys
+    /// produce their character as the event's text.
+    pub fn press_key(&mut self, key: impl Into<Key>) -> EventHandling {
+        let logical_key = key.into();
+        let text = match &logical_key {
+            Key::Character(text) => Some(text.clone()),
+            _ => None,
+        };
+        let mut event = KeyEvent {
+            physical_key: PhysicalKey::Unidentified(NativeKeyCode::Xkb(0)),
+            logical_key,
+            text,
+            location: KeyLocation::Standard,
+            state: ElementState::Pressed,
+            repeat: false,
+            modifiers: self.state.modifiers,
+        };
+        let handled = self.keyboard_input(DeviceId::Virtual(0), event.clone(), true);
+        event.state = ElementState::Released;
+        let _ = self.keyboard_input(DeviceId::Virtual(0), event, true);
+        handled
+    }
+
+    /// Focuses `widget` and types `text` into it.
+    ///
+    /// Each grapheme of `text` is sent as a synthetic key press and release
+    /// using [`press_key()`](Self::press_key).
+    pub fn type_text(&mut self, widget: &MountedWidget, text: &str) {
+        self.focus(widget);
+        for grapheme in text.graphemes(true) {
+            let _ = self.press_key(Key::Character(SmolStr::new(grapheme)));
+        }
+    }
+
+    /// Asserts that `widget` is the focused widget.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `widget` is not focused.
+    #[track_caller]
+    pub fn assert_focused(&self, widget: &MountedWidget) {
+        assert!(
+            widget.focused(),
+            "assertion failed: {widget:?} was not focused"
+        );
+    }
+
+    /// Asserts that whether `widget` is enabled matches `enabled`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the widget's enabled state is not `enabled`.
+    #[track_caller]
+    pub fn assert_enabled(&self, widget: &MountedWidget, enabled: bool) {
+        let actual = self.is_enabled(widget);
+        assert_eq!(
+            actual, enabled,
+            "assertion failed: {widget:?} enabled was {actual}, not {enabled}"
+        );
+    }
+
+    /// Asserts that the text displayed by `widget` is `expected`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the displayed text is not `expected`.
+    #[track_caller]
+    pub fn assert_text(&self, widget: &MountedWidget, expected: &str) {
+        let text = self.text_of(widget);
+        assert!(
+            text.as_deref() == Some(expected),
+            "assertion failed: {widget:?} displayed {text:?}, not {expected:?}"
+        );
+    }
 }
 
 /// A color format containing 8-bit red, green, and blue channels.
@@ -4373,6 +5153,7 @@ pub struct VirtualRecorderBuilder<Format> {
     contents: WidgetInstance,
     size: Size<UPx>,
     scale: f32,
+    zoom: Dynamic<Fraction>,
     format: PhantomData<Format>,
     resize_to_fit: bool,
 }
@@ -4384,6 +5165,7 @@ impl VirtualRecorderBuilder<Rgb8> {
             contents: contents.make_widget(),
             size: Size::upx(800, 600),
             scale: 1.0,
+            zoom: Dynamic::new(Fraction::ONE),
             format: PhantomData,
             resize_to_fit: false,
         }
@@ -4397,6 +5179,7 @@ impl VirtualRecorderBuilder<Rgb8> {
             contents: self.contents,
             size: self.size,
             scale: self.scale,
+            zoom: self.zoom,
             resize_to_fit: self.resize_to_fit,
             format: PhantomData,
         }
@@ -4429,6 +5212,16 @@ where
         self
     }
 
+    /// Sets the zoom factor of this virtual window.
+    ///
+    /// The zoom factor is multiplied with the DPI scaling. Changes to `zoom`
+    /// are applied the next time the window is refreshed.
+    #[must_use]
+    pub fn zoom(mut self, zoom: impl IntoDynamic<Fraction>) -> Self {
+        self.zoom = zoom.into_dynamic();
+        self
+    }
+
     /// Sets this virtual recorder to allow updating its size based on the
     /// contents being rendered.
     #[must_use]
@@ -4438,8 +5231,17 @@ where
     }
 
     /// Returns an initialized [`VirtualRecorder`].
+    ///
+    /// Rendering requires a wgpu adapter. If no adapter is available,
+    /// [`VirtualRecorderError::NoAdapter`] is returned.
     pub fn finish(self) -> Result<VirtualRecorder<Format>, VirtualRecorderError> {
-        VirtualRecorder::new(self.size, self.scale, self.resize_to_fit, self.contents)
+        VirtualRecorder::new_zoomed(
+            self.size,
+            self.scale,
+            self.zoom,
+            self.resize_to_fit,
+            self.contents,
+        )
     }
 }
 
@@ -4535,6 +5337,22 @@ where
         scale: f32,
         resize_to_fit: bool,
         contents: impl MakeWidget,
+    ) -> Result<Self, VirtualRecorderError> {
+        Self::new_zoomed(
+            size,
+            scale,
+            Dynamic::new(Fraction::ONE),
+            resize_to_fit,
+            contents,
+        )
+    }
+
+    fn new_zoomed(
+        size: Size<UPx>,
+        scale: f32,
+        zoom: Dynamic<Fraction>,
+        resize_to_fit: bool,
+        contents: impl MakeWidget,
     ) -> Result<Self, VirtualRecorderError> {
         let wgpu = wgpu::Instance::default();
         let adapter =
@@ -4554,6 +5372,7 @@ where
             .build_standalone_window()
             .size(size)
             .scale(scale)
+            .zoom(zoom)
             .transparent()
             .resize_to_fit(resize_to_fit)
             .finish_virtual(&device, &queue);
@@ -4632,6 +5451,14 @@ where
         Format::load_image(self.bytes(), self.data_size)
     }
 
+    /// Returns the accessibility tree of the window as of the last
+    /// [`refresh()`](Self::refresh).
+    #[cfg(feature = "accessibility")]
+    #[must_use]
+    pub const fn accessibility_tree(&self) -> &AccessibilityTree {
+        self.window.accessibility_tree()
+    }
+
     fn recreate_buffers_if_needed(&mut self, size: Size<UPx>, bytes: u64, bytes_per_row: u32) {
         if self
             .capture
@@ -4730,6 +5557,39 @@ where
         Ok(())
     }
 
+    /// Redraws until the animations spawned by this window have completed and
+    /// the window no longer needs to be redrawn, or until `timeout` has
+    /// elapsed.
+    ///
+    /// Returns true if the window settled before `timeout` elapsed. The
+    /// contents are refreshed before returning.
+    ///
+    /// Only animations spawned while this window was handling events or
+    /// redrawing are waited on. Animations that repeat forever prevent the
+    /// window from settling.
+    pub fn settle(&mut self, timeout: Duration) -> Result<bool, wgpu::BufferAsyncError> {
+        let deadline = Instant::now() + timeout;
+        let settled = loop {
+            self.redraw();
+            if !self.window.cushy.window.animations.running()
+                && !matches!(
+                    self.window.state.dynamic.redraw_target.get(),
+                    RedrawTarget::Now
+                )
+            {
+                break true;
+            }
+
+            let now = Instant::now();
+            let Some(remaining) = deadline.checked_duration_since(now) else {
+                break false;
+            };
+            std::thread::sleep(remaining.min(Duration::from_millis(16)));
+        };
+        self.refresh()?;
+        Ok(settled)
+    }
+
     /// Sets the cursor position immediately.
     pub fn set_cursor_position(&self, position: Point<Px>) {
         self.cursor.set(position);
@@ -4777,6 +5637,18 @@ impl<Format> AnimationRecorder<'_, Format>
 where
     Format: CaptureFormat,
 {
+    /// Returns the virtual window being recorded.
+    #[must_use]
+    pub fn window(&self) -> &VirtualWindow {
+        &self.recorder.window
+    }
+
+    /// Returns exclusive access to the virtual window being recorded.
+    #[must_use]
+    pub fn window_mut(&mut self) -> &mut VirtualWindow {
+        &mut self.recorder.window
+    }
+
     /// Animates the cursor to move from its current location to `location`.
     pub fn animate_cursor_to(
         &mut self,
@@ -4813,6 +5685,48 @@ where
         Ok(())
     }
 
+    /// Animates touching a finger to `location` and lifting it after
+    /// `duration`.
+    pub fn animate_tap(
+        &mut self,
+        location: Point<Px>,
+        duration: Duration,
+    ) -> Result<(), VirtualRecorderError> {
+        let finger = DeviceId::finger(None, 0);
+        let _ = self
+            .recorder
+            .window
+            .touch(finger, TouchPhase::Started, location);
+        self.wait_for(duration)?;
+        let _ = self
+            .recorder
+            .window
+            .touch(finger, TouchPhase::Ended, location);
+        Ok(())
+    }
+
+    /// Animates pressing `button` at the current cursor location, moving the
+    /// cursor to `location` while the button is held, and then releasing the
+    /// button.
+    pub fn animate_mouse_drag_to(
+        &mut self,
+        button: MouseButton,
+        location: Point<Px>,
+        over: Duration,
+        easing: impl Easing,
+    ) -> Result<(), VirtualRecorderError> {
+        let _ =
+            self.recorder
+                .window
+                .mouse_input(DeviceId::Virtual(0), ElementState::Pressed, button);
+        self.animate_cursor_to(location, over, easing)?;
+        let _ =
+            self.recorder
+                .window
+                .mouse_input(DeviceId::Virtual(0), ElementState::Released, button);
+        Ok(())
+    }
+
     /// Simulates a key down and key up event with the given information.
     pub fn animate_keypress(
         &mut self,
@@ -5065,6 +5979,29 @@ pub enum DeviceId {
     Winit(winit::event::DeviceId),
     /// A simulated device.
     Virtual(u64),
+    /// A finger touching a touch screen or touch pad.
+    Finger {
+        /// The device being touched, or `None` for simulated touches.
+        device: Option<winit::event::DeviceId>,
+        /// The identifier of the finger. This identifier is unique while the
+        /// finger remains touching the device.
+        id: u64,
+    },
+}
+
+impl DeviceId {
+    /// Returns the device id of the finger `id` touching `device`.
+    #[must_use]
+    pub const fn finger(device: Option<winit::event::DeviceId>, id: u64) -> Self {
+        Self::Finger { device, id }
+    }
+
+    /// Returns true if this device is a finger touching a touch-enabled
+    /// device.
+    #[must_use]
+    pub const fn is_finger(&self) -> bool {
+        matches!(self, Self::Finger { .. })
+    }
 }
 
 impl From<winit::event::DeviceId> for DeviceId {